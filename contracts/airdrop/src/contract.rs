@@ -0,0 +1,198 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use crate::{
+    error::ContractError,
+    helpers::{check_es_axis_rate, check_merkle_root},
+    state::{save_config, save_state, Config, State},
+};
+
+use axis_protocol::airdrop::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use axis_protocol::query::query_axis_denom;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:airdrop";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<SeiQueryWrapper>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response<SeiMsg>, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    check_merkle_root(&msg.merkle_root)?;
+    check_es_axis_rate(msg.es_axis_rate, msg.es_axis_contract.is_some())?;
+
+    let axis_denom = query_axis_denom(deps.querier, &msg.axis_contract)?;
+    let config = Config {
+        axis_contract: msg.axis_contract,
+        axis_denom,
+        merkle_root: msg.merkle_root,
+        expiry: msg.expiry,
+        es_axis_rate: msg.es_axis_rate,
+        es_axis_contract: msg.es_axis_contract,
+    };
+    save_config(deps.storage, &config)?;
+    save_state(
+        deps.storage,
+        &State {
+            total_claimed: Uint128::zero(),
+            expired: false,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("merkle_root", config.merkle_root))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<SeiQueryWrapper>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response<SeiMsg>, ContractError> {
+    match msg {
+        ExecuteMsg::Claim { amount, proof } => execute::claim(deps, env, info, amount, proof),
+        ExecuteMsg::Expire {} => execute::expire(deps, env),
+    }
+}
+
+pub mod execute {
+    use axis_protocol::axis::ExecuteMsg as AxisExecuteMsg;
+    use axis_protocol::es_axis::ExecuteMsg as EsAxisExecuteMsg;
+    use cosmwasm_std::{coin, BankMsg, WasmMsg};
+
+    use crate::{
+        helpers::{split_claim_amount, verify_proof},
+        state::{load_config, load_state, CLAIM},
+    };
+
+    use super::*;
+
+    pub fn claim(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        info: MessageInfo,
+        amount: Uint128,
+        proof: Vec<String>,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        if state.expired || env.block.time.seconds() >= config.expiry {
+            return Err(ContractError::Expired {});
+        }
+        if CLAIM.has(deps.storage, &info.sender) {
+            return Err(ContractError::AlreadyClaimed {});
+        }
+        verify_proof(&config.merkle_root, info.sender.as_str(), amount, &proof)?;
+
+        CLAIM.save(deps.storage, &info.sender, &amount)?;
+        state.total_claimed += amount;
+        save_state(deps.storage, &state)?;
+
+        let (axis_amount, es_axis_amount) = split_claim_amount(amount, config.es_axis_rate);
+        let mut response = Response::new()
+            .add_attribute("method", "claim")
+            .add_attribute("address", info.sender.to_string())
+            .add_attribute("axis_amount", axis_amount)
+            .add_attribute("es_axis_amount", es_axis_amount);
+        if !axis_amount.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![coin(axis_amount.into(), config.axis_denom.to_owned())],
+            });
+        }
+        //@@ es axis share is locked 1:1 in the es axis contract
+        if let (false, Some(es_axis_contract)) = (es_axis_amount.is_zero(), config.es_axis_contract)
+        {
+            response = response.add_message(WasmMsg::Execute {
+                contract_addr: es_axis_contract.to_string(),
+                msg: to_binary(&EsAxisExecuteMsg::Escrow {
+                    recipient: info.sender.to_string(),
+                })?,
+                funds: vec![coin(es_axis_amount.into(), config.axis_denom)],
+            });
+        }
+        Ok(response)
+    }
+
+    pub fn expire(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        if state.expired || env.block.time.seconds() < config.expiry {
+            return Err(ContractError::NotExpired {});
+        }
+        state.expired = true;
+        save_state(deps.storage, &state)?;
+
+        let unclaimed = deps
+            .querier
+            .query_balance(env.contract.address, config.axis_denom)?;
+        let mut response = Response::new()
+            .add_attribute("method", "expire")
+            .add_attribute("unclaimed_amount", unclaimed.amount);
+        if !unclaimed.amount.is_zero() {
+            response = response.add_message(WasmMsg::Execute {
+                contract_addr: config.axis_contract.to_string(),
+                msg: to_binary(&AxisExecuteMsg::ReturnCommunity {})?,
+                funds: vec![unclaimed],
+            });
+        }
+        Ok(response)
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetConfig {} => to_binary(&query::config(deps)?),
+        QueryMsg::GetState {} => to_binary(&query::state(deps)?),
+        QueryMsg::IsClaimed { address } => to_binary(&query::is_claimed(deps, address)?),
+    }
+}
+
+pub mod query {
+    use crate::state::{load_config, load_state, CLAIM};
+    use axis_protocol::airdrop::{ClaimedResponse, ConfigResponse, StateResponse};
+    use cosmwasm_std::{Deps, StdResult};
+    use sei_cosmwasm::SeiQueryWrapper;
+
+    pub fn config(deps: Deps<SeiQueryWrapper>) -> StdResult<ConfigResponse> {
+        let config = load_config(deps.storage)?;
+        Ok(ConfigResponse {
+            axis_contract: config.axis_contract.to_string(),
+            axis_denom: config.axis_denom,
+            merkle_root: config.merkle_root,
+            expiry: config.expiry,
+            es_axis_rate: config.es_axis_rate,
+            es_axis_contract: config.es_axis_contract.map(|addr| addr.to_string()),
+        })
+    }
+    pub fn state(deps: Deps<SeiQueryWrapper>) -> StdResult<StateResponse> {
+        let state = load_state(deps.storage)?;
+        Ok(StateResponse {
+            total_claimed: state.total_claimed,
+            expired: state.expired,
+        })
+    }
+    pub fn is_claimed(deps: Deps<SeiQueryWrapper>, address: String) -> StdResult<ClaimedResponse> {
+        let address = deps.api.addr_validate(&address)?;
+        let claimed = CLAIM.may_load(deps.storage, &address)?;
+        Ok(ClaimedResponse {
+            claimed: claimed.is_some(),
+            amount: claimed.unwrap_or_default(),
+        })
+    }
+}
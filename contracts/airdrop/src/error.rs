@@ -0,0 +1,32 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid Merkle Root")]
+    InvalidMerkleRoot {},
+
+    #[error("Invalid Proof")]
+    InvalidProof {},
+
+    #[error("Verification Failed")]
+    VerificationFailed {},
+
+    #[error("Already Claimed")]
+    AlreadyClaimed {},
+
+    #[error("Expired")]
+    Expired {},
+
+    #[error("Not Expired")]
+    NotExpired {},
+
+    #[error("Invalid EsAxis Rate")]
+    InvalidEsAxisRate {},
+}
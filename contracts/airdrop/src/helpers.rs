@@ -0,0 +1,52 @@
+use cosmwasm_std::{Decimal, Uint128};
+use sha2::{Digest, Sha256};
+
+use crate::ContractError;
+
+pub fn check_merkle_root(merkle_root: &str) -> Result<(), ContractError> {
+    let mut root_buf: [u8; 32] = [0; 32];
+    hex::decode_to_slice(merkle_root, &mut root_buf)
+        .map_err(|_| ContractError::InvalidMerkleRoot {})
+}
+
+pub fn check_es_axis_rate(
+    es_axis_rate: u8,
+    has_es_axis_contract: bool,
+) -> Result<(), ContractError> {
+    match es_axis_rate <= 100 && (es_axis_rate == 0 || has_es_axis_contract) {
+        true => Ok(()),
+        false => Err(ContractError::InvalidEsAxisRate {}),
+    }
+}
+
+//@@ pairs are hashed in sorted order , so the proof does not need positions
+pub fn verify_proof(
+    merkle_root: &str,
+    address: &str,
+    amount: Uint128,
+    proof: &[String],
+) -> Result<(), ContractError> {
+    let leaf: [u8; 32] = Sha256::digest(format!("{}{}", address, amount).as_bytes()).into();
+
+    let hash = proof.iter().try_fold(leaf, |hash, p| {
+        let mut proof_buf: [u8; 32] = [0; 32];
+        hex::decode_to_slice(p, &mut proof_buf).map_err(|_| ContractError::InvalidProof {})?;
+        let mut hashes = [hash, proof_buf];
+        hashes.sort_unstable();
+        Ok::<[u8; 32], ContractError>(Sha256::digest(hashes.concat()).into())
+    })?;
+
+    let mut root_buf: [u8; 32] = [0; 32];
+    hex::decode_to_slice(merkle_root, &mut root_buf)
+        .map_err(|_| ContractError::InvalidMerkleRoot {})?;
+    match root_buf == hash {
+        true => Ok(()),
+        false => Err(ContractError::VerificationFailed {}),
+    }
+}
+
+//return (axis amount, es axis amount)
+pub fn split_claim_amount(amount: Uint128, es_axis_rate: u8) -> (Uint128, Uint128) {
+    let es_axis_amount = amount * Decimal::percent(es_axis_rate.into());
+    (amount - es_axis_amount, es_axis_amount)
+}
@@ -0,0 +1,7 @@
+pub mod contract;
+mod error;
+pub mod helpers;
+
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,36 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    pub axis_contract: Addr,
+    pub axis_denom: String,
+    pub merkle_root: String,
+    pub expiry: u64,
+    pub es_axis_rate: u8,
+    pub es_axis_contract: Option<Addr>,
+}
+pub fn save_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
+    CONFIG.save(storage, config)
+}
+pub fn load_config(storage: &dyn Storage) -> StdResult<Config> {
+    CONFIG.load(storage)
+}
+
+#[cw_serde]
+pub struct State {
+    pub total_claimed: Uint128,
+    pub expired: bool,
+}
+pub fn save_state(storage: &mut dyn Storage, state: &State) -> StdResult<()> {
+    STATE.save(storage, state)
+}
+pub fn load_state(storage: &dyn Storage) -> StdResult<State> {
+    STATE.load(storage)
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const STATE: Item<State> = Item::new("state");
+//claimed amount per address
+pub const CLAIM: Map<&Addr, Uint128> = Map::new("claim");
@@ -0,0 +1,554 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use crate::{
+    error::ContractError,
+    helpers::check_max_slippage,
+    query::{query_lp_denom, query_pair_lp_staking_contract, query_pair_pool_contract},
+    state::{save_config, save_state, Config, State},
+};
+
+use axis_protocol::auto_compounder::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use axis_protocol::query::{query_axis_contract, query_axis_denom};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:auto-compounder";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const SHARE_DENOM: &str = "share";
+const DEFAULT_MAX_SLIPPAGE: u8 = 1;
+//shares of the first deposit nobody owns , the share price can't be pumped by holding every share
+const MINIMUM_SHARES: u128 = 1_000;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<SeiQueryWrapper>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response<SeiMsg>, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let max_slippage = msg.max_slippage.unwrap_or(DEFAULT_MAX_SLIPPAGE);
+    check_max_slippage(max_slippage)?;
+
+    let pool_contract = query_pair_pool_contract(
+        deps.querier,
+        &msg.core_contract,
+        &msg.base_denom,
+        &msg.price_denom,
+    )?;
+    let lp_staking_contract = query_pair_lp_staking_contract(
+        deps.querier,
+        &msg.core_contract,
+        &msg.base_denom,
+        &msg.price_denom,
+    )?;
+    let lp_denom = query_lp_denom(deps.querier, &pool_contract)?;
+    let axis_contract = query_axis_contract(deps.querier, &msg.core_contract)?;
+    let axis_denom = query_axis_denom(deps.querier, &axis_contract)?;
+    let share_denom = "factory/".to_string() + env.contract.address.as_ref() + "/" + SHARE_DENOM;
+
+    let config = Config {
+        core_contract: msg.core_contract,
+        lp_staking_contract,
+        pool_contract,
+        router_contract: deps.api.addr_validate(&msg.router_contract)?,
+        base_denom: msg.base_denom,
+        price_denom: msg.price_denom,
+        lp_denom,
+        axis_denom,
+        share_denom: share_denom.to_owned(),
+        max_slippage,
+    };
+    save_config(deps.storage, &config)?;
+    save_state(
+        deps.storage,
+        &State {
+            total_lp: Uint128::zero(),
+            total_shares: Uint128::zero(),
+            unbonding_total: Uint128::zero(),
+            held_lp: Uint128::zero(),
+            last_compound_epoch: None,
+            pending_lp: Uint128::zero(),
+            pending_epoch: 0,
+            queued_lp: Uint128::zero(),
+            queued_epoch: 0,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("share_denom", share_denom)
+        .add_message(SeiMsg::CreateDenom {
+            subdenom: SHARE_DENOM.to_owned(),
+        }))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<SeiQueryWrapper>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response<SeiMsg>, ContractError> {
+    use execute::*;
+    match msg {
+        ExecuteMsg::Deposit {} => deposit(deps, info),
+        ExecuteMsg::Unbond {} => unbond(deps, info),
+        ExecuteMsg::Withdraw {} => withdraw(deps, info),
+        ExecuteMsg::Compound {} => compound(deps, env),
+        ExecuteMsg::SwapReward {} => swap_reward(deps, env, info),
+        ExecuteMsg::ProvideLiquidity {} => provide_liquidity(deps, env, info),
+        ExecuteMsg::StakeLiquidity {} => stake_liquidity(deps, env, info),
+    }
+}
+
+pub mod execute {
+    use axis_protocol::lp_staking::ExecuteMsg as LpStakingExecuteMsg;
+    use axis_protocol::pool::{
+        ExecuteMsg as PoolExecuteMsg, PoolResponse, QueryMsg as PoolQueryMsg,
+    };
+    use axis_protocol::query::query_epoch;
+    use axis_protocol::router::ExecuteMsg as RouterExecuteMsg;
+    use cosmwasm_std::{coin, BankMsg, Coin, CosmosMsg, Storage, WasmMsg};
+
+    use crate::{
+        helpers::{
+            check_compound_epoch, check_funds_and_get_amount, check_self, compute_mint_shares,
+            compute_share_lp, min_return,
+        },
+        query::query_oracle_return,
+        state::{
+            add_queued_unbonding, add_unbonding, load_config, load_state, take_unlocked,
+            take_unlocked_queued, take_unlocked_total, unlock_queued, Config, State,
+        },
+    };
+
+    use super::*;
+
+    fn self_msg(env: &Env, msg: &ExecuteMsg) -> StdResult<CosmosMsg<SeiMsg>> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(msg)?,
+            funds: vec![],
+        }))
+    }
+
+    //@@ the queue of an earlier epoch leaves lp-staking now that its stakes started
+    fn unstake_queued(
+        storage: &mut dyn Storage,
+        config: &Config,
+        state: &mut State,
+        epoch: u64,
+    ) -> StdResult<Vec<CosmosMsg<SeiMsg>>> {
+        if state.queued_lp.is_zero() || state.queued_epoch >= epoch {
+            return Ok(vec![]);
+        }
+        unlock_queued(storage, state.queued_epoch, epoch + 1, state.queued_lp)?;
+        let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.lp_staking_contract.to_string(),
+            msg: to_binary(&LpStakingExecuteMsg::UnStaking {
+                amount: Some(state.queued_lp),
+            })?,
+            funds: vec![],
+        });
+        state.queued_lp = Uint128::zero();
+        Ok(vec![msg])
+    }
+
+    pub fn deposit(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        let lp_amount = check_funds_and_get_amount(&info.funds, &config.lp_denom)?;
+
+        let mint_shares = compute_mint_shares(lp_amount, state.total_lp, state.total_shares);
+        let locked_shares = match state.total_shares.is_zero() {
+            true => Uint128::new(MINIMUM_SHARES),
+            false => Uint128::zero(),
+        };
+        if mint_shares <= locked_shares {
+            return Err(ContractError::InvalidAmount {});
+        }
+        let shares = mint_shares - locked_shares;
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+        state.add_pending_lp(epoch, lp_amount);
+        state.total_lp += lp_amount;
+        state.total_shares += mint_shares;
+        save_state(deps.storage, &state)?;
+
+        let share_token = coin(shares.into(), config.share_denom);
+        Ok(Response::new()
+            .add_attribute("method", "deposit")
+            .add_attribute("lp_amount", lp_amount)
+            .add_attribute("shares", shares)
+            .add_message(WasmMsg::Execute {
+                contract_addr: config.lp_staking_contract.to_string(),
+                msg: to_binary(&LpStakingExecuteMsg::Staking { lock_epochs: None })?,
+                funds: info.funds,
+            })
+            .add_message(SeiMsg::MintTokens {
+                amount: share_token.to_owned(),
+            })
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![share_token],
+            }))
+    }
+
+    //@@ only active stakes can leave lp-staking , the lp of the stakes made this epoch
+    //@@ is queued and unstaked by the first unbond , withdraw or compound of a later epoch
+    pub fn unbond(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        let shares = check_funds_and_get_amount(&info.funds, &config.share_denom)?;
+
+        let lp_amount = compute_share_lp(shares, state.total_lp, state.total_shares);
+        if lp_amount.is_zero() {
+            return Err(ContractError::InvalidAmount {});
+        }
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+        let mut msgs = unstake_queued(deps.storage, &config, &mut state, epoch)?;
+        let unstake_amount = Uint128::min(lp_amount, state.active_lp(epoch));
+        let queued_amount = lp_amount - unstake_amount;
+        let unlock_epoch = epoch + 1;
+        if !unstake_amount.is_zero() {
+            add_unbonding(deps.storage, &info.sender, unlock_epoch, unstake_amount)?;
+            msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.lp_staking_contract.to_string(),
+                msg: to_binary(&LpStakingExecuteMsg::UnStaking {
+                    amount: Some(unstake_amount),
+                })?,
+                funds: vec![],
+            }));
+        }
+        if !queued_amount.is_zero() {
+            add_queued_unbonding(deps.storage, &info.sender, epoch, queued_amount)?;
+            state.queued_lp += queued_amount;
+            state.queued_epoch = epoch;
+        }
+        state.total_lp -= lp_amount;
+        state.total_shares -= shares;
+        state.unbonding_total += lp_amount;
+        save_state(deps.storage, &state)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "unbond")
+            .add_attribute("shares", shares)
+            .add_attribute("lp_amount", lp_amount)
+            .add_attribute("queued_amount", queued_amount)
+            .add_attribute("unlock_epoch", unlock_epoch.to_string())
+            .add_message(SeiMsg::BurnTokens {
+                amount: coin(shares.into(), config.share_denom),
+            })
+            .add_messages(msgs))
+    }
+
+    pub fn withdraw(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+
+        //@@ the queue is unstaked even when nothing is withdrawable yet
+        let mut msgs = unstake_queued(deps.storage, &config, &mut state, epoch)?;
+        let lp_amount = take_unlocked(deps.storage, &info.sender, epoch)?
+            + take_unlocked_queued(deps.storage, &info.sender, epoch)?;
+        if lp_amount.is_zero() && msgs.is_empty() {
+            return Err(ContractError::NothingToWithdraw {});
+        }
+        //@@ lp-staking pays every unlocked unstake , the lp of others is held until they withdraw
+        let unlocked_total = take_unlocked_total(deps.storage, epoch)?;
+        state.held_lp = state.held_lp + unlocked_total - lp_amount;
+        state.unbonding_total -= lp_amount;
+        save_state(deps.storage, &state)?;
+
+        if !unlocked_total.is_zero() {
+            msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.lp_staking_contract.to_string(),
+                msg: to_binary(&LpStakingExecuteMsg::Withdraw {})?,
+                funds: vec![],
+            }));
+        }
+        if !lp_amount.is_zero() {
+            msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![coin(lp_amount.into(), config.lp_denom)],
+            }));
+        }
+
+        Ok(Response::new()
+            .add_attribute("method", "withdraw")
+            .add_attribute("lp_amount", lp_amount)
+            .add_messages(msgs))
+    }
+
+    //@@ each step reads the balances the previous one left here
+    pub fn compound(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+        check_compound_epoch(state.last_compound_epoch, epoch)?;
+        if state.total_lp.is_zero() {
+            return Err(ContractError::NothingToCompound {});
+        }
+        state.last_compound_epoch = Some(epoch);
+        let msgs = unstake_queued(deps.storage, &config, &mut state, epoch)?;
+        save_state(deps.storage, &state)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "compound")
+            .add_attribute("epoch", epoch.to_string())
+            .add_messages(msgs)
+            .add_message(WasmMsg::Execute {
+                contract_addr: config.lp_staking_contract.to_string(),
+                msg: to_binary(&LpStakingExecuteMsg::ClaimReward {})?,
+                funds: vec![],
+            })
+            .add_message(self_msg(&env, &ExecuteMsg::SwapReward {})?)
+            .add_message(self_msg(&env, &ExecuteMsg::ProvideLiquidity {})?)
+            .add_message(self_msg(&env, &ExecuteMsg::StakeLiquidity {})?))
+    }
+
+    //@@ AXIS is split by the value of the pool reserves so the deposit takes most of it
+    pub fn swap_reward(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        check_self(&env.contract.address, &info.sender)?;
+        let config = load_config(deps.storage)?;
+        let axis_amount = deps
+            .querier
+            .query_balance(&env.contract.address, &config.axis_denom)?
+            .amount;
+        if axis_amount.is_zero() {
+            return Ok(Response::new().add_attribute("method", "swap_reward"));
+        }
+
+        let pool: PoolResponse = deps
+            .querier
+            .query_wasm_smart(config.pool_contract.to_string(), &PoolQueryMsg::GetPool {})?;
+        let base_value = query_oracle_return(
+            deps.querier,
+            &config.base_denom,
+            pool.base_amount + pool.base_borrow_amount,
+            &config.price_denom,
+        )?;
+        let price_value = pool.price_amount + pool.price_borrow_amount;
+        let base_offer = match (base_value + price_value).is_zero() {
+            true => axis_amount.multiply_ratio(1u128, 2u128),
+            false => axis_amount.multiply_ratio(base_value, base_value + price_value),
+        };
+
+        let swap_msgs = [
+            (base_offer, &config.base_denom),
+            (axis_amount - base_offer, &config.price_denom),
+        ]
+        .into_iter()
+        .filter(|(offer_amount, _)| !offer_amount.is_zero())
+        .map(|(offer_amount, ask_denom)| {
+            let oracle_return =
+                query_oracle_return(deps.querier, &config.axis_denom, offer_amount, ask_denom)?;
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.router_contract.to_string(),
+                msg: to_binary(&RouterExecuteMsg::Swap {
+                    ask_denom: ask_denom.to_owned(),
+                    min_return: min_return(oracle_return, config.max_slippage),
+                    recipient: env.contract.address.to_string(),
+                })?,
+                funds: vec![coin(offer_amount.into(), config.axis_denom.to_owned())],
+            }))
+        })
+        .collect::<Result<Vec<CosmosMsg<SeiMsg>>, ContractError>>()?;
+
+        Ok(Response::new()
+            .add_attribute("method", "swap_reward")
+            .add_attribute("axis_amount", axis_amount)
+            .add_messages(swap_msgs))
+    }
+
+    //@@ what the pool does not accept is refunded here and used by the next compound
+    pub fn provide_liquidity(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        check_self(&env.contract.address, &info.sender)?;
+        let config = load_config(deps.storage)?;
+        let mut funds: Vec<Coin> = [&config.base_denom, &config.price_denom]
+            .into_iter()
+            .map(|denom| deps.querier.query_balance(&env.contract.address, denom))
+            .collect::<StdResult<_>>()?;
+        if funds.iter().any(|fund| fund.amount.is_zero()) {
+            return Ok(Response::new().add_attribute("method", "provide_liquidity"));
+        }
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        Ok(Response::new()
+            .add_attribute("method", "provide_liquidity")
+            .add_message(WasmMsg::Execute {
+                contract_addr: config.pool_contract.to_string(),
+                msg: to_binary(&PoolExecuteMsg::Deposit {})?,
+                funds,
+            }))
+    }
+
+    //@@ new lp and the early exit lp shared by lp-staking go to the share holders
+    pub fn stake_liquidity(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        check_self(&env.contract.address, &info.sender)?;
+        let config = load_config(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        let lp_amount = deps
+            .querier
+            .query_balance(&env.contract.address, &config.lp_denom)?
+            .amount
+            - state.held_lp;
+        if lp_amount.is_zero() {
+            return Ok(Response::new().add_attribute("method", "stake_liquidity"));
+        }
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+        state.add_pending_lp(epoch, lp_amount);
+        state.total_lp += lp_amount;
+        save_state(deps.storage, &state)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "stake_liquidity")
+            .add_attribute("lp_amount", lp_amount)
+            .add_message(WasmMsg::Execute {
+                contract_addr: config.lp_staking_contract.to_string(),
+                msg: to_binary(&LpStakingExecuteMsg::Staking { lock_epochs: None })?,
+                funds: vec![coin(lp_amount.into(), config.lp_denom)],
+            }))
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetConfig {} => to_binary(&query::get_config(deps)?),
+        QueryMsg::GetState {} => to_binary(&query::get_state(deps)?),
+        QueryMsg::GetSharePrice {} => to_binary(&query::get_share_price(deps)?),
+        QueryMsg::GetShareValue { address } => to_binary(&query::get_share_value(deps, address)?),
+        QueryMsg::GetUnbonding { address } => to_binary(&query::get_unbonding(deps, address)?),
+    }
+}
+
+pub mod query {
+    use axis_protocol::auto_compounder::{
+        ConfigResponse, SharePriceResponse, ShareValueResponse, StateResponse, UnbondingResponse,
+    };
+    use cosmwasm_std::Order;
+
+    use crate::{
+        helpers::{compute_share_lp, compute_share_price},
+        state::{load_config, load_state, QUEUED_UNBONDING, QUEUE_UNLOCK, UNBONDING},
+    };
+
+    use super::*;
+
+    pub fn get_config(deps: Deps<SeiQueryWrapper>) -> StdResult<ConfigResponse> {
+        let config = load_config(deps.storage)?;
+        Ok(ConfigResponse {
+            core_contract: config.core_contract,
+            lp_staking_contract: config.lp_staking_contract,
+            pool_contract: config.pool_contract,
+            router_contract: config.router_contract,
+            base_denom: config.base_denom,
+            price_denom: config.price_denom,
+            lp_denom: config.lp_denom,
+            axis_denom: config.axis_denom,
+            share_denom: config.share_denom,
+            max_slippage: config.max_slippage,
+        })
+    }
+
+    pub fn get_state(deps: Deps<SeiQueryWrapper>) -> StdResult<StateResponse> {
+        let state = load_state(deps.storage)?;
+        Ok(StateResponse {
+            total_lp: state.total_lp,
+            total_shares: state.total_shares,
+            unbonding_total: state.unbonding_total,
+            last_compound_epoch: state.last_compound_epoch,
+        })
+    }
+
+    pub fn get_share_price(deps: Deps<SeiQueryWrapper>) -> StdResult<SharePriceResponse> {
+        let state = load_state(deps.storage)?;
+        Ok(SharePriceResponse {
+            share_price: compute_share_price(state.total_lp, state.total_shares),
+            total_lp: state.total_lp,
+            total_shares: state.total_shares,
+        })
+    }
+
+    pub fn get_share_value(
+        deps: Deps<SeiQueryWrapper>,
+        address: String,
+    ) -> StdResult<ShareValueResponse> {
+        let config = load_config(deps.storage)?;
+        let state = load_state(deps.storage)?;
+        let address = deps.api.addr_validate(&address)?;
+        let shares = deps
+            .querier
+            .query_balance(address, config.share_denom)?
+            .amount;
+        Ok(ShareValueResponse {
+            shares,
+            lp_amount: compute_share_lp(shares, state.total_lp, state.total_shares),
+        })
+    }
+
+    pub fn get_unbonding(
+        deps: Deps<SeiQueryWrapper>,
+        address: String,
+    ) -> StdResult<Vec<UnbondingResponse>> {
+        let address = deps.api.addr_validate(&address)?;
+        let mut unbondings = UNBONDING
+            .prefix(&address)
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| {
+                let (unlock_epoch, lp_amount) = item?;
+                Ok(UnbondingResponse {
+                    unlock_epoch,
+                    lp_amount,
+                })
+            })
+            .collect::<StdResult<Vec<UnbondingResponse>>>()?;
+        //@@ a queue not unstaked yet unlocks at the earliest one epoch after its stakes start
+        for item in
+            QUEUED_UNBONDING
+                .prefix(&address)
+                .range(deps.storage, None, None, Order::Ascending)
+        {
+            let (queued_epoch, lp_amount) = item?;
+            let unlock_epoch = QUEUE_UNLOCK
+                .may_load(deps.storage, queued_epoch)?
+                .unwrap_or(queued_epoch + 2);
+            unbondings.push(UnbondingResponse {
+                unlock_epoch,
+                lp_amount,
+            });
+        }
+        Ok(unbondings)
+    }
+}
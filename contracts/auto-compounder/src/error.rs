@@ -0,0 +1,32 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid Funds")]
+    InvalidFunds {},
+
+    #[error("Invalid Amount")]
+    InvalidAmount {},
+
+    #[error("Invalid Slippage")]
+    InvalidSlippage {},
+
+    #[error("Not Found Exchange Rate")]
+    NotFoundExchangeRate {},
+
+    #[error("Already Compounded")]
+    AlreadyCompounded {},
+
+    #[error("Nothing To Compound")]
+    NothingToCompound {},
+
+    #[error("Nothing To Withdraw")]
+    NothingToWithdraw {},
+}
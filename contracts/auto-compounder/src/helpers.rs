@@ -0,0 +1,68 @@
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+
+use crate::ContractError;
+
+pub fn check_funds_and_get_amount(funds: &[Coin], denom: &str) -> Result<Uint128, ContractError> {
+    match funds {
+        [coin] if coin.denom == denom && !coin.amount.is_zero() => Ok(coin.amount),
+        _ => Err(ContractError::InvalidFunds {}),
+    }
+}
+
+pub fn check_max_slippage(max_slippage: u8) -> Result<(), ContractError> {
+    match max_slippage < 100 {
+        true => Ok(()),
+        false => Err(ContractError::InvalidSlippage {}),
+    }
+}
+
+pub fn check_self(contract: &Addr, sender: &Addr) -> Result<(), ContractError> {
+    match *contract == *sender {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
+}
+
+pub fn check_compound_epoch(
+    last_compound_epoch: Option<u64>,
+    epoch: u64,
+) -> Result<(), ContractError> {
+    let is_new_epoch = match last_compound_epoch {
+        Some(last_compound_epoch) => last_compound_epoch < epoch,
+        None => true,
+    };
+    match is_new_epoch {
+        true => Ok(()),
+        false => Err(ContractError::AlreadyCompounded {}),
+    }
+}
+
+//@@ the first deposit mints one share per lp , MINIMUM_SHARES of them stay locked
+pub fn compute_mint_shares(
+    lp_amount: Uint128,
+    total_lp: Uint128,
+    total_shares: Uint128,
+) -> Uint128 {
+    match total_shares.is_zero() || total_lp.is_zero() {
+        true => lp_amount,
+        false => lp_amount.multiply_ratio(total_shares, total_lp),
+    }
+}
+
+pub fn compute_share_lp(shares: Uint128, total_lp: Uint128, total_shares: Uint128) -> Uint128 {
+    match total_shares.is_zero() {
+        true => Uint128::zero(),
+        false => shares.multiply_ratio(total_lp, total_shares),
+    }
+}
+
+pub fn compute_share_price(total_lp: Uint128, total_shares: Uint128) -> Decimal {
+    match total_shares.is_zero() {
+        true => Decimal::one(),
+        false => Decimal::from_ratio(total_lp, total_shares),
+    }
+}
+
+pub fn min_return(oracle_return: Uint128, max_slippage: u8) -> Uint128 {
+    oracle_return.multiply_ratio(100 - max_slippage as u128, 100u128)
+}
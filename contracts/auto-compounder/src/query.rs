@@ -0,0 +1,74 @@
+use crate::ContractError;
+use axis_protocol::core::{
+    PairLpStakingContractResponse, PairPoolContractResponse, QueryMsg as CoreQueryMsg,
+};
+use axis_protocol::pool::{ConfigResponse as PoolConfigResponse, QueryMsg as PoolQueryMsg};
+use cosmwasm_std::{Addr, Decimal, QuerierWrapper, StdResult, Uint128};
+use sei_cosmwasm::{SeiQuerier, SeiQueryWrapper};
+
+pub fn query_pair_pool_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    base_denom: &String,
+    price_denom: &String,
+) -> StdResult<Addr> {
+    let core_res: PairPoolContractResponse = querier.query_wasm_smart(
+        core_contract.to_string(),
+        &CoreQueryMsg::GetPairPoolContract {
+            base_denom: base_denom.to_string(),
+            price_denom: price_denom.to_string(),
+        },
+    )?;
+    Ok(core_res.pool_contract)
+}
+
+pub fn query_pair_lp_staking_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    base_denom: &String,
+    price_denom: &String,
+) -> StdResult<Addr> {
+    let core_res: PairLpStakingContractResponse = querier.query_wasm_smart(
+        core_contract.to_string(),
+        &CoreQueryMsg::GetPairLpStakingContract {
+            base_denom: base_denom.to_string(),
+            price_denom: price_denom.to_string(),
+        },
+    )?;
+    Ok(core_res.lp_staking_contract)
+}
+
+pub fn query_lp_denom(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    pool_contract: &Addr,
+) -> StdResult<String> {
+    let pool_config: PoolConfigResponse =
+        querier.query_wasm_smart(pool_contract.to_string(), &PoolQueryMsg::GetConfig {})?;
+    Ok(pool_config.lp_denom)
+}
+
+pub fn query_exchange_rate(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    denom: &String,
+) -> Result<Decimal, ContractError> {
+    let querier = SeiQuerier::new(&querier);
+    let exchange_rate_res = querier.query_exchange_rates()?;
+    exchange_rate_res
+        .denom_oracle_exchange_rate_pairs
+        .iter()
+        .find(|d| d.denom == *denom)
+        .map(|d| d.oracle_exchange_rate.exchange_rate)
+        .ok_or(ContractError::NotFoundExchangeRate {})
+}
+
+//@@ ask_denom amount worth the offer at oracle prices
+pub fn query_oracle_return(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    offer_denom: &String,
+    offer_amount: Uint128,
+    ask_denom: &String,
+) -> Result<Uint128, ContractError> {
+    let offer_price = query_exchange_rate(querier, offer_denom)?;
+    let ask_price = query_exchange_rate(querier, ask_denom)?;
+    Ok(offer_amount.multiply_ratio(offer_price.atomics(), ask_price.atomics()))
+}
@@ -0,0 +1,171 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    pub core_contract: Addr,
+    pub lp_staking_contract: Addr,
+    pub pool_contract: Addr,
+    pub router_contract: Addr,
+    pub base_denom: String,
+    pub price_denom: String,
+    pub lp_denom: String,
+    pub axis_denom: String,
+    pub share_denom: String,
+    pub max_slippage: u8,
+}
+pub fn save_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
+    CONFIG.save(storage, config)
+}
+pub fn load_config(storage: &dyn Storage) -> StdResult<Config> {
+    CONFIG.load(storage)
+}
+
+#[cw_serde]
+pub struct State {
+    //lp staked for the share holders , pending stakes included
+    pub total_lp: Uint128,
+    pub total_shares: Uint128,
+    //lp unbonded and not withdrawn yet
+    pub unbonding_total: Uint128,
+    //unbonded lp already taken out of lp-staking , kept here for its owners
+    pub held_lp: Uint128,
+    pub last_compound_epoch: Option<u64>,
+    //lp staked at pending_epoch , lp-staking unstakes it from the next epoch
+    pub pending_lp: Uint128,
+    pub pending_epoch: u64,
+    //lp unbonded at queued_epoch over the active stakes , unstaked once its stakes start
+    pub queued_lp: Uint128,
+    pub queued_epoch: u64,
+}
+
+impl State {
+    pub fn add_pending_lp(&mut self, epoch: u64, lp_amount: Uint128) {
+        if self.pending_epoch < epoch {
+            self.pending_lp = Uint128::zero();
+            self.pending_epoch = epoch;
+        }
+        self.pending_lp += lp_amount;
+    }
+
+    //@@ lp that lp-staking can unstake now , the queued lp is taken from it first
+    pub fn active_lp(&self, epoch: u64) -> Uint128 {
+        match self.pending_epoch == epoch {
+            true => self.total_lp.saturating_sub(self.pending_lp),
+            false => self.total_lp,
+        }
+    }
+}
+pub fn save_state(storage: &mut dyn Storage, state: &State) -> StdResult<()> {
+    STATE.save(storage, state)
+}
+pub fn load_state(storage: &dyn Storage) -> StdResult<State> {
+    STATE.load(storage)
+}
+
+pub fn add_unbonding(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    unlock_epoch: u64,
+    lp_amount: Uint128,
+) -> StdResult<()> {
+    UNBONDING.update(storage, (owner, unlock_epoch), |exists| -> StdResult<_> {
+        Ok(exists.unwrap_or_default() + lp_amount)
+    })?;
+    UNBOND_EPOCH.update(storage, unlock_epoch, |exists| -> StdResult<_> {
+        Ok(exists.unwrap_or_default() + lp_amount)
+    })?;
+    Ok(())
+}
+
+pub fn add_queued_unbonding(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    queued_epoch: u64,
+    lp_amount: Uint128,
+) -> StdResult<()> {
+    QUEUED_UNBONDING.update(storage, (owner, queued_epoch), |exists| -> StdResult<_> {
+        Ok(exists.unwrap_or_default() + lp_amount)
+    })?;
+    Ok(())
+}
+
+//@@ the queue of the epoch is unstaked , its owners withdraw after the unlock epoch
+pub fn unlock_queued(
+    storage: &mut dyn Storage,
+    queued_epoch: u64,
+    unlock_epoch: u64,
+    lp_amount: Uint128,
+) -> StdResult<()> {
+    QUEUE_UNLOCK.save(storage, queued_epoch, &unlock_epoch)?;
+    UNBOND_EPOCH.update(storage, unlock_epoch, |exists| -> StdResult<_> {
+        Ok(exists.unwrap_or_default() + lp_amount)
+    })?;
+    Ok(())
+}
+
+//@@ unbondings withdrawable at the epoch are removed , return their lp
+pub fn take_unlocked(storage: &mut dyn Storage, owner: &Addr, epoch: u64) -> StdResult<Uint128> {
+    let unlocked = UNBONDING
+        .prefix(owner)
+        .range(
+            storage,
+            None,
+            Some(Bound::exclusive(epoch)),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<(u64, Uint128)>>>()?;
+    for (unlock_epoch, _) in unlocked.iter() {
+        UNBONDING.remove(storage, (owner, *unlock_epoch));
+    }
+    Ok(unlocked.iter().map(|(_, lp_amount)| *lp_amount).sum())
+}
+
+//@@ queued unbondings withdrawable at the epoch are removed , return their lp
+pub fn take_unlocked_queued(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    epoch: u64,
+) -> StdResult<Uint128> {
+    let queued = QUEUED_UNBONDING
+        .prefix(owner)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, Uint128)>>>()?;
+    let mut unlocked_amount = Uint128::zero();
+    for (queued_epoch, lp_amount) in queued.into_iter() {
+        let unlock_epoch = QUEUE_UNLOCK.may_load(storage, queued_epoch)?;
+        if matches!(unlock_epoch, Some(unlock_epoch) if unlock_epoch < epoch) {
+            QUEUED_UNBONDING.remove(storage, (owner, queued_epoch));
+            unlocked_amount += lp_amount;
+        }
+    }
+    Ok(unlocked_amount)
+}
+
+//@@ lp unlocked in lp-staking and not taken out yet , lp-staking Withdraw pays all of it
+pub fn take_unlocked_total(storage: &mut dyn Storage, epoch: u64) -> StdResult<Uint128> {
+    let unlocked = UNBOND_EPOCH
+        .range(
+            storage,
+            None,
+            Some(Bound::exclusive(epoch)),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<(u64, Uint128)>>>()?;
+    for (unlock_epoch, _) in unlocked.iter() {
+        UNBOND_EPOCH.remove(storage, *unlock_epoch);
+    }
+    Ok(unlocked.iter().map(|(_, lp_amount)| *lp_amount).sum())
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const STATE: Item<State> = Item::new("state");
+//(owner, unlock epoch) -> unbonded lp , withdrawable after the unlock epoch
+pub const UNBONDING: Map<(&Addr, u64), Uint128> = Map::new("unbonding");
+//unlock epoch -> unbonded lp still in lp-staking
+pub const UNBOND_EPOCH: Map<u64, Uint128> = Map::new("unbond_epoch");
+//(owner, queued epoch) -> unbonded lp waiting for its stakes to start
+pub const QUEUED_UNBONDING: Map<(&Addr, u64), Uint128> = Map::new("queued_unbonding");
+//queued epoch -> unlock epoch of the unstake of its queue
+pub const QUEUE_UNLOCK: Map<u64, u64> = Map::new("queue_unlock");
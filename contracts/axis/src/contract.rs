@@ -2,22 +2,31 @@ use axis_protocol::query::query_epoch;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coin, to_binary, BankMsg, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
-    StdResult, SubMsg, Uint128,
+    coin, to_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
 };
 use cw2::set_contract_version;
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 use crate::error::ContractError;
-use crate::state::{save_state, Config, State, CONFIG};
-use axis_protocol::axis::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::helpers::{
+    check_emission_schedule, check_vote_weight_rate, create_vesting_schedule, get_vesting_now,
+};
+use crate::state::{save_state, Config, State, CONFIG, MAX_TOTAL_SUPPLY, VESTING};
+use axis_protocol::axis::{
+    EmissionSchedule, ExecuteMsg, InstantiateMsg, QueryMsg, VestingClock, VestingParams,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:gmx";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-const MAX_TOTAL_SUPPLY: u128 = 1_000_000_000_000_000;
 const AXIS_DENOM: &str = "AXIS";
+const DEFAULT_HALVING_INTERVAL: u64 = 365;
+const DEFAULT_VESTING_CLIFF: u64 = 365;
+const DEFAULT_VESTING_DURATION: u64 = 365 * 4;
+//@@ keep a single trader claim bounded in gas
+const MAX_CLAIM_EPOCHS: usize = 100;
+const DEFAULT_VOTE_WEIGHT_RATE: u8 = 50;
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut<SeiQueryWrapper>,
@@ -28,67 +37,71 @@ pub fn instantiate(
     //@@Foundation amount
     let owner_mint_amount = Uint128::new(MAX_TOTAL_SUPPLY) * Decimal::percent(20);
     //@@Community amount
-    // let community_mint_amount = Uint128::new(MAX_TOTAL_SUPPLY) * Decimal::percent(20);
+    let community_mint_amount = Uint128::new(MAX_TOTAL_SUPPLY) * Decimal::percent(20);
     let axis_denom =
         "factory/".to_string() + env.contract.address.to_string().as_ref() + "/" + AXIS_DENOM;
 
+    let emission_schedule = msg.emission_schedule.unwrap_or(EmissionSchedule::Halving {
+        trader_amount: (Uint128::new(300_000_000_000_000) / Uint128::new(365 * 5)),
+        maker_amount: (Uint128::new(300_000_000_000_000) / Uint128::new(365 * 5)),
+        interval: DEFAULT_HALVING_INTERVAL,
+    });
+    check_emission_schedule(&emission_schedule)?;
+    let vote_weight_rate = msg.vote_weight_rate.unwrap_or(DEFAULT_VOTE_WEIGHT_RATE);
+    check_vote_weight_rate(vote_weight_rate)?;
     let config = Config {
         axis_denom: axis_denom.clone(),
         core_contract: info.sender.clone(),
-        mint_per_epoch_trader_amount: (Uint128::new(300_000_000_000_000) / Uint128::new(365 * 5)),
-        mint_per_epoch_maker_amount: (Uint128::new(300_000_000_000_000) / Uint128::new(365 * 5)),
+        owner: msg.owner.clone(),
+        emission_schedule,
+        vote_weight_rate,
     };
     CONFIG.save(deps.storage, &config)?;
     let epoch = query_epoch(deps.querier, &info.sender)?;
     let state = State {
         pending_total_fee_usd: Uint128::zero(),
         epoch,
+        total_minted: owner_mint_amount + community_mint_amount,
+        community_pool_amount: community_mint_amount,
+        total_burned: Uint128::zero(),
     };
     save_state(deps.storage, &state)?;
 
+    //@@ foundation amount stays in this contract until vested
+    let foundation_vesting = msg.foundation_vesting.unwrap_or(VestingParams {
+        clock: VestingClock::Epoch,
+        start: None,
+        cliff: DEFAULT_VESTING_CLIFF,
+        duration: DEFAULT_VESTING_DURATION,
+        revocable: false,
+    });
+    let now = get_vesting_now(&foundation_vesting.clock, &env, epoch);
+    let schedule = create_vesting_schedule(foundation_vesting, owner_mint_amount, now)?;
+    VESTING.save(deps.storage, &msg.owner, &schedule)?;
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     let axis_create_msg = SeiMsg::CreateDenom {
         subdenom: AXIS_DENOM.to_owned(),
     };
-    let mint_token = coin(owner_mint_amount.into(), axis_denom);
-    let axis_mint_msg = SeiMsg::MintTokens {
-        amount: mint_token.to_owned(),
-    };
-    let owner_send_msg = SubMsg::new(BankMsg::Send {
-        to_address: msg.owner.to_string(),
-        amount: vec![mint_token],
-    });
-
-    // let community_token = coin(community_mint_amount.into(), DENOM.to_owned());
-    // // @@ Wasm 으로 컨트랙트 만들고 돈보내주는게 좋은데...
-    // let community_send_msg = SubMsg::new(BankMsg::Send {
-    //     to_address: msg.community_contract.to_string(),
-    //     amount: vec![community_token],
-    // });
-    // let community_msg = SubMsg::new(WasmMsg::Instantiate {
-    //     admin: Some(env.contract.address.to_string()),
-    //     code_id: 1,
-    //     msg: &msg.community_init_msg,
-    //     funds: vec![community_token],
-    //     label: format!("{:?} community_airdrop", DENOM),
-    // });
-    //airdrop contract code 를 받아서 instantiate 해주고 reply 로 보내주자.
-
-    Ok(
-        Response::new()
-            .add_attribute("method", "instantiate")
-            .add_attribute("owner", info.sender)
-            .add_message(axis_create_msg)
-            .add_message(axis_mint_msg)
-            .add_submessage(owner_send_msg), // .add_submessage(community_send_msg)
-    )
+    //@@ community amount is held here and handed out through registered airdrops
+    let mint_token = coin(
+        (owner_mint_amount + community_mint_amount).into(),
+        axis_denom,
+    );
+    let axis_mint_msg = SeiMsg::MintTokens { amount: mint_token };
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender)
+        .add_message(axis_create_msg)
+        .add_message(axis_mint_msg))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut<SeiQueryWrapper>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<SeiMsg>, ContractError> {
@@ -99,9 +112,23 @@ pub fn execute(
             price_denom,
             trader,
             fee_usd_amount,
-        } => add_fee_amount(deps, info, base_denom, price_denom, trader, fee_usd_amount),
+            referrer,
+            referrer_fee_usd_amount,
+        } => add_fee_amount(
+            deps,
+            info,
+            base_denom,
+            price_denom,
+            trader,
+            fee_usd_amount,
+            referrer,
+            referrer_fee_usd_amount,
+        ),
         //ClaimMintTrader
-        ExecuteMsg::ClaimMintTrader {} => claim_minting_trader(deps, info),
+        ExecuteMsg::ClaimMintTrader {
+            start_epoch,
+            end_epoch,
+        } => claim_minting_trader(deps, info, start_epoch, end_epoch),
         //ClaimMintMaker
         ExecuteMsg::ClaimMintMaker {
             base_denom,
@@ -110,27 +137,55 @@ pub fn execute(
             amount,
         } => claim_minting_maker(deps, info, base_denom, price_denom, sender, amount),
         ExecuteMsg::Setting { epoch } => setting(deps, info, epoch),
-        // ExecuteMsg::RegisterAirDrop { air_drop_contract } => {
-        //     execute::register_airdrop(deps, info, air_drop_contract)
-        // }
+        ExecuteMsg::CreateVesting {
+            beneficiary,
+            params,
+        } => create_vesting(deps, env, info, beneficiary, params),
+        ExecuteMsg::RevokeVesting { beneficiary } => revoke_vesting(deps, env, info, beneficiary),
+        ExecuteMsg::ClaimVested {} => claim_vested(deps, env, info),
+        ExecuteMsg::RegisterAirDrop {
+            air_drop_contract,
+            amount,
+        } => register_airdrop(deps, env, info, air_drop_contract, amount),
+        ExecuteMsg::ReturnCommunity {} => return_community(deps, info),
+        ExecuteMsg::RegisterMinter { minter } => register_minter(deps, info, minter),
+        ExecuteMsg::RemoveMinter { minter } => remove_minter(deps, info, minter),
+        ExecuteMsg::Mint { recipient, amount } => mint(deps, info, recipient, amount),
+        ExecuteMsg::Burn {} => burn(deps, info),
+        ExecuteMsg::Vote { votes } => vote(deps, info, votes),
+        ExecuteMsg::UpdateVoteWeightRate { vote_weight_rate } => {
+            update_vote_weight_rate(deps, info, vote_weight_rate)
+        }
     }
 }
 
 pub mod execute {
+    use axis_protocol::axis::GaugeVote;
     use cosmwasm_std::{coin, Addr, BankMsg, Decimal, Order, SubMsg};
 
     use sei_cosmwasm::SeiQueryWrapper;
 
     use crate::{
-        helpers::{check_core_contract, check_lp_staking_contract, check_market_contract},
-        query::{query_pair_lp_staking_contract, query_pair_market_contract},
+        helpers::{
+            check_airdrop_axis_contract, check_core_contract, check_funds_and_get_amount,
+            check_gauge_votes, check_lp_staking_contract, check_market_contract, check_owner,
+            check_voting_power, compute_gauge_weights, get_claim_epoch_range, get_pool_key,
+            get_trader_mint_amount, get_vested_amount,
+        },
+        query::query_airdrop_axis_contract,
+        query::{
+            check_listed_pair, query_pair_lp_staking_contract, query_pair_market_contract,
+            query_voting_power,
+        },
         state::{
-            load_config, load_state, load_trader, update_pool_fee, update_trader, TraderTreasury,
-            EPOCH_TOTAL_FEE_AMOUNT, POOL_FEE, POOL_MINT_AMOUNT, TRADER,
+            load_config, load_gauge_votes, load_state, load_trader, load_vesting,
+            update_gauge_vote, update_pool_fee, update_total_minted, update_trader, Vote, AIRDROP,
+            EPOCH_TOTAL_FEE_AMOUNT, GAUGE_WEIGHT, MINTER, POOL_FEE, POOL_MINT_AMOUNT, TRADER, VOTE,
         },
     };
 
     use super::*;
+    #[allow(clippy::too_many_arguments)]
     pub fn add_fee_amount(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
@@ -138,6 +193,8 @@ pub mod execute {
         price_denom: String,
         trader: Addr,
         amount: Uint128,
+        referrer: Option<Addr>,
+        referrer_amount: Uint128,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         let mut state = load_state(deps.storage)?;
@@ -160,6 +217,11 @@ pub mod execute {
             amount,
         )?;
         state.pending_total_fee_usd += amount;
+        //@@ referrer shares the trader emissions of the epoch
+        if let (Some(referrer), false) = (referrer, referrer_amount.is_zero()) {
+            update_trader(deps.storage, &referrer, referrer_amount, epoch)?;
+            state.pending_total_fee_usd += referrer_amount;
+        }
         save_state(deps.storage, &state)?;
 
         Ok(Response::new())
@@ -168,27 +230,30 @@ pub mod execute {
     pub fn claim_minting_trader(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        start_epoch: Option<u64>,
+        end_epoch: Option<u64>,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
-        let traders = load_trader(deps.storage, &info.sender)?;
         let state = load_state(deps.storage)?;
-        let mint_amount: Uint128 = traders
-            .iter()
-            .filter(|treasury| treasury.epoch < state.epoch)
-            .map(|trader| {
-                let total_fee_amount = EPOCH_TOTAL_FEE_AMOUNT.load(deps.storage, trader.epoch)?;
-                let ratio = Decimal::from_ratio(trader.fee_amount, total_fee_amount);
-                Ok(config.mint_per_epoch_trader_amount * ratio)
-            })
-            .sum::<Result<Uint128, ContractError>>()?;
-
-        let remain_treasury = traders
-            .into_iter()
-            .filter(|treausry| treausry.epoch == state.epoch)
-            .collect::<Vec<TraderTreasury>>();
-
-        TRADER.save(deps.storage, &info.sender, &remain_treasury)?;
+        let treasuries = match get_claim_epoch_range(start_epoch, end_epoch, state.epoch) {
+            Some((start_epoch, end_epoch)) => load_trader(
+                deps.storage,
+                &info.sender,
+                start_epoch,
+                end_epoch,
+                MAX_CLAIM_EPOCHS,
+            )?,
+            None => vec![],
+        };
+        let mint_amount = get_trader_mint_amount(deps.storage, &config, &treasuries)?;
+        for treasury in treasuries.iter() {
+            TRADER.remove(deps.storage, (&info.sender, treasury.epoch));
+        }
 
+        let mint_amount = update_total_minted(deps.storage, mint_amount)?;
+        if mint_amount.is_zero() {
+            return Ok(Response::new().add_attribute("mint_amount", mint_amount));
+        }
         let token = coin(mint_amount.into(), config.axis_denom);
 
         let mint_msg = SeiMsg::MintTokens {
@@ -201,6 +266,8 @@ pub mod execute {
         });
 
         Ok(Response::new()
+            .add_attribute("mint_amount", mint_amount)
+            .add_attribute("claimed_epochs", treasuries.len().to_string())
             .add_message(mint_msg)
             .add_submessage(send_msg))
     }
@@ -226,6 +293,10 @@ pub mod execute {
 
         check_lp_staking_contract(&pair_lp_staking_contract, &info.sender)?;
 
+        let amount = update_total_minted(deps.storage, amount)?;
+        if amount.is_zero() {
+            return Ok(Response::new().add_attribute("mint_amount", amount));
+        }
         let axis_token = coin(amount.into(), config.axis_denom);
 
         let mint_msg = SeiMsg::MintTokens {
@@ -252,16 +323,20 @@ pub mod execute {
 
         EPOCH_TOTAL_FEE_AMOUNT.save(deps.storage, state.epoch, &state.pending_total_fee_usd)?;
 
-        let pairs = POOL_FEE
+        let pool_fees = POOL_FEE
             .range(deps.storage, None, None, Order::Ascending)
             .collect::<StdResult<Vec<(String, Uint128)>>>()?;
-
-        for (pair, pool_epoch_fee) in pairs {
-            let ratio = Decimal::from_ratio(pool_epoch_fee, state.pending_total_fee_usd);
-
-            let mint_amount = ratio * config.mint_per_epoch_maker_amount; // Compute the mint amount based on the ratio
-
-            POOL_MINT_AMOUNT.save(deps.storage, (&pair, epoch), &mint_amount)?;
+        let gauge_votes = load_gauge_votes(deps.storage, state.epoch)?;
+        let (_, maker_amount) = config.emission_schedule.rate(state.epoch);
+
+        for (pair, gauge_weight) in
+            compute_gauge_weights(&pool_fees, &gauge_votes, config.vote_weight_rate)
+        {
+            let mint_amount = gauge_weight.weight * maker_amount;
+            if !mint_amount.is_zero() {
+                POOL_MINT_AMOUNT.save(deps.storage, (&pair, epoch), &mint_amount)?;
+            }
+            GAUGE_WEIGHT.save(deps.storage, (state.epoch, &pair), &gauge_weight)?;
         }
 
         POOL_FEE.clear(deps.storage);
@@ -272,10 +347,299 @@ pub mod execute {
 
         Ok(Response::new())
     }
+    pub fn register_airdrop(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        info: MessageInfo,
+        air_drop_contract: String,
+        amount: Uint128,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        check_owner(&config.owner, &info.sender)?;
+        let air_drop_contract = deps.api.addr_validate(&air_drop_contract)?;
+        //@@ airdrop must pay out this axis denom
+        let airdrop_axis_contract = query_airdrop_axis_contract(deps.querier, &air_drop_contract)?;
+        check_airdrop_axis_contract(&airdrop_axis_contract, &env.contract.address)?;
+
+        let mut state = load_state(deps.storage)?;
+        if amount.is_zero() || amount > state.community_pool_amount {
+            return Err(ContractError::InsufficientCommunityPool {});
+        }
+        state.community_pool_amount -= amount;
+        save_state(deps.storage, &state)?;
+        AIRDROP.update(
+            deps.storage,
+            &air_drop_contract,
+            |exists| -> StdResult<Uint128> { Ok(exists.unwrap_or_default() + amount) },
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("method", "register_airdrop")
+            .add_attribute("air_drop_contract", air_drop_contract.to_string())
+            .add_attribute("amount", amount)
+            .add_message(BankMsg::Send {
+                to_address: air_drop_contract.to_string(),
+                amount: vec![coin(amount.into(), config.axis_denom)],
+            }))
+    }
+    pub fn return_community(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        if !AIRDROP.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+        let amount = check_funds_and_get_amount(&info.funds, &config.axis_denom)?;
+        let mut state = load_state(deps.storage)?;
+        state.community_pool_amount += amount;
+        save_state(deps.storage, &state)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "return_community")
+            .add_attribute("air_drop_contract", info.sender.to_string())
+            .add_attribute("amount", amount))
+    }
+    pub fn register_minter(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        minter: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        check_owner(&config.owner, &info.sender)?;
+        let minter = deps.api.addr_validate(&minter)?;
+        if !MINTER.has(deps.storage, &minter) {
+            MINTER.save(deps.storage, &minter, &Uint128::zero())?;
+        }
+        Ok(Response::new()
+            .add_attribute("method", "register_minter")
+            .add_attribute("minter", minter))
+    }
+    pub fn remove_minter(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        minter: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        check_owner(&config.owner, &info.sender)?;
+        let minter = deps.api.addr_validate(&minter)?;
+        MINTER.remove(deps.storage, &minter);
+        Ok(Response::new()
+            .add_attribute("method", "remove_minter")
+            .add_attribute("minter", minter))
+    }
+    pub fn mint(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        recipient: String,
+        amount: Uint128,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let minted_amount = MINTER
+            .may_load(deps.storage, &info.sender)?
+            .ok_or(ContractError::Unauthorized {})?;
+        let recipient = deps.api.addr_validate(&recipient)?;
+        //@@ no partial mint , the minter already burned what it converts
+        if update_total_minted(deps.storage, amount)? != amount {
+            return Err(ContractError::ExceedMaxSupply {});
+        }
+        MINTER.save(deps.storage, &info.sender, &(minted_amount + amount))?;
+
+        let token = coin(amount.into(), config.axis_denom);
+        Ok(Response::new()
+            .add_attribute("method", "mint")
+            .add_attribute("minter", info.sender)
+            .add_attribute("recipient", recipient.to_string())
+            .add_attribute("amount", amount)
+            .add_message(SeiMsg::MintTokens {
+                amount: token.to_owned(),
+            })
+            .add_message(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![token],
+            }))
+    }
+    pub fn burn(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let amount = check_funds_and_get_amount(&info.funds, &config.axis_denom)?;
+        let mut state = load_state(deps.storage)?;
+        state.total_burned += amount;
+        save_state(deps.storage, &state)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "burn")
+            .add_attribute("sender", info.sender)
+            .add_attribute("amount", amount)
+            .add_message(SeiMsg::BurnTokens {
+                amount: coin(amount.into(), config.axis_denom),
+            }))
+    }
+
+    //@@ voting power is taken when the vote is cast and counts for the current epoch only
+    pub fn vote(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        votes: Vec<GaugeVote>,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let state = load_state(deps.storage)?;
+        check_gauge_votes(&votes)?;
+        for vote in votes.iter() {
+            check_listed_pair(
+                deps.querier,
+                &config.core_contract,
+                &vote.base_denom,
+                &vote.price_denom,
+            )?;
+        }
+        let voting_power = query_voting_power(deps.querier, &config.core_contract, &info.sender)?;
+        check_voting_power(voting_power)?;
+
+        if let Some(prev) = VOTE.may_load(deps.storage, (state.epoch, &info.sender))? {
+            for vote in prev.votes.iter() {
+                let pool_key = get_pool_key(&vote.base_denom, &vote.price_denom);
+                let vote_amount = prev.voting_power * Decimal::percent(vote.weight as u64);
+                update_gauge_vote(
+                    deps.storage,
+                    state.epoch,
+                    &pool_key,
+                    Uint128::zero(),
+                    vote_amount,
+                )?;
+            }
+        }
+        for vote in votes.iter() {
+            let pool_key = get_pool_key(&vote.base_denom, &vote.price_denom);
+            let vote_amount = voting_power * Decimal::percent(vote.weight as u64);
+            update_gauge_vote(
+                deps.storage,
+                state.epoch,
+                &pool_key,
+                vote_amount,
+                Uint128::zero(),
+            )?;
+        }
+        VOTE.save(
+            deps.storage,
+            (state.epoch, &info.sender),
+            &Vote {
+                voting_power,
+                votes,
+            },
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("method", "vote")
+            .add_attribute("voter", info.sender)
+            .add_attribute("epoch", state.epoch.to_string())
+            .add_attribute("voting_power", voting_power))
+    }
+
+    pub fn update_vote_weight_rate(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        vote_weight_rate: u8,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+        check_owner(&config.owner, &info.sender)?;
+        check_vote_weight_rate(vote_weight_rate)?;
+        config.vote_weight_rate = vote_weight_rate;
+        CONFIG.save(deps.storage, &config)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "update_vote_weight_rate")
+            .add_attribute("vote_weight_rate", vote_weight_rate.to_string()))
+    }
+
+    pub fn create_vesting(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        info: MessageInfo,
+        beneficiary: String,
+        params: VestingParams,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        check_owner(&config.owner, &info.sender)?;
+        let amount = check_funds_and_get_amount(&info.funds, &config.axis_denom)?;
+        let beneficiary = deps.api.addr_validate(&beneficiary)?;
+        if VESTING.has(deps.storage, &beneficiary) {
+            return Err(ContractError::VestingAlreadyExists {});
+        }
+        let state = load_state(deps.storage)?;
+        let now = get_vesting_now(&params.clock, &env, state.epoch);
+        let schedule = create_vesting_schedule(params, amount, now)?;
+        VESTING.save(deps.storage, &beneficiary, &schedule)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "create_vesting")
+            .add_attribute("beneficiary", beneficiary)
+            .add_attribute("amount", amount))
+    }
+    pub fn revoke_vesting(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        info: MessageInfo,
+        beneficiary: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        check_owner(&config.owner, &info.sender)?;
+        let beneficiary = deps.api.addr_validate(&beneficiary)?;
+        let mut schedule = load_vesting(deps.storage, &beneficiary)?;
+        if !schedule.revocable || schedule.revoked {
+            return Err(ContractError::NotRevocable {});
+        }
+        let state = load_state(deps.storage)?;
+        let now = get_vesting_now(&schedule.clock, &env, state.epoch);
+        //@@ already vested amount stays claimable by the beneficiary
+        let vested_amount = get_vested_amount(&schedule, now);
+        let unvested_amount = schedule.total_amount - vested_amount;
+        schedule.total_amount = vested_amount;
+        schedule.revoked = true;
+        VESTING.save(deps.storage, &beneficiary, &schedule)?;
+
+        let mut response = Response::new()
+            .add_attribute("method", "revoke_vesting")
+            .add_attribute("beneficiary", beneficiary)
+            .add_attribute("unvested_amount", unvested_amount);
+        if !unvested_amount.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: config.owner.to_string(),
+                amount: vec![coin(unvested_amount.into(), config.axis_denom)],
+            });
+        }
+        Ok(response)
+    }
+    pub fn claim_vested(
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let mut schedule = load_vesting(deps.storage, &info.sender)?;
+        let state = load_state(deps.storage)?;
+        let now = get_vesting_now(&schedule.clock, &env, state.epoch);
+        let claim_amount = get_vested_amount(&schedule, now) - schedule.claimed_amount;
+        if claim_amount.is_zero() {
+            return Ok(Response::new().add_attribute("claim_amount", claim_amount));
+        }
+        schedule.claimed_amount += claim_amount;
+        VESTING.save(deps.storage, &info.sender, &schedule)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "claim_vested")
+            .add_attribute("claim_amount", claim_amount)
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![coin(claim_amount.into(), config.axis_denom)],
+            }))
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps<SeiQueryWrapper>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetConfig {} => to_binary(&query::get_config(deps)?),
         QueryMsg::GetPendingTotalFee {} => to_binary(&query::get_pending_fee(deps)?),
@@ -294,28 +658,240 @@ pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult
             start_epoch,
             end_epoch,
         } => to_binary(&query::get_epoch_total_fee(deps, start_epoch, end_epoch)?),
+        QueryMsg::GetEmissionRate { epoch } => to_binary(&query::get_emission_rate(deps, epoch)?),
+        QueryMsg::GetRemainingEmission {} => to_binary(&query::get_remaining_emission(deps)?),
+        QueryMsg::GetTraderClaimable { address } => {
+            to_binary(&query::get_trader_claimable(deps, address)?)
+        }
+        QueryMsg::GetCommunityPool {} => to_binary(&query::get_community_pool(deps)?),
+        QueryMsg::GetAirDrop { address } => to_binary(&query::get_air_drop(deps, address)?),
+        QueryMsg::GetVestingSchedule { address } => {
+            to_binary(&query::get_vesting_schedule(deps, env, address)?)
+        }
+        QueryMsg::GetMinters {} => to_binary(&query::get_minters(deps)?),
+        QueryMsg::GetVote { address, epoch } => to_binary(&query::get_vote(deps, address, epoch)?),
+        QueryMsg::GetGaugeWeights { epoch } => to_binary(&query::get_gauge_weights(deps, epoch)?),
     }
 }
 
 pub mod query {
 
     use super::*;
-    use crate::state::{load_config, load_state, EPOCH_TOTAL_FEE_AMOUNT, POOL_MINT_AMOUNT};
+    use crate::helpers::{
+        compute_gauge_weights, get_claim_epoch_range, get_trader_mint_amount, get_vested_amount,
+        split_pool_key,
+    };
+    use crate::state::{
+        load_config, load_gauge_votes, load_state, load_trader, load_vesting, GaugeWeight, AIRDROP,
+        EPOCH_TOTAL_FEE_AMOUNT, GAUGE_WEIGHT, MINTER, POOL_FEE, POOL_MINT_AMOUNT, TRADER, VOTE,
+    };
     use axis_protocol::axis::{
-        ConfigResponse, EpochTotalFeeAmountResponse, PendingFeeResponse,
-        PoolAllowedMintAmountResponse, TotalSupplyResponse,
+        AirDropResponse, CommunityPoolResponse, ConfigResponse, EmissionRateResponse,
+        EpochTotalFeeAmountResponse, GaugeWeightResponse, MinterResponse, PendingFeeResponse,
+        PoolAllowedMintAmountResponse, RemainingEmissionResponse, TotalSupplyResponse,
+        TraderClaimableResponse, VestingScheduleResponse, VoteResponse,
     };
     use cosmwasm_std::Order;
     use cw_storage_plus::Bound;
 
     pub fn get_config(deps: Deps<SeiQueryWrapper>) -> StdResult<ConfigResponse> {
         let config = load_config(deps.storage)?;
+        let state = load_state(deps.storage)?;
+        let (trader_amount, maker_amount) = config.emission_schedule.rate(state.epoch);
 
         Ok(ConfigResponse {
             core_contract: config.core_contract.to_string(),
             axis_denom: config.axis_denom.to_string(),
-            mint_per_epoch_maker_amount: config.mint_per_epoch_maker_amount,
-            mint_per_epoch_trader_amount: config.mint_per_epoch_trader_amount,
+            mint_per_epoch_maker_amount: maker_amount,
+            mint_per_epoch_trader_amount: trader_amount,
+            emission_schedule: config.emission_schedule,
+            vote_weight_rate: config.vote_weight_rate,
+        })
+    }
+
+    pub fn get_emission_rate(
+        deps: Deps<SeiQueryWrapper>,
+        epoch: Option<u64>,
+    ) -> StdResult<EmissionRateResponse> {
+        let config = load_config(deps.storage)?;
+        let epoch = match epoch {
+            Some(epoch) => epoch,
+            None => load_state(deps.storage)?.epoch,
+        };
+        let (trader_amount, maker_amount) = config.emission_schedule.rate(epoch);
+        Ok(EmissionRateResponse {
+            epoch,
+            trader_amount,
+            maker_amount,
+        })
+    }
+
+    pub fn get_remaining_emission(
+        deps: Deps<SeiQueryWrapper>,
+    ) -> StdResult<RemainingEmissionResponse> {
+        let state = load_state(deps.storage)?;
+        let max_total_supply = Uint128::new(MAX_TOTAL_SUPPLY);
+        Ok(RemainingEmissionResponse {
+            max_total_supply,
+            total_minted: state.total_minted,
+            remaining_amount: max_total_supply - state.total_minted,
+        })
+    }
+
+    pub fn get_trader_claimable(
+        deps: Deps<SeiQueryWrapper>,
+        address: String,
+    ) -> StdResult<TraderClaimableResponse> {
+        let trader = deps.api.addr_validate(&address)?;
+        let config = load_config(deps.storage)?;
+        let state = load_state(deps.storage)?;
+        //@@ same epochs a ClaimMintTrader without a range would pay
+        let treasuries = match get_claim_epoch_range(None, None, state.epoch) {
+            Some((start_epoch, end_epoch)) => load_trader(
+                deps.storage,
+                &trader,
+                start_epoch,
+                end_epoch,
+                MAX_CLAIM_EPOCHS,
+            )?,
+            None => vec![],
+        };
+        let mint_amount = get_trader_mint_amount(deps.storage, &config, &treasuries)?;
+        let remaining_amount = Uint128::new(MAX_TOTAL_SUPPLY) - state.total_minted;
+        let pending_fee_amount = TRADER
+            .may_load(deps.storage, (&trader, state.epoch))?
+            .unwrap_or_default();
+
+        Ok(TraderClaimableResponse {
+            claimable_amount: Uint128::min(mint_amount, remaining_amount),
+            start_epoch: treasuries.first().map(|treasury| treasury.epoch),
+            end_epoch: treasuries.last().map(|treasury| treasury.epoch),
+            pending_fee_amount,
+        })
+    }
+
+    pub fn get_community_pool(deps: Deps<SeiQueryWrapper>) -> StdResult<CommunityPoolResponse> {
+        let state = load_state(deps.storage)?;
+        let air_drops = AIRDROP
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| {
+                let (air_drop_contract, amount) = item?;
+                Ok(AirDropResponse {
+                    air_drop_contract: air_drop_contract.to_string(),
+                    amount,
+                })
+            })
+            .collect::<StdResult<Vec<AirDropResponse>>>()?;
+        Ok(CommunityPoolResponse {
+            community_pool_amount: state.community_pool_amount,
+            air_drops,
+        })
+    }
+
+    pub fn get_air_drop(
+        deps: Deps<SeiQueryWrapper>,
+        address: String,
+    ) -> StdResult<Option<AirDropResponse>> {
+        let air_drop_contract = deps.api.addr_validate(&address)?;
+        Ok(AIRDROP
+            .may_load(deps.storage, &air_drop_contract)?
+            .map(|amount| AirDropResponse {
+                air_drop_contract: air_drop_contract.to_string(),
+                amount,
+            }))
+    }
+
+    pub fn get_vote(
+        deps: Deps<SeiQueryWrapper>,
+        address: String,
+        epoch: Option<u64>,
+    ) -> StdResult<VoteResponse> {
+        let voter = deps.api.addr_validate(&address)?;
+        let epoch = match epoch {
+            Some(epoch) => epoch,
+            None => load_state(deps.storage)?.epoch,
+        };
+        let vote = VOTE.may_load(deps.storage, (epoch, &voter))?;
+        Ok(VoteResponse {
+            epoch,
+            voting_power: vote
+                .as_ref()
+                .map(|vote| vote.voting_power)
+                .unwrap_or_default(),
+            votes: vote.map(|vote| vote.votes).unwrap_or_default(),
+        })
+    }
+
+    //@@ ended epochs read the snapshot , the current epoch is computed from what is known so far
+    pub fn get_gauge_weights(
+        deps: Deps<SeiQueryWrapper>,
+        epoch: Option<u64>,
+    ) -> StdResult<Vec<GaugeWeightResponse>> {
+        let config = load_config(deps.storage)?;
+        let state = load_state(deps.storage)?;
+        let gauge_weights: Vec<(String, GaugeWeight)> = match epoch {
+            Some(epoch) if epoch != state.epoch => GAUGE_WEIGHT
+                .prefix(epoch)
+                .range(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<_>>()?,
+            _ => {
+                let pool_fees = POOL_FEE
+                    .range(deps.storage, None, None, Order::Ascending)
+                    .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+                let gauge_votes = load_gauge_votes(deps.storage, state.epoch)?;
+                compute_gauge_weights(&pool_fees, &gauge_votes, config.vote_weight_rate)
+            }
+        };
+        Ok(gauge_weights
+            .into_iter()
+            .map(|(pool_key, gauge_weight)| {
+                let (base_denom, price_denom) = split_pool_key(&pool_key);
+                GaugeWeightResponse {
+                    base_denom,
+                    price_denom,
+                    fee_amount: gauge_weight.fee_amount,
+                    vote_amount: gauge_weight.vote_amount,
+                    weight: gauge_weight.weight,
+                }
+            })
+            .collect())
+    }
+
+    pub fn get_minters(deps: Deps<SeiQueryWrapper>) -> StdResult<Vec<MinterResponse>> {
+        MINTER
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| {
+                let (minter, minted_amount) = item?;
+                Ok(MinterResponse {
+                    minter,
+                    minted_amount,
+                })
+            })
+            .collect()
+    }
+
+    pub fn get_vesting_schedule(
+        deps: Deps<SeiQueryWrapper>,
+        env: Env,
+        address: String,
+    ) -> StdResult<VestingScheduleResponse> {
+        let beneficiary = deps.api.addr_validate(&address)?;
+        let schedule = load_vesting(deps.storage, &beneficiary)?;
+        let state = load_state(deps.storage)?;
+        let now = get_vesting_now(&schedule.clock, &env, state.epoch);
+        let vested_amount = get_vested_amount(&schedule, now);
+        Ok(VestingScheduleResponse {
+            beneficiary: beneficiary.to_string(),
+            total_amount: schedule.total_amount,
+            vested_amount,
+            claimed_amount: schedule.claimed_amount,
+            claimable_amount: vested_amount - schedule.claimed_amount,
+            clock: schedule.clock,
+            start: schedule.start,
+            cliff: schedule.cliff,
+            duration: schedule.duration,
+            revocable: schedule.revocable,
+            revoked: schedule.revoked,
         })
     }
 
@@ -352,12 +928,13 @@ pub mod query {
         Ok(allow_mint_amounts)
     }
 
+    //@@ every mint and burn goes through this contract , so the ledger is the bank supply
     pub fn get_total_supply(deps: Deps<SeiQueryWrapper>) -> StdResult<TotalSupplyResponse> {
         let config = load_config(deps.storage)?;
-        let coin = deps.querier.query_supply(config.axis_denom)?;
+        let state = load_state(deps.storage)?;
         Ok(TotalSupplyResponse {
-            denom: coin.denom,
-            total_supply: coin.amount,
+            denom: config.axis_denom,
+            total_supply: state.total_minted - state.total_burned,
         })
     }
 
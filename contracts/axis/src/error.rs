@@ -11,4 +11,37 @@ pub enum ContractError {
 
     #[error("Not Time")]
     NotTime {},
+
+    #[error("Invalid Emission Schedule")]
+    InvalidEmissionSchedule {},
+
+    #[error("Invalid Vesting Schedule")]
+    InvalidVestingSchedule {},
+
+    #[error("Vesting Already Exists")]
+    VestingAlreadyExists {},
+
+    #[error("Not Revocable")]
+    NotRevocable {},
+
+    #[error("Insufficient Community Pool")]
+    InsufficientCommunityPool {},
+
+    #[error("Invalid Funds")]
+    InvalidFunds {},
+
+    #[error("Exceed Max Supply")]
+    ExceedMaxSupply {},
+
+    #[error("Invalid Vote")]
+    InvalidVote {},
+
+    #[error("Invalid Vote Weight Rate")]
+    InvalidVoteWeightRate {},
+
+    #[error("Invalid Pair")]
+    InvalidPair {},
+
+    #[error("No Voting Power")]
+    NoVotingPower {},
 }
@@ -1,6 +1,119 @@
-use cosmwasm_std::Addr;
+use std::collections::BTreeMap;
 
-use crate::error::ContractError;
+use axis_protocol::axis::{EmissionSchedule, GaugeVote, VestingClock, VestingParams};
+use cosmwasm_std::{Addr, Coin, Decimal, Env, StdResult, Storage, Uint128};
+
+use crate::{
+    error::ContractError,
+    state::{Config, GaugeWeight, TraderTreasury, VestingSchedule, EPOCH_TOTAL_FEE_AMOUNT},
+};
+
+//@@ current epoch is still collecting fees , only settled epochs are claimable
+pub fn get_claim_epoch_range(
+    start_epoch: Option<u64>,
+    end_epoch: Option<u64>,
+    current_epoch: u64,
+) -> Option<(u64, u64)> {
+    let last_settled_epoch = current_epoch.checked_sub(1)?;
+    let start_epoch = start_epoch.unwrap_or(0);
+    let end_epoch = u64::min(end_epoch.unwrap_or(last_settled_epoch), last_settled_epoch);
+    match start_epoch <= end_epoch {
+        true => Some((start_epoch, end_epoch)),
+        false => None,
+    }
+}
+
+pub fn get_trader_mint_amount(
+    storage: &dyn Storage,
+    config: &Config,
+    treasuries: &[TraderTreasury],
+) -> StdResult<Uint128> {
+    treasuries
+        .iter()
+        .map(|treasury| {
+            let total_fee_amount = EPOCH_TOTAL_FEE_AMOUNT.load(storage, treasury.epoch)?;
+            let ratio = Decimal::from_ratio(treasury.fee_amount, total_fee_amount);
+            let (trader_amount, _) = config.emission_schedule.rate(treasury.epoch);
+            Ok(trader_amount * ratio)
+        })
+        .sum()
+}
+
+//current point of the vesting clock
+pub fn get_vesting_now(clock: &VestingClock, env: &Env, epoch: u64) -> u64 {
+    match clock {
+        VestingClock::Epoch => epoch,
+        VestingClock::Time => env.block.time.seconds(),
+    }
+}
+
+pub fn get_vested_amount(schedule: &VestingSchedule, now: u64) -> Uint128 {
+    if now < schedule.start + schedule.cliff {
+        return Uint128::zero();
+    }
+    let elapsed = now - schedule.start;
+    match elapsed >= schedule.duration {
+        true => schedule.total_amount,
+        false => schedule
+            .total_amount
+            .multiply_ratio(elapsed, schedule.duration),
+    }
+}
+
+pub fn create_vesting_schedule(
+    params: VestingParams,
+    total_amount: Uint128,
+    now: u64,
+) -> Result<VestingSchedule, ContractError> {
+    check_vesting_params(&params)?;
+    Ok(VestingSchedule {
+        total_amount,
+        claimed_amount: Uint128::zero(),
+        start: params.start.unwrap_or(now),
+        clock: params.clock,
+        cliff: params.cliff,
+        duration: params.duration,
+        revocable: params.revocable,
+        revoked: false,
+    })
+}
+
+pub fn check_vesting_params(params: &VestingParams) -> Result<(), ContractError> {
+    match params.cliff <= params.duration {
+        true => Ok(()),
+        false => Err(ContractError::InvalidVestingSchedule {}),
+    }
+}
+
+pub fn check_owner(owner: &Addr, sender: &Addr) -> Result<(), ContractError> {
+    match *owner == *sender {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
+}
+
+pub fn check_funds_and_get_amount(funds: &[Coin], denom: &str) -> Result<Uint128, ContractError> {
+    match funds {
+        [coin] if coin.denom == denom && !coin.amount.is_zero() => Ok(coin.amount),
+        _ => Err(ContractError::InvalidFunds {}),
+    }
+}
+
+pub fn check_emission_schedule(emission_schedule: &EmissionSchedule) -> Result<(), ContractError> {
+    let valid = match emission_schedule {
+        EmissionSchedule::Halving { interval, .. } => *interval > 0,
+        EmissionSchedule::Piecewise { steps } => {
+            !steps.is_empty()
+                && steps
+                    .windows(2)
+                    .all(|step| step[0].start_epoch < step[1].start_epoch)
+        }
+    };
+    match valid {
+        true => Ok(()),
+        false => Err(ContractError::InvalidEmissionSchedule {}),
+    }
+}
 
 pub fn check_market_contract(market_contract: &Addr, sender: &Addr) -> Result<(), ContractError> {
     match *market_contract == *sender {
@@ -31,3 +144,94 @@ pub fn check_lp_staking_contract(
         false => Err(ContractError::Unauthorized {}),
     }
 }
+
+pub fn check_airdrop_axis_contract(
+    airdrop_axis_contract: &Addr,
+    axis_contract: &Addr,
+) -> Result<(), ContractError> {
+    match *airdrop_axis_contract == *axis_contract {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
+}
+
+pub fn check_vote_weight_rate(vote_weight_rate: u8) -> Result<(), ContractError> {
+    match vote_weight_rate <= 100 {
+        true => Ok(()),
+        false => Err(ContractError::InvalidVoteWeightRate {}),
+    }
+}
+
+//@@ every weight is positive , the sum is at most 100 and a pool is voted once
+pub fn check_gauge_votes(votes: &[GaugeVote]) -> Result<(), ContractError> {
+    let total_weight: u64 = votes.iter().map(|vote| vote.weight as u64).sum();
+    let duplicated = votes.iter().enumerate().any(|(i, vote)| {
+        votes[..i]
+            .iter()
+            .any(|prev| prev.base_denom == vote.base_denom && prev.price_denom == vote.price_denom)
+    });
+    match votes.iter().all(|vote| vote.weight > 0) && total_weight <= 100 && !duplicated {
+        true => Ok(()),
+        false => Err(ContractError::InvalidVote {}),
+    }
+}
+
+pub fn check_voting_power(voting_power: Uint128) -> Result<(), ContractError> {
+    match !voting_power.is_zero() {
+        true => Ok(()),
+        false => Err(ContractError::NoVotingPower {}),
+    }
+}
+
+pub fn get_pool_key(base_denom: &str, price_denom: &str) -> String {
+    format!("{}:{}", base_denom, price_denom)
+}
+
+pub fn split_pool_key(pool_key: &str) -> (String, String) {
+    let (base_denom, price_denom) = pool_key.split_once(':').unwrap_or((pool_key, ""));
+    (base_denom.to_string(), price_denom.to_string())
+}
+
+//@@ weight = fee share * (100 - rate)% + vote share * rate%
+//@@ an epoch without votes (or without fees) gives the whole emission to the other side
+pub fn compute_gauge_weights(
+    pool_fees: &[(String, Uint128)],
+    gauge_votes: &[(String, Uint128)],
+    vote_weight_rate: u8,
+) -> Vec<(String, GaugeWeight)> {
+    //@@ only pool fees are shared , referrer fees count for the trader emissions alone
+    let total_fee: Uint128 = pool_fees.iter().map(|(_, amount)| *amount).sum();
+    let total_vote: Uint128 = gauge_votes.iter().map(|(_, amount)| *amount).sum();
+    let vote_weight_rate = match (total_fee.is_zero(), total_vote.is_zero()) {
+        (_, true) => 0,
+        (true, false) => 100,
+        (false, false) => vote_weight_rate as u64,
+    };
+    let share = |amount: Uint128, total: Uint128| match total.is_zero() {
+        true => Decimal::zero(),
+        false => Decimal::from_ratio(amount, total),
+    };
+
+    let mut pools: BTreeMap<String, (Uint128, Uint128)> = BTreeMap::new();
+    for (pool_key, fee_amount) in pool_fees {
+        pools.entry(pool_key.clone()).or_default().0 += *fee_amount;
+    }
+    for (pool_key, vote_amount) in gauge_votes {
+        pools.entry(pool_key.clone()).or_default().1 += *vote_amount;
+    }
+    pools
+        .into_iter()
+        .map(|(pool_key, (fee_amount, vote_amount))| {
+            let weight = share(fee_amount, total_fee) * Decimal::percent(100 - vote_weight_rate)
+                + share(vote_amount, total_vote) * Decimal::percent(vote_weight_rate);
+            (
+                pool_key,
+                GaugeWeight {
+                    fee_amount,
+                    vote_amount,
+                    weight,
+                },
+            )
+        })
+        .collect()
+}
@@ -1,8 +1,14 @@
+use axis_protocol::airdrop::{
+    ConfigResponse as AirDropConfigResponse, QueryMsg as AirDropQueryMsg,
+};
 use axis_protocol::core::{
-    PairLpStakingContractResponse, PairMarketContractResponse, QueryMsg as CoreQueryMsg,
+    DelistedPairResponse, PairLpStakingContractResponse, PairMarketContractResponse,
+    QueryMsg as CoreQueryMsg,
 };
+use axis_protocol::query::query_staking_contract;
+use axis_protocol::staking::{QueryMsg as StakingQueryMsg, StakeInfoResponse};
 
-use cosmwasm_std::{Addr, QuerierWrapper};
+use cosmwasm_std::{Addr, QuerierWrapper, Uint128};
 use sei_cosmwasm::SeiQueryWrapper;
 
 use crate::error::ContractError;
@@ -46,13 +52,73 @@ pub fn query_pair_lp_staking_contract(
     base_denom: &String,
     price_denom: &String,
 ) -> Result<Addr, ContractError> {
-    let core_res: PairLpStakingContractResponse = querier.query_wasm_smart(
+    let core_res: Result<PairLpStakingContractResponse, _> = querier.query_wasm_smart(
         core_contract,
         &CoreQueryMsg::GetPairLpStakingContract {
             base_denom: base_denom.to_string(),
             price_denom: price_denom.to_string(),
         },
+    );
+    if let Ok(core_res) = core_res {
+        return Ok(core_res.lp_staking_contract);
+    }
+    //@@ lp stakers of a delisted pair can still claim what they earned
+    let delisted_res: DelistedPairResponse = querier.query_wasm_smart(
+        core_contract,
+        &CoreQueryMsg::GetDelistedPair {
+            base_denom: base_denom.to_string(),
+            price_denom: price_denom.to_string(),
+        },
     )?;
 
-    Ok(core_res.lp_staking_contract)
+    Ok(delisted_res.lp_staking_contract)
+}
+
+pub fn query_airdrop_axis_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    air_drop_contract: &Addr,
+) -> Result<Addr, ContractError> {
+    let air_drop_res: AirDropConfigResponse =
+        querier.query_wasm_smart(air_drop_contract, &AirDropQueryMsg::GetConfig {})?;
+    Ok(Addr::unchecked(air_drop_res.axis_contract))
+}
+
+//@@ only pairs still listed in core can be voted
+pub fn check_listed_pair(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    base_denom: &str,
+    price_denom: &str,
+) -> Result<(), ContractError> {
+    let core_res: Result<PairLpStakingContractResponse, _> = querier.query_wasm_smart(
+        core_contract,
+        &CoreQueryMsg::GetPairLpStakingContract {
+            base_denom: base_denom.to_string(),
+            price_denom: price_denom.to_string(),
+        },
+    );
+    match core_res.is_ok() {
+        true => Ok(()),
+        false => Err(ContractError::InvalidPair {}),
+    }
+}
+
+//@@ AXIS staked in the staking contract , pending stakes included
+pub fn query_voting_power(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    voter: &Addr,
+) -> Result<Uint128, ContractError> {
+    let staking_contract = query_staking_contract(querier, core_contract)?;
+    let stake_info: StakeInfoResponse = querier.query_wasm_smart(
+        staking_contract,
+        &StakingQueryMsg::GetStakeInfo {
+            addr: voter.to_string(),
+        },
+    )?;
+    Ok(stake_info
+        .stake_infos
+        .iter()
+        .map(|stake| stake.staking_amount)
+        .sum())
 }
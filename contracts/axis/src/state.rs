@@ -1,15 +1,18 @@
+use axis_protocol::axis::{EmissionSchedule, GaugeVote, VestingClock};
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cosmwasm_std::{Addr, Decimal, Order, StdResult, Storage, Uint128};
 
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Bound, Item, Map};
 
 #[cw_serde]
 pub struct Config {
     pub core_contract: Addr,
+    pub owner: Addr,
     pub axis_denom: String,
-    pub mint_per_epoch_trader_amount: Uint128,
-    pub mint_per_epoch_maker_amount: Uint128,
+    pub emission_schedule: EmissionSchedule,
+    //percent of the maker emission split by gauge votes , the rest follows the fee share
+    pub vote_weight_rate: u8,
 }
 
 pub fn load_config(storage: &dyn Storage) -> StdResult<Config> {
@@ -19,6 +22,12 @@ pub fn load_config(storage: &dyn Storage) -> StdResult<Config> {
 pub struct State {
     pub epoch: u64,
     pub pending_total_fee_usd: Uint128,
+    //cumulative amount minted , never above MAX_TOTAL_SUPPLY
+    pub total_minted: Uint128,
+    //community amount not handed to an airdrop yet
+    pub community_pool_amount: Uint128,
+    //cumulative amount burned , total supply is total_minted - total_burned
+    pub total_burned: Uint128,
 }
 pub fn save_state(storage: &mut dyn Storage, state: &State) -> StdResult<()> {
     STATE.save(storage, state)
@@ -27,6 +36,32 @@ pub fn load_state(storage: &dyn Storage) -> StdResult<State> {
     STATE.load(storage)
 }
 
+//@@ every mint path goes through here , return the amount clamped to the remaining supply
+pub fn update_total_minted(storage: &mut dyn Storage, amount: Uint128) -> StdResult<Uint128> {
+    let mut state = load_state(storage)?;
+    let remaining_amount = Uint128::new(MAX_TOTAL_SUPPLY) - state.total_minted;
+    let mint_amount = Uint128::min(amount, remaining_amount);
+    state.total_minted += mint_amount;
+    save_state(storage, &state)?;
+    Ok(mint_amount)
+}
+
+#[cw_serde]
+pub struct VestingSchedule {
+    pub total_amount: Uint128,
+    pub claimed_amount: Uint128,
+    pub clock: VestingClock,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub revocable: bool,
+    pub revoked: bool,
+}
+
+pub fn load_vesting(storage: &dyn Storage, beneficiary: &Addr) -> StdResult<VestingSchedule> {
+    VESTING.load(storage, beneficiary)
+}
+
 #[cw_serde]
 pub struct TraderTreasury {
     pub epoch: u64,
@@ -39,38 +74,33 @@ pub fn update_trader(
     amount: Uint128,
     epoch: u64,
 ) -> StdResult<()> {
-    TRADER.update(
-        storage,
-        trader,
-        |exsists| -> StdResult<Vec<TraderTreasury>> {
-            match exsists {
-                Some(mut traders) => {
-                    if let Some(trader) = traders.iter_mut().find(|t| t.epoch == epoch) {
-                        trader.fee_amount += amount;
-                    } else {
-                        let new_trader = TraderTreasury {
-                            epoch,
-                            fee_amount: amount,
-                        };
-                        traders.push(new_trader);
-                    }
-                    Ok(traders)
-                }
-                None => {
-                    let traders = vec![TraderTreasury {
-                        epoch,
-                        fee_amount: amount,
-                    }];
-
-                    Ok(traders)
-                }
-            }
-        },
-    )?;
+    TRADER.update(storage, (trader, epoch), |exsists| -> StdResult<Uint128> {
+        Ok(exsists.unwrap_or_default() + amount)
+    })?;
     Ok(())
 }
-pub fn load_trader(storage: &mut dyn Storage, minter: &Addr) -> StdResult<Vec<TraderTreasury>> {
-    TRADER.load(storage, minter)
+//@@ fee records of the trader in [start_epoch, end_epoch] , at most limit of them
+pub fn load_trader(
+    storage: &dyn Storage,
+    trader: &Addr,
+    start_epoch: u64,
+    end_epoch: u64,
+    limit: usize,
+) -> StdResult<Vec<TraderTreasury>> {
+    TRADER
+        .prefix(trader)
+        .range(
+            storage,
+            Some(Bound::inclusive(start_epoch)),
+            Some(Bound::inclusive(end_epoch)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            let (epoch, fee_amount) = item?;
+            Ok(TraderTreasury { epoch, fee_amount })
+        })
+        .collect()
 }
 
 pub fn update_pool_fee(
@@ -86,7 +116,50 @@ pub fn update_pool_fee(
     })?;
     Ok(())
 }
-pub const TRADER: Map<&Addr, Vec<TraderTreasury>> = Map::new("trader");
+#[cw_serde]
+pub struct Vote {
+    //staked AXIS when the vote was cast
+    pub voting_power: Uint128,
+    pub votes: Vec<GaugeVote>,
+}
+
+#[cw_serde]
+pub struct GaugeWeight {
+    pub fee_amount: Uint128,
+    pub vote_amount: Uint128,
+    pub weight: Decimal,
+}
+
+pub fn update_gauge_vote(
+    storage: &mut dyn Storage,
+    epoch: u64,
+    pool_key: &str,
+    add_amount: Uint128,
+    sub_amount: Uint128,
+) -> StdResult<()> {
+    let vote_amount = GAUGE_VOTE
+        .may_load(storage, (epoch, pool_key))?
+        .unwrap_or_default()
+        + add_amount
+        - sub_amount;
+    match vote_amount.is_zero() {
+        true => GAUGE_VOTE.remove(storage, (epoch, pool_key)),
+        false => GAUGE_VOTE.save(storage, (epoch, pool_key), &vote_amount)?,
+    }
+    Ok(())
+}
+
+pub fn load_gauge_votes(storage: &dyn Storage, epoch: u64) -> StdResult<Vec<(String, Uint128)>> {
+    GAUGE_VOTE
+        .prefix(epoch)
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+pub const MAX_TOTAL_SUPPLY: u128 = 1_000_000_000_000_000;
+
+//fee usd amount per (trader, epoch) , removed once claimed
+pub const TRADER: Map<(&Addr, u64), Uint128> = Map::new("trader_fee");
 
 //pool key is ubtc:usdc, epoch
 
@@ -96,5 +169,17 @@ pub const POOL_FEE: Map<&str, Uint128> = Map::new("pool_fee");
 //pool 이 가져갈 수 있는 민팅양
 pub const POOL_MINT_AMOUNT: Map<(&str, u64), Uint128> = Map::new("pool_mint_amount");
 pub const EPOCH_TOTAL_FEE_AMOUNT: Map<u64, Uint128> = Map::new("total_fee_amount");
+//vested tokens are held by this contract until claimed
+pub const VESTING: Map<&Addr, VestingSchedule> = Map::new("vesting");
+//amount sent to each registered airdrop contract
+pub const AIRDROP: Map<&Addr, Uint128> = Map::new("airdrop");
+//minter -> amount minted by it
+pub const MINTER: Map<&Addr, Uint128> = Map::new("minter");
+//(epoch, voter) -> vote
+pub const VOTE: Map<(u64, &Addr), Vote> = Map::new("vote");
+//(epoch, pool key) -> voting power put on the pool
+pub const GAUGE_VOTE: Map<(u64, &str), Uint128> = Map::new("gauge_vote");
+//(epoch, pool key) -> weight snapshot taken by the setting that ends the epoch
+pub const GAUGE_WEIGHT: Map<(u64, &str), GaugeWeight> = Map::new("gauge_weight");
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const STATE: Item<State> = Item::new("state");
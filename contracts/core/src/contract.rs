@@ -1,26 +1,29 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, SubMsg,
-    SubMsgResult, Timestamp, WasmMsg,
+    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult,
+    SubMsg, SubMsgResult, Timestamp, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 use crate::error::ContractError;
 
-use crate::helpers::find_attribute_value;
+use crate::helpers::{check_fee_tiers, check_referral_tiers, find_attribute_value};
 use crate::state::{
     register_axis_contract, Config, CONFIG, PAIR_MARKET_CONTRACT, PAIR_POOL,
     PAIR_POOL_LP_STAKING_CONTRACT,
 };
 use axis_protocol::axis::InstantiateMsg as AxisInstantiateMsg;
-use axis_protocol::core::{ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+use axis_protocol::core::{
+    ConfigResponse, ExecuteMsg, FeeTier, InstantiateMsg, QueryMsg, ReferralTier,
+};
 use axis_protocol::lp_staking::ExecuteMsg as LpStakingExecuteMsg;
 use axis_protocol::pool::{
     ConfigResponse as PoolConfigReponse, ExecuteMsg as PoolExecuteMsg,
-    InstantiateMsg as PoolInstantiateMsg, QueryMsg as PoolQueryMsg,
+    InstantiateMsg as PoolInstantiateMsg, PauseMode, QueryMsg as PoolQueryMsg,
 };
+use axis_protocol::vault::ExecuteMsg as VaultExecuteMsg;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:core";
@@ -35,6 +38,47 @@ pub fn instantiate(
 ) -> Result<Response<SeiMsg>, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let referral_tiers = msg.referral_tiers.unwrap_or(vec![
+        ReferralTier {
+            min_trader_count: 0,
+            rebate_rate: 5,
+            referrer_rate: 5,
+            emission_rate: 10,
+        },
+        ReferralTier {
+            min_trader_count: 10,
+            rebate_rate: 10,
+            referrer_rate: 10,
+            emission_rate: 20,
+        },
+        ReferralTier {
+            min_trader_count: 50,
+            rebate_rate: 10,
+            referrer_rate: 15,
+            emission_rate: 30,
+        },
+    ]);
+    check_referral_tiers(&referral_tiers)?;
+    //AXIS has 6 decimals
+    let fee_tiers = msg.fee_tiers.unwrap_or(vec![
+        FeeTier {
+            min_axis_amount: Uint128::zero(),
+            discount_rate: 0,
+        },
+        FeeTier {
+            min_axis_amount: Uint128::new(1_000_000_000),
+            discount_rate: 5,
+        },
+        FeeTier {
+            min_axis_amount: Uint128::new(10_000_000_000),
+            discount_rate: 10,
+        },
+        FeeTier {
+            min_axis_amount: Uint128::new(100_000_000_000),
+            discount_rate: 20,
+        },
+    ]);
+    check_fee_tiers(&fee_tiers)?;
     //next update is UTC 00
     let config = Config {
         owner: info.sender.clone(),
@@ -44,6 +88,8 @@ pub fn instantiate(
         staking_contract: Addr::unchecked(""),
         vault_contract: Addr::unchecked(""),
         next_update_timestamp: Timestamp::from_nanos(msg.next_update_timestamp),
+        referral_tiers,
+        fee_tiers,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -54,6 +100,9 @@ pub fn instantiate(
         msg: to_binary(&AxisInstantiateMsg {
             core_contract: env.contract.address,
             owner: info.sender.to_owned(),
+            emission_schedule: msg.emission_schedule,
+            foundation_vesting: msg.foundation_vesting,
+            vote_weight_rate: None,
         })?,
         funds: vec![],
         label: format!("axis"),
@@ -81,21 +130,50 @@ pub fn execute(
         ExecuteMsg::RegisterPriceDenom { price_denom } => {
             execute::register_price_denom(deps, info, price_denom)
         }
-        ExecuteMsg::AllPoolLock {} => execute::all_pool_lock(deps, info),
-        ExecuteMsg::PairLock {
+        ExecuteMsg::AllPoolSetMode { mode } => execute::all_pool_set_mode(deps, info, mode),
+        ExecuteMsg::PairSetMode {
             base_denom,
             price_denom,
-        } => execute::pair_pool_lock(deps, info, base_denom, price_denom),
-        ExecuteMsg::AllPoolUnLock {} => execute::all_pool_unlock(deps, info),
-        ExecuteMsg::PairUnLock {
+            mode,
+        } => execute::pair_pool_set_mode(deps, info, base_denom, price_denom, mode),
+        ExecuteMsg::PairResetBreaker {
             base_denom,
             price_denom,
-        } => execute::pair_pool_un_lock(deps, info, base_denom, price_denom),
+        } => execute::pair_reset_breaker(deps, info, base_denom, price_denom),
+        ExecuteMsg::DelistPair {
+            base_denom,
+            price_denom,
+        } => execute::delist_pair(deps, info, base_denom, price_denom),
         ExecuteMsg::Setting {} => execute::setting(deps, env, info),
         ExecuteMsg::UpdateConfig {
             vault_contract,
             staking_contract,
         } => execute::update_config(deps, info, vault_contract, staking_contract),
+        ExecuteMsg::UpdateFeeTiers { tiers } => execute::update_fee_tiers(deps, info, tiers),
+        ExecuteMsg::UpdateReferralTiers { tiers } => {
+            execute::update_referral_tiers(deps, info, tiers)
+        }
+        ExecuteMsg::RegisterReferralCode { code } => {
+            execute::register_referral_code(deps, info, code)
+        }
+        ExecuteMsg::SetReferralCode { code } => execute::set_referral_code(deps, info, code),
+        ExecuteMsg::AddReferralFee {
+            base_denom,
+            price_denom,
+            trader,
+            fee_usd_amount,
+            rebate_usd_amount,
+            reward_usd_amount,
+        } => execute::add_referral_fee(
+            deps,
+            info,
+            base_denom,
+            price_denom,
+            trader,
+            fee_usd_amount,
+            rebate_usd_amount,
+            reward_usd_amount,
+        ),
     }
 }
 
@@ -111,8 +189,14 @@ pub mod execute {
     use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
     use crate::{
-        helpers::{check_denom_and_get_validate_denom, check_owner, check_valid_price},
-        state::{check_pair, load_config, load_pair, save_config, PAIR_POOL},
+        helpers::{
+            check_denom_and_get_validate_denom, check_owner, check_referral_code, check_set_mode,
+            check_valid_price,
+        },
+        state::{
+            check_pair, load_config, load_pair, save_config, DelistedPair, ReferralStats,
+            DELISTED_PAIR, PAIR_POOL, REFERRAL_CODE, REFERRER, TRADER_REFERRAL,
+        },
     };
 
     use super::*;
@@ -168,52 +252,59 @@ pub mod execute {
         Ok(Response::new())
     }
 
-    pub fn all_pool_lock(
+    pub fn all_pool_set_mode(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        mode: PauseMode,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         check_owner(&info.sender, &config.owner)?;
-        let pair_lock_msgs = PAIR_POOL
+        check_set_mode(&mode)?;
+        let pair_mode_msgs = PAIR_POOL
             .range(deps.storage, None, None, Order::Ascending)
             .map(|item| {
                 let (_, pair_addr) = item?;
-                let pool_lock_msg = PoolExecuteMsg::Lock {};
+                let pool_mode_msg = PoolExecuteMsg::SetMode {
+                    mode: mode.to_owned(),
+                };
 
                 Ok(CosmosMsg::Wasm(WasmMsg::Execute {
                     contract_addr: pair_addr.to_string(),
-                    msg: to_binary(&pool_lock_msg)?,
+                    msg: to_binary(&pool_mode_msg)?,
                     funds: vec![],
                 }))
             })
             .collect::<StdResult<Vec<CosmosMsg<SeiMsg>>>>()?;
 
-        Ok(Response::new().add_messages(pair_lock_msgs))
+        Ok(Response::new()
+            .add_attribute("mode", format!("{:?}", mode))
+            .add_messages(pair_mode_msgs))
     }
-    pub fn all_pool_unlock(
+
+    pub fn pair_pool_set_mode(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        base_denom: String,
+        price_denom: String,
+        mode: PauseMode,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         check_owner(&info.sender, &config.owner)?;
-        let pair_lock_msgs = PAIR_POOL
-            .range(deps.storage, None, None, Order::Ascending)
-            .map(|item| {
-                let (_, pair_addr) = item?;
-                let pool_lock_msg = PoolExecuteMsg::UnLock {};
-
-                Ok(CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: pair_addr.to_string(),
-                    msg: to_binary(&pool_lock_msg)?,
-                    funds: vec![],
-                }))
-            })
-            .collect::<StdResult<Vec<CosmosMsg<SeiMsg>>>>()?;
-
-        Ok(Response::new().add_messages(pair_lock_msgs))
+        check_set_mode(&mode)?;
+        let pair_addr = load_pair(deps.storage, &base_denom, &price_denom)?;
+        let pool_mode_msg = PoolExecuteMsg::SetMode {
+            mode: mode.to_owned(),
+        };
+        let pool_mode_tx = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: pair_addr.to_string(),
+            msg: to_binary(&pool_mode_msg)?,
+            funds: vec![],
+        });
+        Ok(Response::new()
+            .add_attribute("mode", format!("{:?}", mode))
+            .add_message(pool_mode_tx))
     }
-
-    pub fn pair_pool_lock(
+    pub fn pair_reset_breaker(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
         base_denom: String,
@@ -222,15 +313,15 @@ pub mod execute {
         let config = load_config(deps.storage)?;
         check_owner(&info.sender, &config.owner)?;
         let pair_addr = load_pair(deps.storage, &base_denom, &price_denom)?;
-        let pool_lock_msg = PoolExecuteMsg::Lock {};
-        let pool_lock_tx = CosmosMsg::Wasm(WasmMsg::Execute {
+        let pool_reset_msg = PoolExecuteMsg::ResetBreaker {};
+        let pool_reset_tx = CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: pair_addr.to_string(),
-            msg: to_binary(&pool_lock_msg)?,
+            msg: to_binary(&pool_reset_msg)?,
             funds: vec![],
         });
-        Ok(Response::new().add_message(pool_lock_tx))
+        Ok(Response::new().add_message(pool_reset_tx))
     }
-    pub fn pair_pool_un_lock(
+    pub fn delist_pair(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
         base_denom: String,
@@ -238,14 +329,35 @@ pub mod execute {
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         check_owner(&info.sender, &config.owner)?;
-        let pair_addr = load_pair(deps.storage, &base_denom, &price_denom)?;
-        let pool_unlock_msg = PoolExecuteMsg::UnLock {};
-        let pool_lock_tx = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: pair_addr.to_string(),
-            msg: to_binary(&pool_unlock_msg)?,
+        let key = (&base_denom, &price_denom);
+        let pool_contract = load_pair(deps.storage, &base_denom, &price_denom)?;
+        let market_contract = PAIR_MARKET_CONTRACT.load(deps.storage, key)?;
+        let lp_staking_contract = PAIR_POOL_LP_STAKING_CONTRACT.load(deps.storage, key)?;
+
+        //@@ removing the lp staking contract also stops its setting fan-out
+        PAIR_POOL.remove(deps.storage, key);
+        PAIR_MARKET_CONTRACT.remove(deps.storage, key);
+        PAIR_POOL_LP_STAKING_CONTRACT.remove(deps.storage, key);
+        DELISTED_PAIR.save(
+            deps.storage,
+            key,
+            &DelistedPair {
+                pool_contract: pool_contract.to_owned(),
+                market_contract,
+                lp_staking_contract,
+            },
+        )?;
+
+        let pool_delist_tx = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: pool_contract.to_string(),
+            msg: to_binary(&PoolExecuteMsg::Delist {})?,
             funds: vec![],
         });
-        Ok(Response::new().add_message(pool_lock_tx))
+        Ok(Response::new()
+            .add_attribute("method", "delist_pair")
+            .add_attribute("base_denom", base_denom)
+            .add_attribute("price_denom", price_denom)
+            .add_message(pool_delist_tx))
     }
     pub fn setting(
         deps: DepsMut<SeiQueryWrapper>,
@@ -330,6 +442,116 @@ pub mod execute {
         save_config(deps.storage, &config)?;
         Ok(Response::new())
     }
+    pub fn update_fee_tiers(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        tiers: Vec<FeeTier>,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+        check_owner(&info.sender, &config.owner)?;
+        check_fee_tiers(&tiers)?;
+        config.fee_tiers = tiers;
+        save_config(deps.storage, &config)?;
+        Ok(Response::new().add_attribute("method", "update_fee_tiers"))
+    }
+    pub fn update_referral_tiers(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        tiers: Vec<ReferralTier>,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+        check_owner(&info.sender, &config.owner)?;
+        check_referral_tiers(&tiers)?;
+        config.referral_tiers = tiers;
+        save_config(deps.storage, &config)?;
+        Ok(Response::new().add_attribute("method", "update_referral_tiers"))
+    }
+    pub fn register_referral_code(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        code: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        check_referral_code(&code)?;
+        if REFERRAL_CODE.has(deps.storage, &code) || REFERRER.has(deps.storage, &info.sender) {
+            return Err(ContractError::ReferralCodeExists {});
+        }
+        REFERRAL_CODE.save(deps.storage, &code, &info.sender)?;
+        REFERRER.save(
+            deps.storage,
+            &info.sender,
+            &ReferralStats {
+                code: code.to_owned(),
+                trader_count: 0,
+                total_fee_usd_amount: Uint128::zero(),
+                total_rebate_usd_amount: Uint128::zero(),
+                total_reward_usd_amount: Uint128::zero(),
+            },
+        )?;
+        Ok(Response::new()
+            .add_attribute("method", "register_referral_code")
+            .add_attribute("referrer", info.sender)
+            .add_attribute("code", code))
+    }
+    pub fn set_referral_code(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        code: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        if TRADER_REFERRAL.has(deps.storage, &info.sender) {
+            return Err(ContractError::ReferralAlreadySet {});
+        }
+        let referrer = REFERRAL_CODE
+            .may_load(deps.storage, &code)?
+            .ok_or(ContractError::InvalidReferralCode {})?;
+        if referrer == info.sender {
+            return Err(ContractError::InvalidReferralCode {});
+        }
+        TRADER_REFERRAL.save(deps.storage, &info.sender, &code)?;
+        REFERRER.update(
+            deps.storage,
+            &referrer,
+            |stats| -> StdResult<ReferralStats> {
+                let mut stats = stats.ok_or_else(|| StdError::not_found("referrer"))?;
+                stats.trader_count += 1;
+                Ok(stats)
+            },
+        )?;
+        Ok(Response::new()
+            .add_attribute("method", "set_referral_code")
+            .add_attribute("trader", info.sender)
+            .add_attribute("referrer", referrer))
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_referral_fee(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        base_denom: String,
+        price_denom: String,
+        trader: Addr,
+        fee_usd_amount: Uint128,
+        rebate_usd_amount: Uint128,
+        reward_usd_amount: Uint128,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let market_contract =
+            PAIR_MARKET_CONTRACT.load(deps.storage, (&base_denom, &price_denom))?;
+        if market_contract != info.sender {
+            return Err(ContractError::Unauthorized {});
+        }
+        let code = TRADER_REFERRAL.load(deps.storage, &trader)?;
+        let referrer = REFERRAL_CODE.load(deps.storage, &code)?;
+        REFERRER.update(
+            deps.storage,
+            &referrer,
+            |stats| -> StdResult<ReferralStats> {
+                let mut stats = stats.ok_or_else(|| StdError::not_found("referrer"))?;
+                stats.total_fee_usd_amount += fee_usd_amount;
+                stats.total_rebate_usd_amount += rebate_usd_amount;
+                stats.total_reward_usd_amount += reward_usd_amount;
+                Ok(stats)
+            },
+        )?;
+        Ok(Response::new())
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -360,16 +582,39 @@ pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult
             base_denom,
             price_denom,
         )?),
+        QueryMsg::GetFeeTiers {} => to_binary(&query::get_fee_tiers(deps)?),
+        QueryMsg::GetTraderFeeTier { trader } => {
+            to_binary(&query::get_trader_fee_tier(deps, trader)?)
+        }
+        QueryMsg::GetReferralTiers {} => to_binary(&query::get_referral_tiers(deps)?),
+        QueryMsg::GetTraderReferral { trader } => {
+            to_binary(&query::get_trader_referral(deps, trader)?)
+        }
+        QueryMsg::GetReferralStats { referrer } => {
+            to_binary(&query::get_referral_stats(deps, referrer)?)
+        }
+        QueryMsg::GetDelistedPair {
+            base_denom,
+            price_denom,
+        } => to_binary(&query::get_delisted_pair(deps, base_denom, price_denom)?),
     }
 }
 
 pub mod query {
     use axis_protocol::core::{
-        PairLpStakingContractResponse, PairMarketContractResponse, PairPoolContractResponse,
+        DelistedPairResponse, PairLpStakingContractResponse, PairMarketContractResponse,
+        PairPoolContractResponse, ReferralStatsResponse, TraderFeeTierResponse,
+        TraderReferralResponse,
+    };
+    use axis_protocol::es_axis::QueryMsg as EsAxisQueryMsg;
+    use axis_protocol::staking::{
+        ConfigResponse as StakingConfigResponse, QueryMsg as StakingQueryMsg, StakeInfoResponse,
     };
 
+    use crate::helpers::{get_fee_tier, get_referral_tier};
     use crate::state::{
-        load_config, load_pair, PAIR_MARKET_CONTRACT, PAIR_POOL_LP_STAKING_CONTRACT,
+        load_config, load_pair, DELISTED_PAIR, PAIR_MARKET_CONTRACT, PAIR_POOL_LP_STAKING_CONTRACT,
+        REFERRAL_CODE, REFERRER, TRADER_REFERRAL,
     };
 
     use super::*;
@@ -414,6 +659,129 @@ pub mod query {
         })
     }
 
+    pub fn get_delisted_pair(
+        deps: Deps<SeiQueryWrapper>,
+        base_denom: String,
+        price_denom: String,
+    ) -> StdResult<DelistedPairResponse> {
+        let delisted_pair = DELISTED_PAIR.load(deps.storage, (&base_denom, &price_denom))?;
+        Ok(DelistedPairResponse {
+            base_denom,
+            price_denom,
+            pool_contract: delisted_pair.pool_contract,
+            market_contract: delisted_pair.market_contract,
+            lp_staking_contract: delisted_pair.lp_staking_contract,
+        })
+    }
+
+    pub fn get_fee_tiers(deps: Deps<SeiQueryWrapper>) -> StdResult<Vec<FeeTier>> {
+        Ok(load_config(deps.storage)?.fee_tiers)
+    }
+
+    pub fn get_trader_fee_tier(
+        deps: Deps<SeiQueryWrapper>,
+        trader: String,
+    ) -> StdResult<TraderFeeTierResponse> {
+        let trader = deps.api.addr_validate(&trader)?;
+        let config = load_config(deps.storage)?;
+        //@@ staking is registered after instantiate , no staking contract is the base tier
+        let (staked_axis_amount, es_axis_amount) =
+            match config.staking_contract == Addr::unchecked("") {
+                true => (Uint128::zero(), Uint128::zero()),
+                false => {
+                    let stake_info: StakeInfoResponse = deps.querier.query_wasm_smart(
+                        config.staking_contract.to_string(),
+                        &StakingQueryMsg::GetStakeInfo {
+                            addr: trader.to_string(),
+                        },
+                    )?;
+                    let staking_config: StakingConfigResponse = deps.querier.query_wasm_smart(
+                        config.staking_contract.to_string(),
+                        &StakingQueryMsg::GetConfig {},
+                    )?;
+                    let es_axis_balance: Uint128 = deps.querier.query_wasm_smart(
+                        staking_config.es_axis_contract.to_string(),
+                        &EsAxisQueryMsg::GetBalance {
+                            address: trader.to_string(),
+                        },
+                    )?;
+                    (
+                        stake_info
+                            .stake_infos
+                            .iter()
+                            .map(|stake| stake.staking_amount)
+                            .sum(),
+                        es_axis_balance,
+                    )
+                }
+            };
+        let (tier, fee_tier) = get_fee_tier(&config.fee_tiers, staked_axis_amount + es_axis_amount);
+        Ok(TraderFeeTierResponse {
+            tier,
+            discount_rate: fee_tier.discount_rate,
+            staked_axis_amount,
+            es_axis_amount,
+        })
+    }
+
+    pub fn get_referral_tiers(deps: Deps<SeiQueryWrapper>) -> StdResult<Vec<ReferralTier>> {
+        Ok(load_config(deps.storage)?.referral_tiers)
+    }
+
+    pub fn get_trader_referral(
+        deps: Deps<SeiQueryWrapper>,
+        trader: String,
+    ) -> StdResult<TraderReferralResponse> {
+        let trader = deps.api.addr_validate(&trader)?;
+        let config = load_config(deps.storage)?;
+        let code = TRADER_REFERRAL.may_load(deps.storage, &trader)?;
+        let referrer = match &code {
+            Some(code) => Some(REFERRAL_CODE.load(deps.storage, code)?),
+            None => None,
+        };
+        let tier = match &referrer {
+            Some(referrer) => {
+                let stats = REFERRER.load(deps.storage, referrer)?;
+                Some(get_referral_tier(
+                    &config.referral_tiers,
+                    stats.trader_count,
+                ))
+            }
+            None => None,
+        };
+        Ok(TraderReferralResponse {
+            code,
+            referrer,
+            rebate_rate: tier.as_ref().map_or(0, |tier| tier.rebate_rate),
+            referrer_rate: tier.as_ref().map_or(0, |tier| tier.referrer_rate),
+            emission_rate: tier.as_ref().map_or(0, |tier| tier.emission_rate),
+        })
+    }
+
+    pub fn get_referral_stats(
+        deps: Deps<SeiQueryWrapper>,
+        referrer: String,
+    ) -> StdResult<ReferralStatsResponse> {
+        let referrer = deps.api.addr_validate(&referrer)?;
+        let config = load_config(deps.storage)?;
+        let stats = REFERRER.may_load(deps.storage, &referrer)?;
+        let trader_count = stats.as_ref().map_or(0, |stats| stats.trader_count);
+        Ok(ReferralStatsResponse {
+            code: stats.as_ref().map(|stats| stats.code.to_owned()),
+            trader_count,
+            tier: get_referral_tier(&config.referral_tiers, trader_count),
+            total_fee_usd_amount: stats
+                .as_ref()
+                .map_or(Uint128::zero(), |stats| stats.total_fee_usd_amount),
+            total_rebate_usd_amount: stats
+                .as_ref()
+                .map_or(Uint128::zero(), |stats| stats.total_rebate_usd_amount),
+            total_reward_usd_amount: stats
+                .as_ref()
+                .map_or(Uint128::zero(), |stats| stats.total_reward_usd_amount),
+        })
+    }
+
     pub fn get_config(deps: Deps<SeiQueryWrapper>) -> StdResult<ConfigResponse> {
         let config = load_config(deps.storage)?;
         Ok(ConfigResponse {
@@ -469,7 +837,23 @@ pub fn reply(
                         key,
                         &pool_config.lp_staking_contract,
                     )?;
-                    Ok(Response::new())
+
+                    //@@ the vault rejects fees in denoms it doesn't list
+                    let config = CONFIG.load(deps.storage)?;
+                    if config.vault_contract == Addr::unchecked("") {
+                        return Ok(Response::new());
+                    }
+                    let add_denom_msgs = [base_denom, price_denom]
+                        .into_iter()
+                        .map(|denom| {
+                            Ok(WasmMsg::Execute {
+                                contract_addr: config.vault_contract.to_string(),
+                                msg: to_binary(&VaultExecuteMsg::AddDenom { denom })?,
+                                funds: vec![],
+                            })
+                        })
+                        .collect::<StdResult<Vec<WasmMsg>>>()?;
+                    Ok(Response::new().add_messages(add_denom_msgs))
                 }
                 None => Err(ContractError::MissingPoolContractAddr {}),
             },
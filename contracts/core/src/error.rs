@@ -29,4 +29,16 @@ pub enum ContractError {
     MissingAxisContractAddr {},
     #[error("Invalid Reply ID")]
     InvalidReplyId {},
+    #[error("Invalid Fee Tiers")]
+    InvalidFeeTiers {},
+    #[error("Invalid Referral Tiers")]
+    InvalidReferralTiers {},
+    #[error("Invalid Referral Code")]
+    InvalidReferralCode {},
+    #[error("Referral Code already exists")]
+    ReferralCodeExists {},
+    #[error("Referral Code already set")]
+    ReferralAlreadySet {},
+    #[error("Delisted mode is set by delist only")]
+    InvalidMode {},
 }
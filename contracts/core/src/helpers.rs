@@ -1,4 +1,6 @@
-use cosmwasm_std::{Addr, Attribute, Coin};
+use axis_protocol::core::{FeeTier, ReferralTier};
+use axis_protocol::pool::PauseMode;
+use cosmwasm_std::{Addr, Attribute, Coin, Uint128};
 
 use crate::{state::Config, ContractError};
 
@@ -9,6 +11,13 @@ pub fn check_owner(sender: &Addr, owner: &Addr) -> Result<(), ContractError> {
     }
 }
 
+pub fn check_set_mode(mode: &PauseMode) -> Result<(), ContractError> {
+    match mode.can_set_mode() {
+        true => Ok(()),
+        false => Err(ContractError::InvalidMode {}),
+    }
+}
+
 pub fn check_valid_price(config: &Config, price_denom: &String) -> Result<(), ContractError> {
     match config.accept_price_denoms.contains(price_denom) {
         true => Ok(()),
@@ -43,3 +52,70 @@ pub fn find_attribute_value(
     }
     Err(ContractError::PoolContractInstantiationFailed {})
 }
+
+//@@ rebate and referrer share come out of the fee , the rest still goes to pool / vault / insurance
+const MAX_REFERRAL_RATE: u8 = 50;
+const MAX_REFERRAL_CODE_LENGTH: usize = 32;
+
+pub fn check_referral_tiers(tiers: &[ReferralTier]) -> Result<(), ContractError> {
+    let valid = tiers.first().map(|tier| tier.min_trader_count) == Some(0)
+        && tiers
+            .windows(2)
+            .all(|tier| tier[0].min_trader_count < tier[1].min_trader_count)
+        && tiers.iter().all(|tier| {
+            tier.rebate_rate as u16 + tier.referrer_rate as u16 <= MAX_REFERRAL_RATE as u16
+                && tier.emission_rate <= 100
+        });
+    match valid {
+        true => Ok(()),
+        false => Err(ContractError::InvalidReferralTiers {}),
+    }
+}
+
+pub fn check_referral_code(code: &str) -> Result<(), ContractError> {
+    match !code.is_empty()
+        && code.len() <= MAX_REFERRAL_CODE_LENGTH
+        && code.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        true => Ok(()),
+        false => Err(ContractError::InvalidReferralCode {}),
+    }
+}
+
+//highest tier the referrer reached
+pub fn get_referral_tier(tiers: &[ReferralTier], trader_count: u64) -> ReferralTier {
+    tiers
+        .iter()
+        .rev()
+        .find(|tier| tier.min_trader_count <= trader_count)
+        .unwrap_or(&tiers[0])
+        .to_owned()
+}
+
+//@@ discount comes out of the whole fee before the referral split
+const MAX_FEE_DISCOUNT_RATE: u8 = 50;
+
+pub fn check_fee_tiers(tiers: &[FeeTier]) -> Result<(), ContractError> {
+    let valid = tiers.first().map(|tier| tier.min_axis_amount) == Some(Uint128::zero())
+        && tiers
+            .windows(2)
+            .all(|tier| tier[0].min_axis_amount < tier[1].min_axis_amount)
+        && tiers
+            .iter()
+            .all(|tier| tier.discount_rate <= MAX_FEE_DISCOUNT_RATE);
+    match valid {
+        true => Ok(()),
+        false => Err(ContractError::InvalidFeeTiers {}),
+    }
+}
+
+//(index , tier) of the highest tier the amount reached
+pub fn get_fee_tier(tiers: &[FeeTier], axis_amount: Uint128) -> (u8, FeeTier) {
+    tiers
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(_, tier)| tier.min_axis_amount <= axis_amount)
+        .map(|(index, tier)| (index as u8, tier.to_owned()))
+        .unwrap_or((0, tiers[0].to_owned()))
+}
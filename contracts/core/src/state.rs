@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::{Addr, StdResult, Storage, Timestamp};
+use axis_protocol::core::{FeeTier, ReferralTier};
+use cosmwasm_std::{Addr, StdResult, Storage, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 
 use crate::ContractError;
@@ -14,6 +15,8 @@ pub struct Config {
     pub staking_contract: Addr,
     pub vault_contract: Addr,
     pub next_update_timestamp: Timestamp,
+    pub referral_tiers: Vec<ReferralTier>,
+    pub fee_tiers: Vec<FeeTier>,
 }
 
 pub fn save_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
@@ -58,9 +61,34 @@ pub fn register_pair_pool_contract(
     PAIR_POOL.save(storage, (base_denom, price_denom), &pool_contract)
 }
 
+#[cw_serde]
+pub struct DelistedPair {
+    pub pool_contract: Addr,
+    pub market_contract: Addr,
+    pub lp_staking_contract: Addr,
+}
+
+#[cw_serde]
+pub struct ReferralStats {
+    pub code: String,
+    pub trader_count: u64,
+    pub total_fee_usd_amount: Uint128,
+    pub total_rebate_usd_amount: Uint128,
+    pub total_reward_usd_amount: Uint128,
+}
+
 pub const CONFIG: Item<Config> = Item::new("config");
 
 pub const PAIR_POOL: Map<(&String, &String), Addr> = Map::new("pair");
 pub const PAIR_POOL_LP_STAKING_CONTRACT: Map<(&String, &String), Addr> =
     Map::new("pair_lp_contract");
 pub const PAIR_MARKET_CONTRACT: Map<(&String, &String), Addr> = Map::new("pair_market_contract");
+//@@ contracts of a delisted pair , kept so already earned rewards can be claimed
+pub const DELISTED_PAIR: Map<(&String, &String), DelistedPair> = Map::new("delisted_pair");
+
+//code -> referrer
+pub const REFERRAL_CODE: Map<&str, Addr> = Map::new("referral_code");
+//referrer -> stats
+pub const REFERRER: Map<&Addr, ReferralStats> = Map::new("referrer");
+//trader -> code
+pub const TRADER_REFERRAL: Map<&Addr, String> = Map::new("trader_referral");
@@ -1,9 +1,10 @@
-use axis_protocol::es_axis::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use axis_protocol::es_axis::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use cosmwasm_schema::write_api;
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
     }
 }
@@ -9,85 +9,147 @@ use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 use crate::{
     error::ContractError,
-    state::{save_config, Config},
+    helpers::check_vesting_config,
+    state::{save_config, Config, MOVER},
 };
 
-use axis_protocol::es_axis::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use axis_protocol::es_axis::{
+    EsAxisReceiveMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, VestingConfig,
+};
+use cosmwasm_schema::cw_serde;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:es-axis";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+//@@ esAXIS is a ledger in this contract , the denom only names it
 pub const ES_AXIS_DENOM: &str = "esAxis";
+
+fn default_vesting_config() -> VestingConfig {
+    VestingConfig {
+        vesting_epochs: 365,
+        reserve_rate: 0,
+    }
+}
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut<SeiQueryWrapper>,
     env: Env,
     info: MessageInfo,
-    _msg: InstantiateMsg,
+    msg: InstantiateMsg,
 ) -> Result<Response<SeiMsg>, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let vesting_config = msg.vesting_config.unwrap_or_else(default_vesting_config);
+    check_vesting_config(&vesting_config)?;
     let es_axis_denom =
         "factory/".to_string() + env.contract.address.to_string().as_ref() + "/" + ES_AXIS_DENOM;
 
+    //@@ staking pays the rewards out so it is a mover from the start
+    MOVER.save(deps.storage, &info.sender, &true)?;
     let config = Config {
         staking_contarct: info.sender,
         es_axis_total_supply: Uint128::zero(),
         es_axis_denom,
+        core_contract: msg.core_contract,
+        vesting_config,
+        escrowed_axis_amount: Uint128::zero(),
     };
     save_config(deps.storage, &config)?;
-    let es_axis_create_msg = SeiMsg::CreateDenom {
-        subdenom: ES_AXIS_DENOM.to_owned(),
-    };
 
-    Ok(Response::new().add_message(es_axis_create_msg))
+    Ok(Response::new().add_attribute("method", "instantiate"))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut<SeiQueryWrapper>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<SeiMsg>, ContractError> {
     match msg {
-        ExecuteMsg::Claim { sender, amount } => execute::claim(deps, info, sender, amount),
-        ExecuteMsg::Mint { amount } => execute::mint(deps, info, amount),
-        ExecuteMsg::Burn {} => execute::burn(deps, info),
+        ExecuteMsg::Claim { sender, amount } => execute::claim(deps, env, info, sender, amount),
+        ExecuteMsg::Mint { amount } => execute::mint(deps, env, info, amount),
+        ExecuteMsg::Burn { amount } => execute::burn(deps, info, amount),
+        ExecuteMsg::Escrow { recipient } => execute::escrow(deps, info, recipient),
+        ExecuteMsg::Transfer { recipient, amount } => {
+            execute::transfer(deps, info, recipient, amount)
+        }
+        ExecuteMsg::Send {
+            contract,
+            amount,
+            msg,
+        } => execute::send(deps, info, contract, amount, msg),
+        ExecuteMsg::AddMover { mover } => execute::add_mover(deps, info, mover),
+        ExecuteMsg::RemoveMover { mover } => execute::remove_mover(deps, info, mover),
+        ExecuteMsg::UpdateVestingConfig { vesting_config } => {
+            execute::update_vesting_config(deps, info, vesting_config)
+        }
+        ExecuteMsg::Deposit { amount } => execute::deposit(deps, info, amount),
+        ExecuteMsg::ClaimVested {} => execute::claim_vested(deps, info),
+        ExecuteMsg::WithdrawVesting { position_id } => {
+            execute::withdraw_vesting(deps, info, position_id)
+        }
+        ExecuteMsg::RedeemBank {} => execute::redeem_bank(deps, info),
     }
 }
 
+//@@ receiving contracts take it as ExecuteMsg::Receive
+#[cw_serde]
+enum ReceiveExecuteMsg {
+    Receive(EsAxisReceiveMsg),
+}
+
 pub mod execute {
-    use cosmwasm_std::{coin, Addr, BankMsg, SubMsg, Uint128};
+    use axis_protocol::axis::ExecuteMsg as AxisExecuteMsg;
+    use axis_protocol::query::{query_axis_contract, query_epoch};
+    use cosmwasm_std::{coin, Addr, BankMsg, CosmosMsg, Uint128, WasmMsg};
     use sei_cosmwasm::SeiQueryWrapper;
 
     use crate::{
-        helpers::{check_funds_and_get_es_axis, check_staking_contract},
-        state::load_config,
+        helpers::{
+            check_airdrop_contract, check_core_owner, check_funds_and_get_es_axis, check_mover,
+            check_reserve, check_staking_contract, get_reserve_amount, get_vested_amount,
+            query_axis_denom, query_staked_amount, split_escrowed_amount,
+        },
+        state::{
+            decrease_balance, increase_balance, load_config, load_vesting_positions,
+            next_position_id, update_reserved, VestingPosition, BALANCE, RESERVED,
+            VESTING_POSITION,
+        },
     };
 
     use super::*;
 
     pub fn claim(
         deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
         info: MessageInfo,
         sender: Addr,
         amount: Uint128,
     ) -> Result<Response<SeiMsg>, ContractError> {
-        let config = load_config(deps.storage)?;
+        let mut config = load_config(deps.storage)?;
         check_staking_contract(&info.sender, &config)?;
 
-        let es_axis_token = coin(amount.into(), config.es_axis_denom);
-
-        let send_msg = SubMsg::new(BankMsg::Send {
-            to_address: sender.to_string(),
-            amount: vec![es_axis_token],
-        });
+        //@@ rewards minted ahead by Mint are used first , the rest is minted on claim
+        let minted_balance = BALANCE
+            .may_load(deps.storage, &env.contract.address)?
+            .unwrap_or_default();
+        let from_minted = Uint128::min(minted_balance, amount);
+        let mint_amount = amount - from_minted;
+        decrease_balance(deps.storage, &env.contract.address, from_minted)?;
+        increase_balance(deps.storage, &sender, amount)?;
+        config.es_axis_total_supply += mint_amount;
+        save_config(deps.storage, &config)?;
 
-        Ok(Response::new().add_submessage(send_msg))
+        Ok(Response::new()
+            .add_attribute("method", "claim")
+            .add_attribute("recipient", sender)
+            .add_attribute("amount", amount)
+            .add_attribute("mint_amount", mint_amount))
     }
     pub fn mint(
         deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
         info: MessageInfo,
         amount: Uint128,
     ) -> Result<Response<SeiMsg>, ContractError> {
@@ -95,31 +157,291 @@ pub mod execute {
 
         check_staking_contract(&info.sender, &config)?;
 
-        let new_tokens = coin(amount.into(), config.es_axis_denom.to_owned());
-
-        let es_axis_mint_msg = SeiMsg::MintTokens { amount: new_tokens };
-
+        increase_balance(deps.storage, &env.contract.address, amount)?;
         config.es_axis_total_supply += amount;
 
         save_config(deps.storage, &config)?;
 
-        Ok(Response::new().add_message(es_axis_mint_msg))
+        Ok(Response::new()
+            .add_attribute("method", "mint")
+            .add_attribute("amount", amount))
     }
     pub fn burn(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        amount: Uint128,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+
+        decrease_balance(deps.storage, &info.sender, amount)?;
+        config.es_axis_total_supply -= amount;
+        save_config(deps.storage, &config)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "burn")
+            .add_attribute("owner", info.sender)
+            .add_attribute("amount", amount))
+    }
+    pub fn escrow(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        recipient: String,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let mut config = load_config(deps.storage)?;
+        check_airdrop_contract(deps.querier, &info.sender, &config.core_contract)?;
+        let recipient = deps.api.addr_validate(&recipient)?;
+        let axis_denom = query_axis_denom(deps.querier, &config.staking_contarct)?;
+        //@@ AXIS stays in this contract
+        let axis = check_funds_and_get_es_axis(info.funds, &axis_denom)?;
+
+        increase_balance(deps.storage, &recipient, axis.amount)?;
+        config.es_axis_total_supply += axis.amount;
+        config.escrowed_axis_amount += axis.amount;
+        save_config(deps.storage, &config)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "escrow")
+            .add_attribute("recipient", recipient)
+            .add_attribute("amount", axis.amount))
+    }
+    pub fn transfer(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        recipient: String,
+        amount: Uint128,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        check_mover(deps.storage, &info.sender)?;
+        let recipient = deps.api.addr_validate(&recipient)?;
+        decrease_balance(deps.storage, &info.sender, amount)?;
+        increase_balance(deps.storage, &recipient, amount)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "transfer")
+            .add_attribute("from", info.sender)
+            .add_attribute("to", recipient)
+            .add_attribute("amount", amount))
+    }
+    pub fn send(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let contract = deps.api.addr_validate(&contract)?;
+        //@@ anyone can send , but only into a whitelisted contract
+        check_mover(deps.storage, &contract)?;
+        decrease_balance(deps.storage, &info.sender, amount)?;
+        increase_balance(deps.storage, &contract, amount)?;
 
-        let burn_ex_axis = check_funds_and_get_es_axis(info.funds, &config.es_axis_denom)?;
-        config.es_axis_total_supply -= burn_ex_axis.amount;
+        let receive_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_binary(&ReceiveExecuteMsg::Receive(EsAxisReceiveMsg {
+                sender: info.sender.to_string(),
+                amount,
+                msg,
+            }))?,
+            funds: vec![],
+        });
+        Ok(Response::new()
+            .add_attribute("method", "send")
+            .add_attribute("from", info.sender)
+            .add_attribute("to", contract)
+            .add_attribute("amount", amount)
+            .add_message(receive_msg))
+    }
+    pub fn add_mover(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        mover: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        check_core_owner(deps.querier, &info.sender, &config.core_contract)?;
+        let mover = deps.api.addr_validate(&mover)?;
+        MOVER.save(deps.storage, &mover, &true)?;
+        Ok(Response::new()
+            .add_attribute("method", "add_mover")
+            .add_attribute("mover", mover))
+    }
+    pub fn remove_mover(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        mover: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        check_core_owner(deps.querier, &info.sender, &config.core_contract)?;
+        let mover = deps.api.addr_validate(&mover)?;
+        MOVER.remove(deps.storage, &mover);
+        Ok(Response::new()
+            .add_attribute("method", "remove_mover")
+            .add_attribute("mover", mover))
+    }
+    pub fn update_vesting_config(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        vesting_config: VestingConfig,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+        check_core_owner(deps.querier, &info.sender, &config.core_contract)?;
+        check_vesting_config(&vesting_config)?;
+        config.vesting_config = vesting_config;
         save_config(deps.storage, &config)?;
+        Ok(Response::new().add_attribute("method", "update_vesting_config"))
+    }
+    pub fn deposit(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        amount: Uint128,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        //@@ deposited esAXIS leaves the balance and lives in the position
+        decrease_balance(deps.storage, &info.sender, amount)?;
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+
+        let reserved_amount = get_reserve_amount(amount, config.vesting_config.reserve_rate);
+        if !reserved_amount.is_zero() {
+            let staked_amount =
+                query_staked_amount(deps.querier, &config.staking_contarct, &info.sender)?;
+            let already_reserved = RESERVED
+                .may_load(deps.storage, &info.sender)?
+                .unwrap_or_default();
+            check_reserve(staked_amount, already_reserved, reserved_amount)?;
+            update_reserved(deps.storage, &info.sender, reserved_amount, Uint128::zero())?;
+        }
+
+        let position_id = next_position_id(deps.storage)?;
+        VESTING_POSITION.save(
+            deps.storage,
+            (&info.sender, position_id),
+            &VestingPosition {
+                amount,
+                claimed_amount: Uint128::zero(),
+                reserved_amount,
+                start_epoch: epoch,
+                end_epoch: epoch + config.vesting_config.vesting_epochs,
+            },
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("method", "deposit")
+            .add_attribute("position_id", position_id.to_string())
+            .add_attribute("amount", amount)
+            .add_attribute("reserved_amount", reserved_amount))
+    }
+    pub fn claim_vested(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+        let mut claim_amount = Uint128::zero();
+        let mut released_amount = Uint128::zero();
+        for (position_id, mut position) in load_vesting_positions(deps.storage, &info.sender)? {
+            let vested_amount = get_vested_amount(&position, epoch);
+            claim_amount += vested_amount - position.claimed_amount;
+            position.claimed_amount = vested_amount;
+            //@@ fully vested position gives its reserve back
+            match position.claimed_amount == position.amount {
+                true => {
+                    released_amount += position.reserved_amount;
+                    VESTING_POSITION.remove(deps.storage, (&info.sender, position_id));
+                }
+                false => {
+                    VESTING_POSITION.save(deps.storage, (&info.sender, position_id), &position)?
+                }
+            }
+        }
+        update_reserved(deps.storage, &info.sender, Uint128::zero(), released_amount)?;
+        if claim_amount.is_zero() {
+            return Err(ContractError::NothingToClaim {});
+        }
+        let msgs = convert_msgs(deps, &info.sender, claim_amount)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "claim_vested")
+            .add_attribute("amount", claim_amount)
+            .add_messages(msgs))
+    }
+    pub fn withdraw_vesting(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        position_id: u64,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+        let position = VESTING_POSITION.load(deps.storage, (&info.sender, position_id))?;
+        VESTING_POSITION.remove(deps.storage, (&info.sender, position_id));
+        update_reserved(
+            deps.storage,
+            &info.sender,
+            Uint128::zero(),
+            position.reserved_amount,
+        )?;
 
-        let burn_msg = SeiMsg::BurnTokens {
-            amount: burn_ex_axis,
+        let vested_amount = get_vested_amount(&position, epoch);
+        let claim_amount = vested_amount - position.claimed_amount;
+        let unvested_amount = position.amount - vested_amount;
+        increase_balance(deps.storage, &info.sender, unvested_amount)?;
+        let msgs = match claim_amount.is_zero() {
+            true => vec![],
+            false => convert_msgs(deps, &info.sender, claim_amount)?,
         };
 
-        Ok(Response::new().add_message(burn_msg))
+        Ok(Response::new()
+            .add_attribute("method", "withdraw_vesting")
+            .add_attribute("position_id", position_id.to_string())
+            .add_attribute("amount", claim_amount)
+            .add_attribute("unvested_amount", unvested_amount)
+            .add_messages(msgs))
+    }
+    //@@ bank esAXIS left from the old claims moves into the ledger , the supply stays the same
+    pub fn redeem_bank(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let es_axis = check_funds_and_get_es_axis(info.funds, &config.es_axis_denom)?;
+        increase_balance(deps.storage, &info.sender, es_axis.amount)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "redeem_bank")
+            .add_attribute("owner", info.sender)
+            .add_attribute("amount", es_axis.amount)
+            .add_message(SeiMsg::BurnTokens { amount: es_axis }))
+    }
+    //@@ vested esAXIS is burned , pay escrowed AXIS first and mint the rest
+    fn convert_msgs(
+        deps: DepsMut<SeiQueryWrapper>,
+        recipient: &Addr,
+        amount: Uint128,
+    ) -> Result<Vec<CosmosMsg<SeiMsg>>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+        let (escrowed_amount, mint_amount) =
+            split_escrowed_amount(amount, config.escrowed_axis_amount);
+        config.es_axis_total_supply -= amount;
+        config.escrowed_axis_amount -= escrowed_amount;
+        save_config(deps.storage, &config)?;
+
+        let mut msgs: Vec<CosmosMsg<SeiMsg>> = vec![];
+        if !escrowed_amount.is_zero() {
+            let axis_denom = query_axis_denom(deps.querier, &config.staking_contarct)?;
+            msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![coin(escrowed_amount.into(), axis_denom)],
+            }));
+        }
+        if !mint_amount.is_zero() {
+            let axis_contract = query_axis_contract(deps.querier, &config.core_contract)?;
+            msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: axis_contract.to_string(),
+                msg: to_binary(&AxisExecuteMsg::Mint {
+                    recipient: recipient.to_string(),
+                    amount: mint_amount,
+                })?,
+                funds: vec![],
+            }));
+        }
+        Ok(msgs)
     }
 }
 
@@ -128,15 +450,41 @@ pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult
     match msg {
         QueryMsg::GetTotalSupply {} => to_binary(&query::total_supply(deps)?),
         QueryMsg::GetConfig {} => to_binary(&query::config(deps)?),
+        QueryMsg::GetBalance { address } => to_binary(&query::balance(deps, address)?),
+        QueryMsg::GetMovers {} => to_binary(&query::movers(deps)?),
+        QueryMsg::GetVestingPositions { address } => {
+            to_binary(&query::vesting_positions(deps, address)?)
+        }
+        QueryMsg::GetReservedAmount { address } => {
+            to_binary(&query::reserved_amount(deps, address)?)
+        }
+        QueryMsg::GetSupplyInvariant {} => to_binary(&query::supply_invariant(deps)?),
     }
 }
 
 pub mod query {
-    use crate::state::load_config;
-    use axis_protocol::es_axis::ConfigResponse;
-    use cosmwasm_std::{Deps, StdResult, Uint128};
+    use crate::helpers::get_vested_amount;
+    use crate::state::{
+        load_config, load_ledger_supply, load_vesting_positions, load_vesting_supply, BALANCE,
+        MOVER, RESERVED,
+    };
+    use axis_protocol::es_axis::{
+        ConfigResponse, SupplyInvariantResponse, VestingPositionResponse, VestingPositionsResponse,
+    };
+    use axis_protocol::query::query_epoch;
+    use cosmwasm_std::{Addr, Deps, Order, StdResult, Uint128};
     use sei_cosmwasm::SeiQueryWrapper;
 
+    pub fn balance(deps: Deps<SeiQueryWrapper>, address: String) -> StdResult<Uint128> {
+        let owner = deps.api.addr_validate(&address)?;
+        Ok(BALANCE.may_load(deps.storage, &owner)?.unwrap_or_default())
+    }
+    pub fn movers(deps: Deps<SeiQueryWrapper>) -> StdResult<Vec<Addr>> {
+        MOVER
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect()
+    }
+
     pub fn total_supply(deps: Deps<SeiQueryWrapper>) -> StdResult<Uint128> {
         let config = load_config(deps.storage)?;
         Ok(config.es_axis_total_supply)
@@ -147,9 +495,117 @@ pub mod query {
             staking_contarct: config.staking_contarct.to_string(),
             es_axis_denom: config.es_axis_denom,
             es_axis_total_supply: config.es_axis_total_supply,
+            core_contract: config.core_contract.to_string(),
+            vesting_config: config.vesting_config,
+            escrowed_axis_amount: config.escrowed_axis_amount,
+        })
+    }
+    pub fn vesting_positions(
+        deps: Deps<SeiQueryWrapper>,
+        address: String,
+    ) -> StdResult<VestingPositionsResponse> {
+        let owner = deps.api.addr_validate(&address)?;
+        let config = load_config(deps.storage)?;
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+        let positions = load_vesting_positions(deps.storage, &owner)?
+            .into_iter()
+            .map(|(position_id, position)| {
+                let vested_amount = get_vested_amount(&position, epoch);
+                VestingPositionResponse {
+                    position_id,
+                    amount: position.amount,
+                    vested_amount,
+                    claimed_amount: position.claimed_amount,
+                    claimable_amount: vested_amount - position.claimed_amount,
+                    reserved_amount: position.reserved_amount,
+                    start_epoch: position.start_epoch,
+                    end_epoch: position.end_epoch,
+                }
+            })
+            .collect();
+        Ok(VestingPositionsResponse {
+            positions,
+            reserved_amount: reserved_amount(deps, address)?,
+        })
+    }
+    pub fn reserved_amount(deps: Deps<SeiQueryWrapper>, address: String) -> StdResult<Uint128> {
+        let owner = deps.api.addr_validate(&address)?;
+        Ok(RESERVED.may_load(deps.storage, &owner)?.unwrap_or_default())
+    }
+    pub fn supply_invariant(deps: Deps<SeiQueryWrapper>) -> StdResult<SupplyInvariantResponse> {
+        let config = load_config(deps.storage)?;
+        let ledger_supply = load_ledger_supply(deps.storage)?;
+        let vesting_supply = load_vesting_supply(deps.storage)?;
+        //@@ no bank supply when the denom was never created
+        let bank_supply = deps
+            .querier
+            .query_supply(&config.es_axis_denom)
+            .map(|supply| supply.amount)
+            .unwrap_or_default();
+        Ok(SupplyInvariantResponse {
+            es_axis_total_supply: config.es_axis_total_supply,
+            ledger_supply,
+            vesting_supply,
+            bank_supply,
+            is_consistent: config.es_axis_total_supply
+                == ledger_supply + vesting_supply + bank_supply,
         })
     }
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut<SeiQueryWrapper>,
+    env: Env,
+    msg: MigrateMsg,
+) -> Result<Response<SeiMsg>, ContractError> {
+    use crate::state::{increase_balance, load_legacy_config};
+    use axis_protocol::query::query_vault_contract;
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let legacy_config = load_legacy_config(deps.storage)?;
+    let core_contract = deps.api.addr_validate(&msg.core_contract)?;
+    let vesting_config = msg.vesting_config.unwrap_or_else(default_vesting_config);
+    check_vesting_config(&vesting_config)?;
+    //@@ the bank denom version has no ledger , all esAXIS is in the bank and claims used to
+    //@@ move pre-minted esAXIS , the tracked total counts Mint and Burn when the bank can't tell
+    let bank_supply = deps
+        .querier
+        .query_supply(&legacy_config.es_axis_denom)
+        .map(|supply| supply.amount)
+        .unwrap_or(legacy_config.es_axis_total_supply);
+    //@@ pre-minted bank esAXIS of this contract is burned into its ledger balance , Claim pays from it
+    let minted_bank = deps.querier.query_balance(
+        env.contract.address.to_owned(),
+        &legacy_config.es_axis_denom,
+    )?;
+    increase_balance(deps.storage, &env.contract.address, minted_bank.amount)?;
+    let config = Config {
+        staking_contarct: legacy_config.staking_contarct,
+        es_axis_total_supply: bank_supply,
+        es_axis_denom: legacy_config.es_axis_denom,
+        core_contract,
+        vesting_config,
+        escrowed_axis_amount: Uint128::zero(),
+    };
+    save_config(deps.storage, &config)?;
+    //@@ staking pays the rewards and the vault takes esAXIS swaps
+    let vault_contract = query_vault_contract(deps.querier, &config.core_contract)?;
+    MOVER.save(deps.storage, &config.staking_contarct, &true)?;
+    MOVER.save(deps.storage, &vault_contract, &true)?;
+
+    let response = Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("previous_supply", legacy_config.es_axis_total_supply)
+        .add_attribute("es_axis_total_supply", config.es_axis_total_supply)
+        .add_attribute("burned_bank_amount", minted_bank.amount);
+    match minted_bank.amount.is_zero() {
+        true => Ok(response),
+        false => Ok(response.add_message(SeiMsg::BurnTokens {
+            amount: minted_bank,
+        })),
+    }
+}
+
 // #[cfg(test)]
 // mod tests {}
@@ -12,4 +12,19 @@ pub enum ContractError {
     // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
     #[error("Invalid Denom")]
     InvalidDenom {},
+
+    #[error("Invalid Vesting Config")]
+    InvalidVestingConfig {},
+
+    #[error("Insufficient Staked Axis")]
+    InsufficientStakedAxis {},
+
+    #[error("Nothing To Claim")]
+    NothingToClaim {},
+
+    #[error("Insufficient Balance")]
+    InsufficientBalance {},
+
+    #[error("Transfer Not Allowed")]
+    TransferNotAllowed {},
 }
@@ -1,6 +1,17 @@
-use cosmwasm_std::{Addr, Coin};
+use axis_protocol::axis::{AirDropResponse, QueryMsg as AxisQueryMsg};
+use axis_protocol::core::{ConfigResponse as CoreConfigResponse, QueryMsg as CoreQueryMsg};
+use axis_protocol::es_axis::VestingConfig;
+use axis_protocol::query::query_axis_contract;
+use axis_protocol::staking::{
+    ConfigResponse as StakingConfigResponse, QueryMsg as StakingQueryMsg, StakeInfoResponse,
+};
+use cosmwasm_std::{Addr, Coin, Decimal, QuerierWrapper, StdResult, Storage, Uint128};
+use sei_cosmwasm::SeiQueryWrapper;
 
-use crate::{state::Config, ContractError};
+use crate::{
+    state::{Config, VestingPosition, MOVER},
+    ContractError,
+};
 
 pub fn check_staking_contract(sender: &Addr, state: &Config) -> Result<(), ContractError> {
     match *sender == state.staking_contarct {
@@ -9,6 +20,13 @@ pub fn check_staking_contract(sender: &Addr, state: &Config) -> Result<(), Contr
     }
 }
 
+pub fn check_mover(storage: &dyn Storage, mover: &Addr) -> Result<(), ContractError> {
+    match MOVER.has(storage, mover) {
+        true => Ok(()),
+        false => Err(ContractError::TransferNotAllowed {}),
+    }
+}
+
 pub fn check_funds_and_get_es_axis(
     funds: Vec<Coin>,
     axis_denom: &String,
@@ -20,3 +38,100 @@ pub fn check_funds_and_get_es_axis(
 
     Ok(es_axis.clone())
 }
+
+pub fn query_axis_denom(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    staking_contract: &Addr,
+) -> StdResult<String> {
+    let staking_config: StakingConfigResponse =
+        querier.query_wasm_smart(staking_contract.to_string(), &StakingQueryMsg::GetConfig {})?;
+    Ok(staking_config.axis_denom)
+}
+
+pub fn check_core_owner(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    sender: &Addr,
+    core_contract: &Addr,
+) -> Result<(), ContractError> {
+    let core_config: CoreConfigResponse =
+        querier.query_wasm_smart(core_contract.to_string(), &CoreQueryMsg::GetConfig {})?;
+    match *sender == core_config.owner {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
+}
+
+//@@ only airdrops funded from the community pool can lock AXIS as esAXIS
+pub fn check_airdrop_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    sender: &Addr,
+    core_contract: &Addr,
+) -> Result<(), ContractError> {
+    let axis_contract = query_axis_contract(querier, core_contract)?;
+    let air_drop: Option<AirDropResponse> = querier.query_wasm_smart(
+        axis_contract.to_string(),
+        &AxisQueryMsg::GetAirDrop {
+            address: sender.to_string(),
+        },
+    )?;
+    match air_drop.is_some() {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
+}
+
+pub fn check_vesting_config(vesting_config: &VestingConfig) -> Result<(), ContractError> {
+    match vesting_config.vesting_epochs > 0 && vesting_config.reserve_rate <= 100 {
+        true => Ok(()),
+        false => Err(ContractError::InvalidVestingConfig {}),
+    }
+}
+
+pub fn check_reserve(
+    staked_amount: Uint128,
+    reserved_amount: Uint128,
+    reserve_amount: Uint128,
+) -> Result<(), ContractError> {
+    match reserved_amount + reserve_amount <= staked_amount {
+        true => Ok(()),
+        false => Err(ContractError::InsufficientStakedAxis {}),
+    }
+}
+
+pub fn query_staked_amount(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    staking_contract: &Addr,
+    staker: &Addr,
+) -> StdResult<Uint128> {
+    let stake_info: StakeInfoResponse = querier.query_wasm_smart(
+        staking_contract.to_string(),
+        &StakingQueryMsg::GetStakeInfo {
+            addr: staker.to_string(),
+        },
+    )?;
+    Ok(stake_info
+        .stake_infos
+        .iter()
+        .map(|stake| stake.staking_amount)
+        .sum())
+}
+
+pub fn get_reserve_amount(amount: Uint128, reserve_rate: u8) -> Uint128 {
+    amount * Decimal::percent(reserve_rate.into())
+}
+
+//linear from start_epoch , fully vested at end_epoch
+pub fn get_vested_amount(position: &VestingPosition, epoch: u64) -> Uint128 {
+    let elapsed = epoch.saturating_sub(position.start_epoch);
+    let duration = position.end_epoch - position.start_epoch;
+    match elapsed >= duration {
+        true => position.amount,
+        false => position.amount.multiply_ratio(elapsed, duration),
+    }
+}
+
+//(AXIS paid from escrow , AXIS minted)
+pub fn split_escrowed_amount(amount: Uint128, escrowed_axis_amount: Uint128) -> (Uint128, Uint128) {
+    let escrowed_amount = Uint128::min(amount, escrowed_axis_amount);
+    (escrowed_amount, amount - escrowed_amount)
+}
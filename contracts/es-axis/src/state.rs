@@ -1,17 +1,125 @@
+use axis_protocol::es_axis::VestingConfig;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use crate::ContractError;
 
 #[cw_serde]
 pub struct Config {
     pub staking_contarct: Addr,
     pub es_axis_total_supply: Uint128,
     pub es_axis_denom: String,
+    pub core_contract: Addr,
+    pub vesting_config: VestingConfig,
+    //AXIS locked by escrow , vesting pays it out before minting
+    pub escrowed_axis_amount: Uint128,
+}
+//@@ config of the bank denom version , read once by migrate
+#[cw_serde]
+pub struct LegacyConfig {
+    pub staking_contarct: Addr,
+    pub es_axis_total_supply: Uint128,
+    pub es_axis_denom: String,
+}
+pub fn load_legacy_config(storage: &dyn Storage) -> StdResult<LegacyConfig> {
+    LEGACY_CONFIG.load(storage)
 }
+
 pub fn save_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
     CONFIG.save(storage, config)
 }
 pub fn load_config(storage: &dyn Storage) -> StdResult<Config> {
     CONFIG.load(storage)
 }
+
+#[cw_serde]
+pub struct VestingPosition {
+    //deposited esAXIS
+    pub amount: Uint128,
+    pub claimed_amount: Uint128,
+    //staked AXIS reserved until the position is closed
+    pub reserved_amount: Uint128,
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+}
+
+pub fn load_vesting_positions(
+    storage: &dyn Storage,
+    owner: &Addr,
+) -> StdResult<Vec<(u64, VestingPosition)>> {
+    VESTING_POSITION
+        .prefix(owner)
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+//@@ position id is global so ids never repeat for an owner
+pub fn next_position_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let position_id = POSITION_ID.may_load(storage)?.unwrap_or_default() + 1;
+    POSITION_ID.save(storage, &position_id)?;
+    Ok(position_id)
+}
+
+pub fn update_reserved(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    add_amount: Uint128,
+    sub_amount: Uint128,
+) -> StdResult<()> {
+    let reserved = RESERVED.may_load(storage, owner)?.unwrap_or_default() + add_amount - sub_amount;
+    match reserved.is_zero() {
+        true => RESERVED.remove(storage, owner),
+        false => RESERVED.save(storage, owner, &reserved)?,
+    }
+    Ok(())
+}
+
+pub fn increase_balance(storage: &mut dyn Storage, owner: &Addr, amount: Uint128) -> StdResult<()> {
+    BALANCE.update(storage, owner, |balance| -> StdResult<Uint128> {
+        Ok(balance.unwrap_or_default() + amount)
+    })?;
+    Ok(())
+}
+
+pub fn decrease_balance(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let balance = BALANCE.may_load(storage, owner)?.unwrap_or_default();
+    let balance = balance
+        .checked_sub(amount)
+        .map_err(|_| ContractError::InsufficientBalance {})?;
+    match balance.is_zero() {
+        true => BALANCE.remove(storage, owner),
+        false => BALANCE.save(storage, owner, &balance)?,
+    }
+    Ok(())
+}
+
+pub fn load_ledger_supply(storage: &dyn Storage) -> StdResult<Uint128> {
+    BALANCE
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, balance)| balance))
+        .sum()
+}
+
+pub fn load_vesting_supply(storage: &dyn Storage) -> StdResult<Uint128> {
+    VESTING_POSITION
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, position)| position.amount - position.claimed_amount))
+        .sum()
+}
+
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+//esAXIS is kept here instead of the bank so it can't be moved freely
+pub const BALANCE: Map<&Addr, Uint128> = Map::new("balance");
+//contracts allowed to move esAXIS
+pub const MOVER: Map<&Addr, bool> = Map::new("mover");
+pub const POSITION_ID: Item<u64> = Item::new("position_id");
+//(owner, position id) -> position
+pub const VESTING_POSITION: Map<(&Addr, u64), VestingPosition> = Map::new("vesting_position");
+//owner -> staked AXIS reserved by all positions
+pub const RESERVED: Map<&Addr, Uint128> = Map::new("reserved");
@@ -1,7 +1,7 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+    to_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
 };
 use cw2::set_contract_version;
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
@@ -9,11 +9,12 @@ use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 use crate::{
     error::ContractError,
+    helpers::{check_lock_tiers, check_max_boost, check_penalty_rate},
     state::{save_config, save_state, Config, State},
 };
 
 use axis_protocol::{
-    lp_staking::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    lp_staking::{ExecuteMsg, InstantiateMsg, LockTier, QueryMsg},
     query::query_epoch,
 };
 
@@ -29,12 +30,38 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response<SeiMsg>, ContractError> {
     let epoch = query_epoch(deps.querier, &msg.core_contract)?;
+    let max_boost = msg
+        .max_boost
+        .unwrap_or_else(|| Decimal::from_ratio(5u128, 2u128));
+    check_max_boost(max_boost)?;
+    let lock_tiers = msg.lock_tiers.unwrap_or_else(|| {
+        vec![
+            LockTier {
+                lock_epochs: 7,
+                weight: Decimal::from_ratio(5u128, 4u128),
+            },
+            LockTier {
+                lock_epochs: 30,
+                weight: Decimal::from_ratio(3u128, 2u128),
+            },
+            LockTier {
+                lock_epochs: 90,
+                weight: Decimal::from_ratio(2u128, 1u128),
+            },
+        ]
+    });
+    check_lock_tiers(&lock_tiers)?;
+    let early_exit_penalty_rate = msg.early_exit_penalty_rate.unwrap_or(50);
+    check_penalty_rate(early_exit_penalty_rate)?;
     let config = Config {
         lp_denom: msg.lp_denom,
         axis_contract: msg.axis_contract,
         core_contract: msg.core_contract,
         base_denom: msg.base_denom,
         price_denom: msg.price_denom,
+        max_boost,
+        lock_tiers,
+        early_exit_penalty_rate,
     };
     save_config(deps.storage, config)?;
 
@@ -43,6 +70,9 @@ pub fn instantiate(
         staking_total: Uint128::zero(),
         stake_pending_total: Uint128::zero(),
         withdraw_pending_total: Uint128::zero(),
+        working_total: Uint128::zero(),
+        penalty_pending_total: Uint128::zero(),
+        pending_working_total: Uint128::zero(),
     };
     save_state(deps.storage, state)?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -58,105 +88,168 @@ pub fn execute(
 ) -> Result<Response<SeiMsg>, ContractError> {
     use execute::*;
     match msg {
-        ExecuteMsg::Staking {} => staking(deps, info),
-        ExecuteMsg::UnStaking {} => un_staking(deps, info),
+        ExecuteMsg::Staking { lock_epochs } => staking(deps, info, lock_epochs),
+        ExecuteMsg::UnStaking { amount } => un_staking(deps, info, amount, false),
+        ExecuteMsg::EarlyUnStaking {} => un_staking(deps, info, None, true),
         ExecuteMsg::Withdraw {} => withdraw(deps, info),
         ExecuteMsg::ClaimReward {} => claim_reward(deps, info),
         ExecuteMsg::Setting { epoch } => setting(deps, info, epoch),
+        ExecuteMsg::UpdateBoost { address } => update_boost(deps, address),
     }
 }
 
 mod execute {
     use crate::{
-        helpers::{check_core_contract, check_funds_and_get_lp, compute_mint_amount},
+        helpers::{
+            check_core_contract, check_funds_and_get_lp, compute_early_exit_penalty,
+            find_lock_tier, split_unstake_amount, take_accrued_reward, update_working_balance,
+        },
+        query::{query_boost_amounts, query_pool_mint_amount},
         state::{
-            load_config, load_stakings, load_state, load_un_stakings, save_state, StakeInfo,
-            UnStakeInfo, EPOCH_STAKING_TOTAL_AMOUNT, STAKING, UN_STAKING,
+            load_config, load_stakings, load_state, load_un_stakings, save_state, Config,
+            StakeInfo, StakeLock, UnStakeInfo, AXIS_REWARD, EPOCH_STAKING_TOTAL_AMOUNT,
+            LP_PENALTY_REWARD, REWARD_INDEX, STAKING, UN_STAKING,
         },
         ContractError,
     };
     use axis_protocol::axis::ExecuteMsg as AxisExecuteMsg;
     use cosmwasm_std::{
-        coin, to_binary, BankMsg, CosmosMsg, DepsMut, MessageInfo, Response, StdResult, SubMsg,
-        Uint128, WasmMsg,
+        coin, to_binary, Addr, BankMsg, CosmosMsg, DepsMut, MessageInfo, Response, StdResult,
+        SubMsg, Uint128, WasmMsg,
     };
     use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
+    //@@ AXIS is minted by the axis contract , the penalty lp is held here
+    fn reward_msgs(
+        config: Config,
+        staker: Addr,
+        axis_amount: Uint128,
+        lp_amount: Uint128,
+    ) -> StdResult<Vec<CosmosMsg<SeiMsg>>> {
+        let mut msgs = vec![];
+        if !lp_amount.is_zero() {
+            msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: staker.to_string(),
+                amount: vec![coin(lp_amount.into(), config.lp_denom)],
+            }));
+        }
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.axis_contract.to_string(),
+            msg: to_binary(&AxisExecuteMsg::ClaimMintMaker {
+                base_denom: config.base_denom,
+                price_denom: config.price_denom,
+                sender: staker,
+                amount: axis_amount,
+            })?,
+            funds: vec![],
+        }));
+        Ok(msgs)
+    }
+
     pub fn staking(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        lock_epochs: Option<u64>,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
 
         let lp_coin = check_funds_and_get_lp(info.funds, &config.lp_denom)?;
 
         let mut state = load_state(deps.storage)?;
-        STAKING.update(
+        let start_epoch = state.epoch + 1;
+        let lock = match lock_epochs {
+            Some(lock_epochs) => {
+                let lock_tier = find_lock_tier(&config.lock_tiers, lock_epochs)?;
+                Some(StakeLock {
+                    lock_epochs,
+                    unlock_epoch: start_epoch + lock_epochs,
+                    weight: lock_tier.weight,
+                })
+            }
+            None => None,
+        };
+        //@@ stakes starting at the same epoch with the same lock are kept as one
+        let mut stakings = STAKING
+            .may_load(deps.storage, &info.sender)?
+            .unwrap_or_default();
+        match stakings
+            .iter_mut()
+            .find(|s| s.start_epoch == start_epoch && s.lock == lock)
+        {
+            Some(stake) => stake.staking_amount += lp_coin.amount,
+            None => stakings.push(StakeInfo {
+                start_epoch,
+                staking_amount: lp_coin.amount,
+                lock,
+            }),
+        }
+        STAKING.save(deps.storage, &info.sender, &stakings)?;
+
+        state.stake_pending_total += lp_coin.amount;
+        let boost_amounts = query_boost_amounts(deps.querier, &config.core_contract, &info.sender)?;
+        update_working_balance(
             deps.storage,
+            &mut state,
             &info.sender,
-            |exsits| -> StdResult<Vec<StakeInfo>> {
-                match exsits {
-                    Some(mut stakings) => {
-                        if let Some(stake) = stakings
-                            .iter_mut()
-                            .find(|s| s.start_epoch == state.epoch + 1)
-                        {
-                            stake.staking_amount += lp_coin.amount;
-                        } else {
-                            stakings.push(StakeInfo {
-                                start_epoch: state.epoch + 1,
-                                staking_amount: lp_coin.amount,
-                            })
-                        }
-                        Ok(stakings)
-                    }
-                    None => {
-                        let stakings = vec![StakeInfo {
-                            start_epoch: state.epoch + 1,
-                            staking_amount: lp_coin.amount,
-                        }];
-                        Ok(stakings)
-                    }
-                }
-            },
+            config.max_boost,
+            Some(boost_amounts),
         )?;
-
-        state.stake_pending_total += lp_coin.amount;
         save_state(deps.storage, state)?;
 
         Ok(Response::default())
     }
 
+    //@@ early exit unstakes the locked stakes too , the penalty is shared at the next setting
     pub fn un_staking(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        amount: Option<Uint128>,
+        early_exit: bool,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         let mut state = load_state(deps.storage)?;
-        let stakings = load_stakings(deps.storage, &info.sender)?
-            .into_iter()
-            .filter(|s| s.start_epoch <= state.epoch)
-            .collect::<Vec<StakeInfo>>();
+        //@@ pending stakes stay , they start at the next setting
+        let (stakings, mut remaining_stakings): (Vec<StakeInfo>, Vec<StakeInfo>) =
+            load_stakings(deps.storage, &info.sender)?
+                .into_iter()
+                .partition(|s| {
+                    s.start_epoch <= state.epoch && (early_exit || !s.is_locked(state.epoch))
+                });
+        let stakings = match amount {
+            Some(amount) => {
+                let (stakings, kept_stakings) = split_unstake_amount(stakings, amount)?;
+                remaining_stakings.extend(kept_stakings);
+                stakings
+            }
+            None => stakings,
+        };
 
         let unlock_epoch = state.epoch + 1;
 
-        let mut axis_amount = Uint128::zero();
-        let mut unstaking_amount = Uint128::zero();
-        for stake in stakings.into_iter() {
-            axis_amount += compute_mint_amount(
-                &deps,
-                &config,
-                stake.staking_amount,
-                stake.start_epoch,
-                state.epoch,
-            )?;
-            unstaking_amount += stake.staking_amount;
-        }
+        let staked_amount: Uint128 = stakings.iter().map(|s| s.staking_amount).sum();
+        let penalty_amount: Uint128 = stakings
+            .iter()
+            .map(|s| compute_early_exit_penalty(s, state.epoch, config.early_exit_penalty_rate))
+            .sum();
+        let unstaking_amount = staked_amount - penalty_amount;
         let un_stake = UnStakeInfo {
             unlock_epoch,
             unstaking_amount,
         };
-        STAKING.remove(deps.storage, &info.sender);
+        match remaining_stakings.is_empty() {
+            true => STAKING.remove(deps.storage, &info.sender),
+            false => STAKING.save(deps.storage, &info.sender, &remaining_stakings)?,
+        }
+        let boost_amounts = query_boost_amounts(deps.querier, &config.core_contract, &info.sender)?;
+        update_working_balance(
+            deps.storage,
+            &mut state,
+            &info.sender,
+            config.max_boost,
+            Some(boost_amounts),
+        )?;
+        let axis_amount = take_accrued_reward(deps.storage, &info.sender, AXIS_REWARD)?;
+        let lp_amount = take_accrued_reward(deps.storage, &info.sender, LP_PENALTY_REWARD)?;
 
         UN_STAKING.update(deps.storage, &info.sender, |exsists| -> StdResult<_> {
             match exsists {
@@ -172,23 +265,21 @@ mod execute {
         })?;
 
         state.withdraw_pending_total += unstaking_amount;
-        state.staking_total -= unstaking_amount;
+        state.penalty_pending_total += penalty_amount;
+        state.staking_total -= staked_amount;
         save_state(deps.storage, state)?;
-        match axis_amount.is_zero() {
-            true => Ok(Response::new()),
-            false => {
-                let claim_msg = CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: config.axis_contract.to_string(),
-                    msg: to_binary(&AxisExecuteMsg::ClaimMintMaker {
-                        base_denom: config.base_denom,
-                        price_denom: config.price_denom,
-                        sender: info.sender,
-                        amount: axis_amount,
-                    })?,
-                    funds: vec![],
-                });
-                Ok(Response::new().add_message(claim_msg))
-            }
+        let response = Response::new()
+            .add_attribute("method", "un_staking")
+            .add_attribute("unstaking_amount", unstaking_amount)
+            .add_attribute("penalty_amount", penalty_amount);
+        match axis_amount.is_zero() && lp_amount.is_zero() {
+            true => Ok(response),
+            false => Ok(response.add_messages(reward_msgs(
+                config,
+                info.sender,
+                axis_amount,
+                lp_amount,
+            )?)),
         }
     }
 
@@ -234,36 +325,52 @@ mod execute {
         info: MessageInfo,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
-        let mut stakings = load_stakings(deps.storage, &info.sender)?;
-        let mut claim_axis_amount = Uint128::zero();
-        let state = load_state(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        //@@ only stakers can claim , pending stakes earn from the next setting
+        load_stakings(deps.storage, &info.sender)?;
 
-        for stake in stakings.iter_mut() {
-            if stake.start_epoch == state.epoch {
-                continue;
-            }
-            let reward = compute_mint_amount(
-                &deps,
-                &config,
-                stake.staking_amount,
-                stake.start_epoch,
-                state.epoch,
-            )?;
-            stake.start_epoch = state.epoch;
-            claim_axis_amount += reward;
-        }
+        let boost_amounts = query_boost_amounts(deps.querier, &config.core_contract, &info.sender)?;
+        update_working_balance(
+            deps.storage,
+            &mut state,
+            &info.sender,
+            config.max_boost,
+            Some(boost_amounts),
+        )?;
+        save_state(deps.storage, state)?;
+        let claim_axis_amount = take_accrued_reward(deps.storage, &info.sender, AXIS_REWARD)?;
+        let claim_lp_amount = take_accrued_reward(deps.storage, &info.sender, LP_PENALTY_REWARD)?;
+
+        Ok(Response::new().add_messages(reward_msgs(
+            config,
+            info.sender,
+            claim_axis_amount,
+            claim_lp_amount,
+        )?))
+    }
 
-        let claim_msg = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: config.axis_contract.to_string(),
-            msg: to_binary(&AxisExecuteMsg::ClaimMintMaker {
-                base_denom: config.base_denom,
-                price_denom: config.price_denom,
-                sender: info.sender,
-                amount: claim_axis_amount,
-            })?,
-            funds: vec![],
-        });
-        Ok(Response::new().add_message(claim_msg))
+    //@@ a boost that went down or a lock that expired can be updated by anyone
+    pub fn update_boost(
+        deps: DepsMut<SeiQueryWrapper>,
+        address: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let mut state = load_state(deps.storage)?;
+        let staker = deps.api.addr_validate(&address)?;
+
+        let boost_amounts = query_boost_amounts(deps.querier, &config.core_contract, &staker)?;
+        update_working_balance(
+            deps.storage,
+            &mut state,
+            &staker,
+            config.max_boost,
+            Some(boost_amounts),
+        )?;
+        save_state(deps.storage, state)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "update_boost")
+            .add_attribute("address", staker))
     }
 
     pub fn setting(
@@ -277,9 +384,38 @@ mod execute {
         let mut state = load_state(deps.storage)?;
 
         EPOCH_STAKING_TOTAL_AMOUNT.save(deps.storage, state.epoch, &state.staking_total)?;
+        //@@ axis setting runs first , so the pool mint of the ended epoch is known
+        let mint_amount = query_pool_mint_amount(
+            deps.querier,
+            &config.axis_contract,
+            &config.base_denom,
+            &config.price_denom,
+            epoch,
+        )?;
+        REWARD_INDEX.distribute(
+            deps.storage,
+            AXIS_REWARD,
+            epoch,
+            mint_amount,
+            state.working_total,
+        )?;
+        //@@ the penalty waits for a working balance to take it
+        REWARD_INDEX.distribute(
+            deps.storage,
+            LP_PENALTY_REWARD,
+            epoch,
+            state.penalty_pending_total,
+            state.working_total,
+        )?;
+        if !state.working_total.is_zero() {
+            state.penalty_pending_total = Uint128::zero();
+        }
         state.staking_total += state.stake_pending_total;
         state.stake_pending_total = Uint128::zero();
         state.epoch = epoch;
+        //@@ started stakes join with the working balance of the last update of each staker
+        state.working_total += state.pending_working_total;
+        state.pending_working_total = Uint128::zero();
         save_state(deps.storage, state)?;
 
         Ok(Response::new())
@@ -303,20 +439,22 @@ pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult
             start_epoch,
             end_epoch,
         )?),
+        QueryMsg::GetBoostInfo { address } => to_binary(&query::get_boost_info(deps, address)?),
     }
 }
 
 pub mod query {
     use axis_protocol::lp_staking::{
-        ConfigResponse, EpochTotalStakingResponse, StakeInfoResponse, StateResponse,
-        UnStakeInfoResponse,
+        BoostInfoResponse, ConfigResponse, EpochTotalStakingResponse, LockResponse,
+        StakeInfoResponse, StateResponse, UnStakeInfoResponse,
     };
-    use cosmwasm_std::{Addr, Deps, Order, StdResult};
+    use cosmwasm_std::{Addr, Decimal, Deps, Order, StdResult};
     use cw_storage_plus::Bound;
     use sei_cosmwasm::SeiQueryWrapper;
 
     use crate::state::{
-        load_config, load_stakings, load_state, load_un_stakings, EPOCH_STAKING_TOTAL_AMOUNT,
+        load_active_amount, load_config, load_stakings, load_state, load_un_stakings, BOOST_INFO,
+        EPOCH_STAKING_TOTAL_AMOUNT,
     };
 
     pub fn get_config(deps: Deps<SeiQueryWrapper>) -> StdResult<ConfigResponse> {
@@ -328,6 +466,9 @@ pub mod query {
             lp_denom: config.lp_denom,
             base_denom: config.base_denom,
             price_denom: config.price_denom,
+            max_boost: config.max_boost,
+            lock_tiers: config.lock_tiers,
+            early_exit_penalty_rate: config.early_exit_penalty_rate,
         })
     }
     pub fn get_state(deps: Deps<SeiQueryWrapper>) -> StdResult<StateResponse> {
@@ -339,6 +480,33 @@ pub mod query {
             withdraw_pending_total: state.withdraw_pending_total,
             epoch: state.epoch,
             stake_pending_total: state.stake_pending_total,
+            working_total: state.working_total,
+            penalty_pending_total: state.penalty_pending_total,
+        })
+    }
+
+    pub fn get_boost_info(
+        deps: Deps<SeiQueryWrapper>,
+        address: Addr,
+    ) -> StdResult<BoostInfoResponse> {
+        let state = load_state(deps.storage)?;
+        let boost_info = BOOST_INFO
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default();
+        let (staking_amount, _) = load_active_amount(deps.storage, &address, state.epoch)?;
+        let boost = match staking_amount.is_zero() {
+            true => Decimal::one(),
+            false => {
+                Decimal::from_ratio(boost_info.working_balance_at(state.epoch), staking_amount)
+            }
+        };
+        Ok(BoostInfoResponse {
+            staking_amount,
+            boost_amount: boost_info.boost_amount,
+            boost_total: boost_info.boost_total,
+            boost,
+            working_balance: boost_info.working_balance_at(state.epoch),
+            working_total: state.working_total,
         })
     }
 
@@ -352,6 +520,11 @@ pub mod query {
             .map(|stake| StakeInfoResponse {
                 start_epoch: stake.start_epoch,
                 staking_amount: stake.staking_amount,
+                lock: stake.lock.map(|lock| LockResponse {
+                    lock_epochs: lock.lock_epochs,
+                    unlock_epoch: lock.unlock_epoch,
+                    weight: lock.weight,
+                }),
             })
             .collect::<Vec<StakeInfoResponse>>();
         Ok(stake_infos_res)
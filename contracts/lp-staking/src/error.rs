@@ -11,4 +11,16 @@ pub enum ContractError {
 
     #[error("Invalid Denom")]
     InvalidDenom {},
+
+    #[error("Invalid Max Boost")]
+    InvalidMaxBoost {},
+
+    #[error("Invalid Lock Tier")]
+    InvalidLockTier {},
+
+    #[error("Invalid Penalty Rate")]
+    InvalidPenaltyRate {},
+
+    #[error("Invalid Amount")]
+    InvalidAmount {},
 }
@@ -1,10 +1,11 @@
-use cosmwasm_std::{Addr, Coin, Decimal, DepsMut, Order, StdResult, Uint128};
-use cw_storage_plus::Bound;
-use sei_cosmwasm::SeiQueryWrapper;
+use axis_protocol::lp_staking::LockTier;
+use cosmwasm_std::{Addr, Coin, Decimal, StdResult, Storage, Uint128};
 
 use crate::{
-    query::query_pool_mint_amounts,
-    state::{Config, EPOCH_STAKING_TOTAL_AMOUNT},
+    state::{
+        load_active_amount, load_pending_weighted_amount, BoostInfo, PendingWorking, StakeInfo,
+        State, ACCRUED_REWARD, AXIS_REWARD, BOOST_INFO, LP_PENALTY_REWARD, REWARD_INDEX,
+    },
     ContractError,
 };
 
@@ -24,44 +25,200 @@ pub fn check_core_contract(core_contract: &Addr, sender: &Addr) -> Result<(), Co
     }
 }
 
-pub fn compute_mint_amount(
-    deps: &DepsMut<SeiQueryWrapper>,
-    config: &Config,
+pub fn check_max_boost(max_boost: Decimal) -> Result<(), ContractError> {
+    match max_boost >= Decimal::one() {
+        true => Ok(()),
+        false => Err(ContractError::InvalidMaxBoost {}),
+    }
+}
+
+pub fn check_lock_tiers(lock_tiers: &[LockTier]) -> Result<(), ContractError> {
+    let is_valid = lock_tiers.iter().enumerate().all(|(i, tier)| {
+        tier.lock_epochs > 0
+            && tier.weight >= Decimal::one()
+            && lock_tiers[..i]
+                .iter()
+                .all(|other| other.lock_epochs != tier.lock_epochs)
+    });
+    match is_valid {
+        true => Ok(()),
+        false => Err(ContractError::InvalidLockTier {}),
+    }
+}
+
+pub fn check_penalty_rate(early_exit_penalty_rate: u8) -> Result<(), ContractError> {
+    match early_exit_penalty_rate <= 100 {
+        true => Ok(()),
+        false => Err(ContractError::InvalidPenaltyRate {}),
+    }
+}
+
+//@@ newest stakes leave first , return (unstaked , kept)
+pub fn split_unstake_amount(
+    stakings: Vec<StakeInfo>,
+    amount: Uint128,
+) -> Result<(Vec<StakeInfo>, Vec<StakeInfo>), ContractError> {
+    let staked_amount: Uint128 = stakings.iter().map(|s| s.staking_amount).sum();
+    if amount.is_zero() || amount > staked_amount {
+        return Err(ContractError::InvalidAmount {});
+    }
+    let mut remaining_amount = amount;
+    let mut unstakings = vec![];
+    let mut kept_stakings = vec![];
+    for mut stake in stakings.into_iter().rev() {
+        let unstake_amount = Uint128::min(stake.staking_amount, remaining_amount);
+        remaining_amount -= unstake_amount;
+        if !unstake_amount.is_zero() {
+            unstakings.push(StakeInfo {
+                staking_amount: unstake_amount,
+                ..stake.clone()
+            });
+        }
+        stake.staking_amount -= unstake_amount;
+        if !stake.staking_amount.is_zero() {
+            kept_stakings.push(stake);
+        }
+    }
+    Ok((unstakings, kept_stakings))
+}
+
+pub fn find_lock_tier(
+    lock_tiers: &[LockTier],
+    lock_epochs: u64,
+) -> Result<LockTier, ContractError> {
+    lock_tiers
+        .iter()
+        .find(|tier| tier.lock_epochs == lock_epochs)
+        .cloned()
+        .ok_or(ContractError::InvalidLockTier {})
+}
+
+//@@ the penalty rate shrinks linearly with the epochs left in the lock
+pub fn compute_early_exit_penalty(stake: &StakeInfo, epoch: u64, penalty_rate: u8) -> Uint128 {
+    match &stake.lock {
+        Some(lock) if stake.is_locked(epoch) => stake.staking_amount.multiply_ratio(
+            penalty_rate as u128 * (lock.unlock_epoch - epoch) as u128,
+            100 * lock.lock_epochs as u128,
+        ),
+        _ => Uint128::zero(),
+    }
+}
+
+//@@ Curve style , the weighted lp counts once and the share of staked AXIS , esAXIS adds
+//@@ the same share of every staked lp up to max_boost times the weighted lp
+pub fn compute_working_balance(
     staking_amount: Uint128,
-    start_epoch: u64,
-    now_epoch: u64,
-) -> StdResult<Uint128> {
-    if start_epoch == now_epoch {
-        return Ok(Uint128::zero());
+    staking_total: Uint128,
+    boost_amount: Uint128,
+    boost_total: Uint128,
+    max_boost: Decimal,
+) -> Uint128 {
+    let boosted_amount = match boost_total.is_zero() {
+        true => Uint128::zero(),
+        false => {
+            staking_total.multiply_ratio(boost_amount, boost_total) * (max_boost - Decimal::one())
+        }
+    };
+    (staking_amount + boosted_amount).min(staking_amount * max_boost)
+}
+
+//@@ settles the AXIS and the penalty lp of the working balance up to the index of the epoch ,
+//@@ None settles up to the latest index
+fn settle_reward(
+    storage: &mut dyn Storage,
+    staker: &Addr,
+    boost_info: &mut BoostInfo,
+    epoch: Option<u64>,
+) -> StdResult<()> {
+    for (key, staker_index) in [
+        (AXIS_REWARD, &mut boost_info.reward_index),
+        (LP_PENALTY_REWARD, &mut boost_info.penalty_index),
+    ] {
+        let index = match epoch {
+            Some(epoch) => match REWARD_INDEX.at(storage, key, epoch)? {
+                Some(index) => index,
+                None => REWARD_INDEX.latest(storage, key)?,
+            },
+            None => REWARD_INDEX.latest(storage, key)?,
+        };
+        let reward = boost_info.working_balance * (index - *staker_index);
+        if !reward.is_zero() {
+            ACCRUED_REWARD.update(storage, (staker, key), |accrued| -> StdResult<_> {
+                Ok(accrued.unwrap_or_default() + reward)
+            })?;
+        }
+        *staker_index = index;
     }
-    let mint_amounts = query_pool_mint_amounts(
-        deps.querier,
-        &config.axis_contract,
-        &config.base_denom,
-        &config.price_denom,
-        start_epoch,
-    )?;
-    let stake_ratio = EPOCH_STAKING_TOTAL_AMOUNT
-        .range(
-            deps.storage,
-            Some(Bound::inclusive(start_epoch)),
-            None,
-            Order::Ascending,
-        )
-        .into_iter()
-        .map(|data| {
-            let (key, total) = data?;
-            let ratio = Decimal::from_ratio(staking_amount, total);
-            Ok((key, ratio))
-        })
-        .collect::<StdResult<Vec<(u64, Decimal)>>>()?;
-    // @@ mint_epoch 이랑 epoch 이랑 다를 경우가 있을까?
-
-    let staker_mint_amount = mint_amounts
-        .into_iter()
-        .zip(stake_ratio.into_iter())
-        .map(|(item, (_, ratio))| Ok(item.mint_amount * ratio))
-        .sum::<StdResult<Uint128>>()?;
-
-    Ok(staker_mint_amount)
+    Ok(())
+}
+
+//@@ settles the AXIS and the penalty lp of the old working balance and sets the new one ,
+//@@ None keeps the boost amounts of the last update
+pub fn update_working_balance(
+    storage: &mut dyn Storage,
+    state: &mut State,
+    staker: &Addr,
+    max_boost: Decimal,
+    boost_amounts: Option<(Uint128, Uint128)>,
+) -> StdResult<()> {
+    let mut boost_info = BOOST_INFO.may_load(storage, staker)?.unwrap_or_default();
+    //@@ a started pending working balance is already in working_total since its setting
+    if let Some(pending) = boost_info.pending_working.take() {
+        match pending.epoch <= state.epoch {
+            true => {
+                settle_reward(storage, staker, &mut boost_info, Some(pending.epoch))?;
+                boost_info.working_balance = pending.working_balance;
+            }
+            false => state.pending_working_total -= pending.added_amount,
+        }
+    }
+    settle_reward(storage, staker, &mut boost_info, None)?;
+
+    if let Some((boost_amount, boost_total)) = boost_amounts {
+        boost_info.boost_amount = boost_amount;
+        boost_info.boost_total = boost_total;
+    }
+    let (_, weighted_amount) = load_active_amount(storage, staker, state.epoch)?;
+    let working_balance = compute_working_balance(
+        weighted_amount,
+        state.staking_total,
+        boost_info.boost_amount,
+        boost_info.boost_total,
+        max_boost,
+    );
+    state.working_total = state.working_total - boost_info.working_balance + working_balance;
+    boost_info.working_balance = working_balance;
+
+    //@@ stakes of the next epoch grow the working balance at setting without touching the staker
+    let pending_weighted_amount = load_pending_weighted_amount(storage, staker, state.epoch)?;
+    if !pending_weighted_amount.is_zero() {
+        let pending_working_balance = compute_working_balance(
+            weighted_amount + pending_weighted_amount,
+            state.staking_total + state.stake_pending_total,
+            boost_info.boost_amount,
+            boost_info.boost_total,
+            max_boost,
+        );
+        let added_amount = pending_working_balance - working_balance;
+        state.pending_working_total += added_amount;
+        boost_info.pending_working = Some(PendingWorking {
+            epoch: state.epoch + 1,
+            working_balance: pending_working_balance,
+            added_amount,
+        });
+    }
+    BOOST_INFO.save(storage, staker, &boost_info)
+}
+
+//@@ settled reward of the staker , cleared for the claim
+pub fn take_accrued_reward(
+    storage: &mut dyn Storage,
+    staker: &Addr,
+    key: &str,
+) -> StdResult<Uint128> {
+    let reward = ACCRUED_REWARD
+        .may_load(storage, (staker, key))?
+        .unwrap_or_default();
+    ACCRUED_REWARD.remove(storage, (staker, key));
+    Ok(reward)
 }
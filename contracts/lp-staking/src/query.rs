@@ -1,6 +1,12 @@
 use axis_protocol::axis::{PoolAllowedMintAmountResponse, QueryMsg as AxisQueryMsg};
+use axis_protocol::es_axis::QueryMsg as EsAxisQueryMsg;
+use axis_protocol::query::query_staking_contract;
+use axis_protocol::staking::{
+    ConfigResponse as StakingConfigResponse, QueryMsg as StakingQueryMsg, StakeInfoResponse,
+    StateResponse as StakingStateResponse,
+};
 
-use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Uint128};
 use sei_cosmwasm::SeiQueryWrapper;
 
 pub fn query_pool_mint_amounts(
@@ -21,3 +27,59 @@ pub fn query_pool_mint_amounts(
 
     Ok(axis_res)
 }
+
+//@@ axis saves the pool mint of the ended epoch under the epoch it opens
+pub fn query_pool_mint_amount(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    axis_contract: &Addr,
+    base_denom: &String,
+    price_denom: &String,
+    epoch: u64,
+) -> StdResult<Uint128> {
+    let mint_amounts =
+        query_pool_mint_amounts(querier, axis_contract, base_denom, price_denom, epoch)?;
+    Ok(mint_amounts
+        .into_iter()
+        .find(|item| item.epoch == epoch)
+        .map(|item| item.mint_amount)
+        .unwrap_or_default())
+}
+
+//@@ (AXIS and esAXIS of the address , of everyone) , pending AXIS stakes count as staked
+pub fn query_boost_amounts(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    address: &Addr,
+) -> StdResult<(Uint128, Uint128)> {
+    let staking_contract = query_staking_contract(querier, core_contract)?;
+    let staking_config: StakingConfigResponse =
+        querier.query_wasm_smart(staking_contract.to_string(), &StakingQueryMsg::GetConfig {})?;
+    let stake_info: StakeInfoResponse = querier.query_wasm_smart(
+        staking_contract.to_string(),
+        &StakingQueryMsg::GetStakeInfo {
+            addr: address.to_string(),
+        },
+    )?;
+    let staking_state: StakingStateResponse =
+        querier.query_wasm_smart(staking_contract.to_string(), &StakingQueryMsg::GetState {})?;
+    let es_axis_amount: Uint128 = querier.query_wasm_smart(
+        staking_config.es_axis_contract.to_string(),
+        &EsAxisQueryMsg::GetBalance {
+            address: address.to_string(),
+        },
+    )?;
+    let es_axis_total: Uint128 = querier.query_wasm_smart(
+        staking_config.es_axis_contract.to_string(),
+        &EsAxisQueryMsg::GetTotalSupply {},
+    )?;
+
+    let staked_amount: Uint128 = stake_info
+        .stake_infos
+        .iter()
+        .map(|stake| stake.staking_amount)
+        .sum();
+    Ok((
+        staked_amount + es_axis_amount,
+        staking_state.staking_total + staking_state.pending_staking_total + es_axis_total,
+    ))
+}
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use axis_protocol::{lp_staking::LockTier, reward::RewardIndex};
+use cosmwasm_std::{Addr, Decimal, StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
@@ -10,6 +11,9 @@ pub struct Config {
     pub lp_denom: String,
     pub base_denom: String,
     pub price_denom: String,
+    pub max_boost: Decimal,
+    pub lock_tiers: Vec<LockTier>,
+    pub early_exit_penalty_rate: u8,
 }
 
 pub fn save_config(storage: &mut dyn Storage, config: Config) -> StdResult<()> {
@@ -25,6 +29,10 @@ pub struct State {
     pub staking_total: Uint128,
     pub stake_pending_total: Uint128,
     pub withdraw_pending_total: Uint128,
+    pub working_total: Uint128,
+    pub penalty_pending_total: Uint128,
+    //working balance added by the stakes of the next epoch , joins working_total at setting
+    pub pending_working_total: Uint128,
 }
 pub fn load_state(storage: &dyn Storage) -> StdResult<State> {
     STATE.load(storage)
@@ -36,6 +44,64 @@ pub fn save_state(storage: &mut dyn Storage, state: State) -> StdResult<()> {
 pub struct StakeInfo {
     pub start_epoch: u64,
     pub staking_amount: Uint128,
+    pub lock: Option<StakeLock>,
+}
+
+//@@ weight of the tier when staked , the tier can change later
+#[cw_serde]
+pub struct StakeLock {
+    pub lock_epochs: u64,
+    pub unlock_epoch: u64,
+    pub weight: Decimal,
+}
+
+impl StakeInfo {
+    pub fn is_locked(&self, epoch: u64) -> bool {
+        match &self.lock {
+            Some(lock) => epoch < lock.unlock_epoch,
+            None => false,
+        }
+    }
+
+    //@@ lp counted in the working balance , an expired lock counts once
+    pub fn weighted_amount(&self, epoch: u64) -> Uint128 {
+        match (&self.lock, self.is_locked(epoch)) {
+            (Some(lock), true) => self.staking_amount * lock.weight,
+            _ => self.staking_amount,
+        }
+    }
+}
+
+//@@ working balance of a staker , each reward is working_balance * (index - index of the staker)
+#[cw_serde]
+#[derive(Default)]
+pub struct BoostInfo {
+    //staked AXIS and esAXIS of the staker and of everyone when last updated
+    pub boost_amount: Uint128,
+    pub boost_total: Uint128,
+    pub working_balance: Uint128,
+    pub reward_index: Decimal,
+    pub penalty_index: Decimal,
+    pub pending_working: Option<PendingWorking>,
+}
+
+//@@ working balance of the staker from the epoch its pending stakes start
+#[cw_serde]
+pub struct PendingWorking {
+    pub epoch: u64,
+    pub working_balance: Uint128,
+    //part of pending_working_total added by the staker
+    pub added_amount: Uint128,
+}
+
+impl BoostInfo {
+    //@@ the pending working balance counts once its epoch has started
+    pub fn working_balance_at(&self, epoch: u64) -> Uint128 {
+        match &self.pending_working {
+            Some(pending) if pending.epoch <= epoch => pending.working_balance,
+            _ => self.working_balance,
+        }
+    }
 }
 
 #[cw_serde]
@@ -47,9 +113,18 @@ pub const CONFIG: Item<Config> = Item::new("config");
 pub const STATE: Item<State> = Item::new("state");
 //epoch,total_staking_amount
 pub const EPOCH_STAKING_TOTAL_AMOUNT: Map<u64, Uint128> = Map::new("epoch_staking_amount");
+//(reward key, epoch) -> cumulative reward per working balance
+pub const REWARD_INDEX: RewardIndex = RewardIndex::new("reward_index");
+//reward index key of the minted AXIS
+pub const AXIS_REWARD: &str = "axis";
+//reward index key of the lp taken by early exits
+pub const LP_PENALTY_REWARD: &str = "lp_penalty";
 
 pub const STAKING: Map<&Addr, Vec<StakeInfo>> = Map::new("staking");
 pub const UN_STAKING: Map<&Addr, Vec<UnStakeInfo>> = Map::new("unstaking");
+pub const BOOST_INFO: Map<&Addr, BoostInfo> = Map::new("boost_info");
+//(staker, reward key) -> settled reward not claimed yet
+pub const ACCRUED_REWARD: Map<(&Addr, &str), Uint128> = Map::new("accrued_reward");
 pub fn load_stakings(storage: &dyn Storage, staker: &Addr) -> StdResult<Vec<StakeInfo>> {
     let stakings = STAKING.load(storage, staker)?;
     Ok(stakings)
@@ -59,3 +134,40 @@ pub fn load_un_stakings(storage: &dyn Storage, staker: &Addr) -> StdResult<Vec<U
     let stakings = UN_STAKING.load(storage, staker)?;
     Ok(stakings)
 }
+
+//@@ (lp , weighted lp) of the stakes started at or before the epoch
+pub fn load_active_amount(
+    storage: &dyn Storage,
+    staker: &Addr,
+    epoch: u64,
+) -> StdResult<(Uint128, Uint128)> {
+    Ok(STAKING
+        .may_load(storage, staker)?
+        .unwrap_or_default()
+        .iter()
+        .filter(|s| s.start_epoch <= epoch)
+        .fold(
+            (Uint128::zero(), Uint128::zero()),
+            |(amount, weighted), s| {
+                (
+                    amount + s.staking_amount,
+                    weighted + s.weighted_amount(epoch),
+                )
+            },
+        ))
+}
+
+//@@ weighted lp of the stakes starting after the epoch , weighted at their start
+pub fn load_pending_weighted_amount(
+    storage: &dyn Storage,
+    staker: &Addr,
+    epoch: u64,
+) -> StdResult<Uint128> {
+    Ok(STAKING
+        .may_load(storage, staker)?
+        .unwrap_or_default()
+        .iter()
+        .filter(|s| s.start_epoch > epoch)
+        .map(|s| s.weighted_amount(s.start_epoch))
+        .sum())
+}
@@ -1,19 +1,23 @@
 use axis_protocol::axis::ExecuteMsg as AxisExecuteMsg;
 use axis_protocol::market::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use axis_protocol::pool::ExecuteMsg as PoolExecuteMsg;
+use axis_protocol::pool::{
+    ConfigResponse as PoolConfigResponse, ExecuteMsg as PoolExecuteMsg, PauseMode,
+    QueryMsg as PoolQueryMsg,
+};
 use axis_protocol::vault::ExecuteMsg as VaultExecuteMsg;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+    to_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
 };
 use cw2::set_contract_version;
 use sei_cosmwasm::{Order, SeiMsg, SeiQueryWrapper};
 
 use crate::error::ContractError;
 
+use crate::helpers::check::check_adl_rate;
 use crate::query::query_base_coin_price_and_price_coin_price;
-use crate::state::{save_state, Config, State, CONFIG};
+use crate::state::{save_breaker, save_state, Breaker, Config, State, CONFIG};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:market";
@@ -38,7 +42,18 @@ pub fn instantiate(
         limit_profit_loss_open_fee_rate,
         axis_contract,
         vault_contract,
+        adl_threshold,
+        adl_close_rate,
+        price_breaker_rate,
+        nav_breaker_rate,
     } = msg;
+    check_adl_rate(adl_threshold)?;
+    check_adl_rate(adl_close_rate)?;
+
+    //@@ market is instantiated by the pool , core is read from the pool config
+    let pool_config: PoolConfigResponse = deps
+        .querier
+        .query_wasm_smart(info.sender.to_string(), &PoolQueryMsg::GetConfig {})?;
 
     let (past_price, _) =
         query_base_coin_price_and_price_coin_price(&deps.querier, &base_denom, &price_denom)?;
@@ -54,16 +69,31 @@ pub fn instantiate(
         borrow_fee_rate,
         open_close_fee_rate,
         limit_profit_loss_open_fee_rate,
+        adl_threshold,
+        adl_close_rate,
+        price_breaker_rate,
+        nav_breaker_rate,
+        core_contract: pool_config.core_contract,
     };
     let state = State {
         base_coin_total_fee: Uint128::zero(),
         price_coin_total_fee: Uint128::zero(),
         past_price,
+        mode: PauseMode::Active,
     };
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     CONFIG.save(deps.storage, &config)?;
     save_state(deps.storage, &state)?;
+    save_breaker(
+        deps.storage,
+        &Breaker {
+            tripped: false,
+            reason: None,
+            epoch: 0,
+            epoch_nav: Decimal::zero(),
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "instantiate")
@@ -100,6 +130,11 @@ pub fn execute(
         ),
         Close {} => execute::close(deps, env, info),
         Liquidated {} => execute::hook_liquidated(deps, env, info),
+        AutoDeleverage {} => execute::auto_deleverage(deps, env, info),
+        ResetBreaker {} => execute::reset_breaker(deps, env, info),
+        SetMode { mode } => execute::set_mode(deps, info, mode),
+        Delist {} => execute::delist(deps, info),
+        SettleTrade { trader } => execute::settle_trade(deps, info, trader),
     }
 }
 
@@ -107,19 +142,29 @@ pub mod execute {
 
     use crate::{
         helpers::{
-            calculate_close_fee_amount, calculate_open_fee_amount,
+            apply_fee_discount, bad_debt_event, calculate_close_fee_amount,
+            calculate_open_fee_amount,
             check::{
-                check_funds_for_positions_get_funds, check_leverage_amount, check_leverage_rate,
+                check_breaker, check_funds_for_positions_get_funds, check_leverage_amount,
+                check_leverage_rate, check_mode,
             },
-            control_desitinated_traders, fee_division, get_trade_information, get_trader_amount,
-            get_usd_amount,
+            check_circuit_breaker, control_adl_traders, control_desitinated_traders, fee_division,
+            get_bad_debt_amount, get_lp_nav, get_referral_amounts, get_referral_msgs,
+            get_referrer_fee_usd_amount, get_settle_amount, get_trade_information,
+            get_trader_amount, get_usd_amount,
         },
         position::Position,
-        query::{query_base_coin_price_and_price_coin_price, query_pool_balance},
-        state::{load_config, load_state},
+        query::{
+            query_base_coin_price_and_price_coin_price, query_pool, query_pool_balance,
+            query_trader_fee_tier, query_trader_referral,
+        },
+        state::{
+            load_breaker, load_config, load_settlement, load_state, save_settlement, Settlement,
+        },
         trade::{get_desitinated_price_traders, trade_load, trade_remove, trade_update, Trade},
     };
-    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Uint128, WasmMsg};
+    use axis_protocol::query::query_epoch;
+    use cosmwasm_std::{coin, BankMsg, Coin, CosmosMsg, Event, Uint128, WasmMsg};
 
     use sei_cosmwasm::SeiQueryWrapper;
 
@@ -141,6 +186,8 @@ pub mod execute {
         //trade 에 기록해놨다가 fee 가져갈까?
         let config = load_config(deps.storage)?;
         let mut state = load_state(deps.storage)?;
+        check_mode(state.mode.can_open())?;
+        check_breaker(load_breaker(deps.storage)?.tripped)?;
         let position = Position::new(position);
 
         check_leverage_rate(leverage, config.max_leverage)?;
@@ -172,9 +219,17 @@ pub mod execute {
                 }
             }
         };
+        let fee_tier = query_trader_fee_tier(deps.querier, &config.core_contract, &info.sender)?;
+        let open_fee_amount = apply_fee_discount(open_fee_amount, fee_tier.discount_rate);
+        let referral = query_trader_referral(deps.querier, &config.core_contract, &info.sender)?;
+        let (rebate_amount, reward_amount) = get_referral_amounts(open_fee_amount, &referral);
         match position {
-            Position::Long => state.base_coin_total_fee += open_fee_amount,
-            Position::Short => state.price_coin_total_fee += open_fee_amount,
+            Position::Long => {
+                state.base_coin_total_fee += open_fee_amount - rebate_amount - reward_amount
+            }
+            Position::Short => {
+                state.price_coin_total_fee += open_fee_amount - rebate_amount - reward_amount
+            }
         }
         save_state(deps.storage, &state)?;
 
@@ -224,6 +279,7 @@ pub mod execute {
             position_size,
             leverage,
             leverage_amount,
+            fee_tier.tier,
         );
 
         //Trade 저장하는 로직.
@@ -250,6 +306,15 @@ pub mod execute {
             }
         };
 
+        let referral_msgs = get_referral_msgs(
+            &config,
+            &referral,
+            &info.sender,
+            &collateral_denom,
+            (rebate_amount, reward_amount),
+            fee_usd,
+        )?;
+
         //@@ attribute 만들어야함.
         Ok(Response::new()
             .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
@@ -264,9 +329,15 @@ pub mod execute {
                     price_denom: config.price_denom,
                     trader: info.sender,
                     fee_usd_amount: fee_usd.to_uint_ceil(),
+                    referrer: referral.referrer.to_owned(),
+                    referrer_fee_usd_amount: get_referrer_fee_usd_amount(&referral, fee_usd),
                 })?,
                 funds: vec![],
-            })))
+            }))
+            .add_messages(referral_msgs)
+            .add_attribute("method", "open")
+            .add_attribute("fee_tier", fee_tier.tier.to_string())
+            .add_attribute("fee_amount", open_fee_amount))
     }
 
     pub fn close(
@@ -276,6 +347,7 @@ pub mod execute {
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         let mut state = load_state(deps.storage)?;
+        check_mode(state.mode.can_close())?;
         //@@Trade 에서 timestamp 저장해야함.
         let trade = trade_load(deps.storage, info.sender)?;
 
@@ -287,6 +359,7 @@ pub mod execute {
             position: user_position,
             leverage,
             leverage_amount,
+            position_size,
             ..
         } = trade;
 
@@ -329,8 +402,12 @@ pub mod execute {
             ),
         }?;
         //@@ pnl 로 뜯어야함.
-        let close_fee_amount =
-            calculate_close_fee_amount(trader_amount, config.open_close_fee_rate);
+        trader_amount = Uint128::min(trader_amount, collateral_amount + leverage_amount);
+        let fee_tier = query_trader_fee_tier(deps.querier, &config.core_contract, &trader)?;
+        let close_fee_amount = apply_fee_discount(
+            calculate_close_fee_amount(trader_amount, config.open_close_fee_rate),
+            fee_tier.discount_rate,
+        );
         let fee_usd = match user_position {
             Position::Long => {
                 get_usd_amount(close_fee_amount, config.base_decimal, now_base_price_dec)?
@@ -341,9 +418,23 @@ pub mod execute {
         };
         trader_amount -= close_fee_amount;
 
+        let bad_debt = get_bad_debt_amount(
+            &user_position,
+            entry_price,
+            now_price,
+            collateral_amount,
+            position_size,
+        )?;
+
+        let referral = query_trader_referral(deps.querier, &config.core_contract, &trader)?;
+        let (rebate_amount, reward_amount) = get_referral_amounts(close_fee_amount, &referral);
         match user_position {
-            Position::Long => state.base_coin_total_fee += close_fee_amount,
-            Position::Short => state.price_coin_total_fee += close_fee_amount,
+            Position::Long => {
+                state.base_coin_total_fee += close_fee_amount - rebate_amount - reward_amount
+            }
+            Position::Short => {
+                state.price_coin_total_fee += close_fee_amount - rebate_amount - reward_amount
+            }
         }
         save_state(deps.storage, &state)?;
         let send_amount_to_pool =
@@ -356,13 +447,23 @@ pub mod execute {
         let axis_treasury_msg = CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: config.axis_contract.to_string(),
             msg: to_binary(&AxisExecuteMsg::AddFeeAmount {
-                base_denom: config.base_denom,
-                price_denom: config.price_denom,
+                base_denom: config.base_denom.to_owned(),
+                price_denom: config.price_denom.to_owned(),
                 trader: trader.to_owned(),
                 fee_usd_amount: fee_usd.to_uint_ceil(),
+                referrer: referral.referrer.to_owned(),
+                referrer_fee_usd_amount: get_referrer_fee_usd_amount(&referral, fee_usd),
             })?,
             funds: vec![],
         });
+        let referral_msgs = get_referral_msgs(
+            &config,
+            &referral,
+            &trader,
+            &denom,
+            (rebate_amount, reward_amount),
+            fee_usd,
+        )?;
 
         let user_bank_msg = CosmosMsg::Bank(BankMsg::Send {
             to_address: trader.to_string(),
@@ -377,13 +478,40 @@ pub mod execute {
                 amount: send_amount_to_pool,
                 borrowed_amount: leverage_amount,
             })?,
-            funds: vec![coin(send_amount_to_pool.into(), denom)],
+            funds: match send_amount_to_pool.is_zero() {
+                true => vec![],
+                false => vec![coin(send_amount_to_pool.into(), denom.to_owned())],
+            },
         });
 
-        Ok(Response::new()
+        let response = Response::new()
             .add_message(user_bank_msg)
             .add_message(pool_repay_msg)
-            .add_message(axis_treasury_msg))
+            .add_message(axis_treasury_msg)
+            .add_messages(referral_msgs)
+            .add_attribute("method", "close")
+            .add_attribute("fee_tier", fee_tier.tier.to_string())
+            .add_attribute("fee_amount", close_fee_amount);
+        if bad_debt.is_zero() {
+            return Ok(response);
+        }
+
+        let (base_bad_debt, price_bad_debt) = match user_position {
+            Position::Long => (bad_debt, Uint128::zero()),
+            Position::Short => (Uint128::zero(), bad_debt),
+        };
+        Ok(response
+            .add_event(bad_debt_event(&trader, &denom, bad_debt))
+            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.vault_contract.to_string(),
+                msg: to_binary(&VaultExecuteMsg::CoverBadDebt {
+                    base_denom: config.base_denom,
+                    base_amount: base_bad_debt,
+                    price_denom: config.price_denom,
+                    price_amount: price_bad_debt,
+                })?,
+                funds: vec![],
+            })))
     }
 
     pub fn hook_liquidated(
@@ -393,6 +521,7 @@ pub mod execute {
     ) -> Result<Response<SeiMsg>, ContractError> {
         let mut config = load_config(deps.storage)?;
         let mut state = load_state(deps.storage)?;
+        check_mode(state.mode.can_liquidate())?;
         let past_price = state.past_price;
 
         let (current_price, price_price) = query_base_coin_price_and_price_coin_price(
@@ -401,7 +530,16 @@ pub mod execute {
             &config.price_denom,
         )?;
 
-        state.past_price = past_price;
+        //@@ breaker only blocks new opens , the sweep below keeps liquidating
+        let mut breaker = load_breaker(deps.storage)?;
+        let pool = query_pool(deps.querier, &config.pool_contract)?;
+        let epoch = query_epoch(deps.querier, &config.core_contract)?;
+        let nav = get_lp_nav(&config, &pool, current_price, price_price)?;
+        let trip_reason =
+            check_circuit_breaker(&config, &mut breaker, past_price, current_price, epoch, nav);
+        save_breaker(deps.storage, &breaker)?;
+
+        state.past_price = current_price;
         let price_destinated_trader = get_desitinated_price_traders(
             deps.storage,
             past_price.atomics(),
@@ -412,7 +550,10 @@ pub mod execute {
         let mut price_coin_to_pool = coin(0, config.price_denom.to_owned());
         let mut base_borrowed_amount = Uint128::zero();
         let mut price_borrowed_amount = Uint128::zero();
+        let mut base_bad_debt = Uint128::zero();
+        let mut price_bad_debt = Uint128::zero();
         let mut bank_msgs: Vec<CosmosMsg<SeiMsg>> = vec![];
+        let mut events: Vec<Event> = vec![];
         //liquidated
         control_desitinated_traders(
             deps.storage,
@@ -426,6 +567,9 @@ pub mod execute {
             &mut price_coin_to_pool,
             &mut base_borrowed_amount,
             &mut price_borrowed_amount,
+            &mut base_bad_debt,
+            &mut price_bad_debt,
+            &mut events,
         )?;
         //limit_loss
         control_desitinated_traders(
@@ -440,6 +584,9 @@ pub mod execute {
             &mut price_coin_to_pool,
             &mut base_borrowed_amount,
             &mut price_borrowed_amount,
+            &mut base_bad_debt,
+            &mut price_bad_debt,
+            &mut events,
         )?;
         //limit_profit
         control_desitinated_traders(
@@ -454,6 +601,9 @@ pub mod execute {
             &mut price_coin_to_pool,
             &mut base_borrowed_amount,
             &mut price_borrowed_amount,
+            &mut base_bad_debt,
+            &mut price_bad_debt,
+            &mut events,
         )?;
 
         //@@ fee_zero_reset is used fee division
@@ -462,35 +612,42 @@ pub mod execute {
             send_price_fee_to_pool,
             send_base_fee_to_valut,
             send_price_fee_to_valut,
+            send_base_fee_to_insurance,
+            send_price_fee_to_insurance,
         ) = fee_division(&mut state);
         save_state(deps.storage, &state)?;
 
         let mut wasm_messages: Vec<CosmosMsg<SeiMsg>> = Vec::new();
 
-        if !send_price_fee_to_pool.is_zero() {
+        let price_repay_amount = price_coin_to_pool.amount + send_price_fee_to_pool;
+        if !price_repay_amount.is_zero() {
             let repay_price_msg: CosmosMsg<SeiMsg> = CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: config.pool_contract.to_string(),
                 msg: to_binary(&PoolExecuteMsg::RePay {
                     denom: config.price_denom.to_owned(),
                     position: false,
-                    amount: price_coin_to_pool.amount + send_price_fee_to_pool,
+                    amount: price_repay_amount,
                     borrowed_amount: price_borrowed_amount,
                 })?,
-                funds: vec![price_coin_to_pool],
+                funds: vec![coin(
+                    price_repay_amount.into(),
+                    config.price_denom.to_owned(),
+                )],
             });
             wasm_messages.push(repay_price_msg);
         }
 
-        if !send_base_fee_to_pool.is_zero() {
+        let base_repay_amount = base_coin_to_pool.amount + send_base_fee_to_pool;
+        if !base_repay_amount.is_zero() {
             let repay_base_msg: CosmosMsg<SeiMsg> = CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: config.pool_contract.to_string(),
                 msg: to_binary(&PoolExecuteMsg::RePay {
                     denom: config.base_denom.to_owned(),
                     position: true,
-                    amount: base_coin_to_pool.amount + send_base_fee_to_pool,
+                    amount: base_repay_amount,
                     borrowed_amount: base_borrowed_amount,
                 })?,
-                funds: vec![base_coin_to_pool],
+                funds: vec![coin(base_repay_amount.into(), config.base_denom.to_owned())],
             });
             wasm_messages.push(repay_base_msg);
         }
@@ -505,16 +662,295 @@ pub mod execute {
                     price_amount: send_price_fee_to_valut,
                 })?,
                 funds: vec![
-                    coin(send_base_fee_to_valut.into(), config.base_denom),
-                    coin(send_price_fee_to_valut.into(), config.price_denom),
+                    coin(send_base_fee_to_valut.into(), config.base_denom.to_owned()),
+                    coin(
+                        send_price_fee_to_valut.into(),
+                        config.price_denom.to_owned(),
+                    ),
                 ],
             });
             wasm_messages.push(send_fee_to_valut_msg);
         }
 
+        let insurance_funds = vec![
+            coin(
+                send_base_fee_to_insurance.into(),
+                config.base_denom.to_owned(),
+            ),
+            coin(
+                send_price_fee_to_insurance.into(),
+                config.price_denom.to_owned(),
+            ),
+        ]
+        .into_iter()
+        .filter(|c| !c.amount.is_zero())
+        .collect::<Vec<Coin>>();
+        if !insurance_funds.is_empty() {
+            wasm_messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.vault_contract.to_string(),
+                msg: to_binary(&VaultExecuteMsg::RecievedInsuranceFee {
+                    base_denom: config.base_denom.to_owned(),
+                    price_denom: config.price_denom.to_owned(),
+                })?,
+                funds: insurance_funds,
+            }));
+        }
+
+        //@@ shortfall of liquidated traders is paid to the pool from the insurance fund
+        if !base_bad_debt.is_zero() || !price_bad_debt.is_zero() {
+            wasm_messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.vault_contract.to_string(),
+                msg: to_binary(&VaultExecuteMsg::CoverBadDebt {
+                    base_denom: config.base_denom.to_owned(),
+                    base_amount: base_bad_debt,
+                    price_denom: config.price_denom.to_owned(),
+                    price_amount: price_bad_debt,
+                })?,
+                funds: vec![],
+            }));
+        }
+
+        if let Some(reason) = trip_reason {
+            events.push(
+                Event::new("circuit_breaker")
+                    .add_attribute("reason", reason)
+                    .add_attribute("past_price", past_price.to_string())
+                    .add_attribute("current_price", current_price.to_string())
+                    .add_attribute("epoch_nav", breaker.epoch_nav.to_string())
+                    .add_attribute("nav", nav.to_string()),
+            );
+            wasm_messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.pool_contract.to_string(),
+                msg: to_binary(&PoolExecuteMsg::TripBreaker {})?,
+                funds: vec![],
+            }));
+        }
+
         Ok(Response::new()
             .add_messages(bank_msgs)
-            .add_messages(wasm_messages))
+            .add_messages(wasm_messages)
+            .add_events(events))
+    }
+
+    pub fn auto_deleverage(
+        deps: DepsMut<SeiQueryWrapper>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let (base_price, _) = query_base_coin_price_and_price_coin_price(
+            &deps.querier,
+            &config.base_denom,
+            &config.price_denom,
+        )?;
+        check_mode(load_state(deps.storage)?.mode.can_liquidate())?;
+        let pool = query_pool(deps.querier, &config.pool_contract)?;
+
+        let mut bank_msgs: Vec<CosmosMsg<SeiMsg>> = vec![];
+        let mut wasm_messages: Vec<CosmosMsg<SeiMsg>> = vec![];
+        let mut events: Vec<Event> = vec![];
+
+        for position in [Position::Long, Position::Short] {
+            let (denom, reserve) = match position {
+                Position::Long => (
+                    config.base_denom.to_owned(),
+                    pool.base_amount + pool.base_borrow_amount,
+                ),
+                Position::Short => (
+                    config.price_denom.to_owned(),
+                    pool.price_amount + pool.price_borrow_amount,
+                ),
+            };
+            let (repay_amount, borrowed_amount) = control_adl_traders(
+                deps.storage,
+                &config,
+                base_price,
+                &position,
+                reserve,
+                &mut bank_msgs,
+                &mut events,
+            )?;
+            if borrowed_amount.is_zero() {
+                continue;
+            }
+            let funds = match repay_amount.is_zero() {
+                true => vec![],
+                false => vec![coin(repay_amount.into(), denom.to_owned())],
+            };
+            wasm_messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.pool_contract.to_string(),
+                msg: to_binary(&PoolExecuteMsg::RePay {
+                    denom,
+                    position: position.convert_boolean(),
+                    amount: repay_amount,
+                    borrowed_amount,
+                })?,
+                funds,
+            }));
+        }
+
+        Ok(Response::new()
+            .add_attribute("method", "auto_deleverage")
+            .add_messages(bank_msgs)
+            .add_messages(wasm_messages)
+            .add_events(events))
+    }
+
+    pub fn reset_breaker(
+        deps: DepsMut<SeiQueryWrapper>,
+        _env: Env,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        if info.sender != config.pool_contract {
+            return Err(ContractError::Unauthorized {});
+        }
+        let (current_price, _) = query_base_coin_price_and_price_coin_price(
+            &deps.querier,
+            &config.base_denom,
+            &config.price_denom,
+        )?;
+        let mut state = load_state(deps.storage)?;
+        state.past_price = current_price;
+        save_state(deps.storage, &state)?;
+
+        //@@ nav is snapshotted again by the next sweep
+        let mut breaker = load_breaker(deps.storage)?;
+        breaker.tripped = false;
+        breaker.reason = None;
+        breaker.epoch_nav = Decimal::zero();
+        save_breaker(deps.storage, &breaker)?;
+
+        Ok(Response::new().add_attribute("method", "reset_breaker"))
+    }
+
+    pub fn set_mode(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        mode: PauseMode,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        if info.sender != config.pool_contract {
+            return Err(ContractError::Unauthorized {});
+        }
+        let mut state = load_state(deps.storage)?;
+        state.mode = mode;
+        save_state(deps.storage, &state)?;
+        Ok(Response::new().add_attribute("method", "set_mode"))
+    }
+
+    pub fn delist(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        if info.sender != config.pool_contract {
+            return Err(ContractError::Unauthorized {});
+        }
+        let (base_price, stable_price) = query_base_coin_price_and_price_coin_price(
+            &deps.querier,
+            &config.base_denom,
+            &config.price_denom,
+        )?;
+        save_settlement(
+            deps.storage,
+            &Settlement {
+                base_price,
+                stable_price,
+            },
+        )?;
+
+        //@@ fees not swept yet are returned to the lps
+        let mut state = load_state(deps.storage)?;
+        state.mode = PauseMode::Delisted;
+        let fee_repay_msgs = [
+            (
+                config.base_denom.to_owned(),
+                true,
+                state.base_coin_total_fee,
+            ),
+            (
+                config.price_denom.to_owned(),
+                false,
+                state.price_coin_total_fee,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, _, amount)| !amount.is_zero())
+        .map(|(denom, position, amount)| {
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.pool_contract.to_string(),
+                msg: to_binary(&PoolExecuteMsg::RePay {
+                    denom: denom.to_owned(),
+                    position,
+                    amount,
+                    borrowed_amount: Uint128::zero(),
+                })?,
+                funds: vec![coin(amount.into(), denom)],
+            }))
+        })
+        .collect::<StdResult<Vec<CosmosMsg<SeiMsg>>>>()?;
+        state.base_coin_total_fee = Uint128::zero();
+        state.price_coin_total_fee = Uint128::zero();
+        save_state(deps.storage, &state)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "delist")
+            .add_attribute("settlement_price", base_price.to_string())
+            .add_messages(fee_repay_msgs))
+    }
+
+    pub fn settle_trade(
+        deps: DepsMut<SeiQueryWrapper>,
+        _info: MessageInfo,
+        trader: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let state = load_state(deps.storage)?;
+        check_mode(state.mode.can_settle())?;
+        let settlement = load_settlement(deps.storage)?.ok_or(ContractError::NotDelisted {})?;
+
+        let trader = deps.api.addr_validate(&trader)?;
+        let trade = trade_load(deps.storage, trader.to_owned())?;
+        let trader_amount = get_settle_amount(
+            &config,
+            &trade,
+            settlement.base_price,
+            settlement.stable_price,
+        )?;
+        let send_amount_to_pool = trade.collateral_amount + trade.leverage_amount - trader_amount;
+        trade_remove(deps.storage, trader.to_owned())?;
+
+        let mut response = Response::new()
+            .add_attribute("method", "settle_trade")
+            .add_event(
+                Event::new("settle_trade")
+                    .add_attribute("trader", trader.to_string())
+                    .add_attribute("settlement_price", settlement.base_price.to_string())
+                    .add_attribute("payout", trader_amount),
+            );
+        if !trader_amount.is_zero() {
+            response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: trader.to_string(),
+                amount: vec![coin(
+                    trader_amount.into(),
+                    trade.collateral_denom.to_owned(),
+                )],
+            }));
+        }
+        Ok(response.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.pool_contract.to_string(),
+            msg: to_binary(&PoolExecuteMsg::RePay {
+                denom: trade.collateral_denom.to_owned(),
+                position: trade.position.convert_boolean(),
+                amount: send_amount_to_pool,
+                borrowed_amount: trade.leverage_amount,
+            })?,
+            funds: match send_amount_to_pool.is_zero() {
+                true => vec![],
+                false => vec![coin(send_amount_to_pool.into(), trade.collateral_denom)],
+            },
+        })))
     }
 }
 
@@ -524,14 +960,17 @@ pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult
         QueryMsg::GetConfig {} => to_binary(&query::get_config(deps)?),
         QueryMsg::GetState {} => to_binary(&query::get_state(deps)?),
         QueryMsg::GetTrade { trader } => to_binary(&query::get_trade(deps, trader)?),
+        QueryMsg::GetBreakerStatus {} => to_binary(&query::get_breaker_status(deps)?),
     }
 }
 
 pub mod query {
-    use axis_protocol::market::{GetConfigResponse, GetStateResponse, TradeResponse};
+    use axis_protocol::market::{
+        BreakerStatusResponse, GetConfigResponse, GetStateResponse, TradeResponse,
+    };
 
     use crate::{
-        state::{load_config, load_state},
+        state::{load_breaker, load_config, load_settlement, load_state},
         trade::{trades, Trade},
     };
 
@@ -551,6 +990,11 @@ pub mod query {
             borrow_fee_rate,
             open_close_fee_rate,
             limit_profit_loss_open_fee_rate,
+            adl_threshold,
+            adl_close_rate,
+            price_breaker_rate,
+            nav_breaker_rate,
+            core_contract,
         } = config;
 
         Ok(GetConfigResponse {
@@ -565,19 +1009,42 @@ pub mod query {
             borrow_fee_rate,
             open_close_fee_rate,
             limit_profit_loss_open_fee_rate,
+            adl_threshold,
+            adl_close_rate,
+            price_breaker_rate,
+            nav_breaker_rate,
+            core_contract,
+        })
+    }
+    pub fn get_breaker_status(deps: Deps<SeiQueryWrapper>) -> StdResult<BreakerStatusResponse> {
+        let config = load_config(deps.storage)?;
+        let state = load_state(deps.storage)?;
+        let breaker = load_breaker(deps.storage)?;
+        Ok(BreakerStatusResponse {
+            tripped: breaker.tripped,
+            reason: breaker.reason,
+            past_price: state.past_price,
+            price_breaker_rate: config.price_breaker_rate,
+            nav_breaker_rate: config.nav_breaker_rate,
+            epoch: breaker.epoch,
+            epoch_nav: breaker.epoch_nav,
         })
     }
     pub fn get_state(deps: Deps<SeiQueryWrapper>) -> StdResult<GetStateResponse> {
         let state = load_state(deps.storage)?;
+        let settlement = load_settlement(deps.storage)?;
         let State {
             base_coin_total_fee,
             price_coin_total_fee,
             past_price,
+            mode,
         } = state;
         Ok(GetStateResponse {
             base_coin_total_fee,
             price_coin_total_fee,
             past_price,
+            mode,
+            settlement_price: settlement.map(|settlement| settlement.base_price),
         })
     }
 
@@ -596,6 +1063,7 @@ pub mod query {
             position_size,
             leverage,
             leverage_amount,
+            fee_tier,
         } = trade;
         Ok(TradeResponse {
             trader,
@@ -609,6 +1077,7 @@ pub mod query {
             position_size,
             leverage,
             leverage_amount,
+            fee_tier,
         })
     }
 }
@@ -48,4 +48,16 @@ pub enum ContractError {
 
     #[error("Convert Error")]
     ConvertError {},
+
+    #[error("Circuit Breaker Tripped")]
+    CircuitBreakerTripped {},
+
+    #[error("Not allowed in current pool mode")]
+    NotAllowedMode {},
+
+    #[error("Pair is not delisted")]
+    NotDelisted {},
+
+    #[error("ADL rate must be at most 100")]
+    InvalidAdlRate {},
 }
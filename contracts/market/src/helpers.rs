@@ -1,15 +1,24 @@
-use cosmwasm_std::{coin, BankMsg, Coin, CosmosMsg, Decimal, Storage, Uint128};
+use axis_protocol::core::{ExecuteMsg as CoreExecuteMsg, TraderReferralResponse};
+use axis_protocol::pool::PoolResponse;
+use cosmwasm_std::{
+    coin, to_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Event, StdResult, Storage, Uint128,
+    WasmMsg,
+};
 use sei_cosmwasm::SeiMsg;
 
 use crate::{
     error::ContractError,
     helpers::check::check_collateral_value,
     position::Position,
-    state::{Config, State},
-    trade::{trade_remove, PriceDestinatedStatus, Trade},
+    state::{Breaker, Config, State},
+    trade::{
+        get_adl_ranked_trades, trade_remove, trade_save, PriceDestinatedStatus, Trade,
+        ADL_TRADE_LIMIT,
+    },
 };
 const MINIMUM_USD_VALUE: u8 = 10;
 const PRICE_DECIMAL: u32 = 18;
+const LP_DECIMAL: u32 = 6;
 const LEVERAGE_DECIAML: u32 = 0;
 pub fn calculate_open_fee_amount(
     collateral_amount: Uint128,
@@ -21,6 +30,65 @@ pub fn calculate_open_fee_amount(
 pub fn calculate_close_fee_amount(trader_amount: Uint128, fee_rate: u8) -> Uint128 {
     trader_amount * Decimal::permille(fee_rate.into())
 }
+//staker discount , taken off before the referral split
+pub fn apply_fee_discount(fee_amount: Uint128, discount_rate: u8) -> Uint128 {
+    fee_amount - fee_amount * Decimal::percent(discount_rate.into())
+}
+//@@ (trader rebate, referrer reward) , both come out of the fee
+pub fn get_referral_amounts(
+    fee_amount: Uint128,
+    referral: &TraderReferralResponse,
+) -> (Uint128, Uint128) {
+    (
+        fee_amount * Decimal::percent(referral.rebate_rate.into()),
+        fee_amount * Decimal::percent(referral.referrer_rate.into()),
+    )
+}
+//referrer weight in the trader emissions
+pub fn get_referrer_fee_usd_amount(referral: &TraderReferralResponse, fee_usd: Decimal) -> Uint128 {
+    (fee_usd * Decimal::percent(referral.emission_rate.into())).to_uint_ceil()
+}
+pub fn get_referral_msgs(
+    config: &Config,
+    referral: &TraderReferralResponse,
+    trader: &Addr,
+    denom: &str,
+    (rebate_amount, reward_amount): (Uint128, Uint128),
+    fee_usd: Decimal,
+) -> StdResult<Vec<CosmosMsg<SeiMsg>>> {
+    let referrer = match &referral.referrer {
+        Some(referrer) => referrer,
+        None => return Ok(vec![]),
+    };
+    let mut msgs: Vec<CosmosMsg<SeiMsg>> = vec![];
+    if !rebate_amount.is_zero() {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: trader.to_string(),
+            amount: vec![coin(rebate_amount.into(), denom)],
+        }));
+    }
+    if !reward_amount.is_zero() {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: referrer.to_string(),
+            amount: vec![coin(reward_amount.into(), denom)],
+        }));
+    }
+    msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.core_contract.to_string(),
+        msg: to_binary(&CoreExecuteMsg::AddReferralFee {
+            base_denom: config.base_denom.to_owned(),
+            price_denom: config.price_denom.to_owned(),
+            trader: trader.to_owned(),
+            fee_usd_amount: fee_usd.to_uint_ceil(),
+            rebate_usd_amount: (fee_usd * Decimal::percent(referral.rebate_rate.into()))
+                .to_uint_floor(),
+            reward_usd_amount: (fee_usd * Decimal::percent(referral.referrer_rate.into()))
+                .to_uint_floor(),
+        })?,
+        funds: vec![],
+    }));
+    Ok(msgs)
+}
 pub fn calculate_position_size(collateral_amount: Uint128, leverage: u8) -> Uint128 {
     collateral_amount * Uint128::new(leverage.into())
 }
@@ -113,34 +181,83 @@ pub fn get_trader_amount(
     let past_leverage = entry_price * leverage;
     let current_leverage = current_price * leverage;
 
-    let (profit_position, loss_position) = match winning_position == trader_position {
-        true => (trader_position, winning_position),
-        false => (winning_position, trader_position),
-    };
-
-    let profit_usd = match profit_position {
-        Position::Long => current_leverage - past_leverage,
-        Position::Short => past_leverage - current_leverage,
-    };
+    let one_coin_amount = Uint128::new(10u128.pow(collateral_decimal.into()));
 
-    let loss_usd = match loss_position {
-        Position::Long => past_leverage - current_leverage,
-        Position::Short => current_leverage - past_leverage,
+    //@@ only the side the trader is on is computed, the opposite difference would underflow
+    let trader_amount = match winning_position == trader_position {
+        true => {
+            let profit_usd = match trader_position {
+                Position::Long => current_leverage.checked_sub(past_leverage),
+                Position::Short => past_leverage.checked_sub(current_leverage),
+            }
+            .unwrap_or(Decimal::zero());
+            let profit_amount = (profit_usd / collateral_price) * one_coin_amount;
+            collateral_amount + profit_amount
+        }
+        false => {
+            let loss_usd = match trader_position {
+                Position::Long => past_leverage.checked_sub(current_leverage),
+                Position::Short => current_leverage.checked_sub(past_leverage),
+            }
+            .unwrap_or(Decimal::zero());
+            let loss_amount = (loss_usd / collateral_price) * one_coin_amount;
+            collateral_amount
+                .checked_sub(loss_amount)
+                .unwrap_or(Uint128::zero())
+        }
     };
 
-    let one_coin_amount = Uint128::new(10u128.pow(collateral_decimal.into()));
+    Ok(trader_amount)
+}
 
-    let profit_amount = (profit_usd / collateral_price) * one_coin_amount;
-    let loss_amount = (loss_usd / collateral_price) * one_coin_amount;
+//@@ pnl of the whole position in collateral units, position_size * |price move| / collateral price
+//long collateral is the base coin at the current price, short collateral is the price coin at the entry price
+pub fn get_position_pnl_amount(
+    trader_position: &Position,
+    entry_price: Uint128,
+    current_price: Uint128,
+    position_size: Uint128,
+) -> Result<Uint128, ContractError> {
+    let price_diff = match current_price >= entry_price {
+        true => current_price - entry_price,
+        false => entry_price - current_price,
+    };
+    let collateral_price = match trader_position {
+        Position::Long => current_price,
+        Position::Short => entry_price,
+    };
+    let pnl_amount = position_size
+        .checked_multiply_ratio(price_diff, collateral_price)
+        .map_err(|_| ContractError::OverflowError {})?;
+    Ok(pnl_amount)
+}
 
-    let trader_amount = match winning_position {
-        Position::Long => collateral_amount + profit_amount,
-        Position::Short => collateral_amount
-            .checked_sub(loss_amount)
-            .unwrap_or(Uint128::zero()),
+//@@ loss beyond the collateral after a gap move, the part the pool can not recover from the trader
+pub fn get_bad_debt_amount(
+    trader_position: &Position,
+    entry_price: Uint128,
+    current_price: Uint128,
+    collateral_amount: Uint128,
+    position_size: Uint128,
+) -> Result<Uint128, ContractError> {
+    let is_loss = match trader_position {
+        Position::Long => current_price < entry_price,
+        Position::Short => current_price > entry_price,
     };
+    if !is_loss {
+        return Ok(Uint128::zero());
+    }
+    let loss_amount =
+        get_position_pnl_amount(trader_position, entry_price, current_price, position_size)?;
 
-    Ok(trader_amount)
+    Ok(loss_amount.saturating_sub(collateral_amount))
+}
+
+pub fn bad_debt_event(trader: &Addr, denom: &String, bad_debt: Uint128) -> Event {
+    Event::new("bad_debt")
+        .add_attribute("trader", trader.to_string())
+        .add_attribute("denom", denom.to_owned())
+        .add_attribute("amount", bad_debt)
 }
 // pub fn get_trader_amount(
 //     trader_position: &Position,
@@ -239,6 +356,9 @@ pub fn control_desitinated_traders(
     stable_coin_to_pool: &mut Coin,
     base_leveraged_amount: &mut Uint128,
     stable_leveraged_amount: &mut Uint128,
+    base_bad_debt: &mut Uint128,
+    stable_bad_debt: &mut Uint128,
+    events: &mut Vec<Event>,
 ) -> Result<(), ContractError> {
     //@@ This variables for recording open interest in a pool contract
 
@@ -284,6 +404,7 @@ pub fn control_desitinated_traders(
                     )?,
                 };
 
+                trader_amount = Uint128::min(trader_amount, collateral_amount + leverage_amount);
                 let close_fee_amount =
                     calculate_close_fee_amount(trader_amount, config.open_close_fee_rate);
                 trader_amount -= close_fee_amount;
@@ -320,16 +441,33 @@ pub fn control_desitinated_traders(
                 let send_amount_to_pool =
                     trade.collateral_amount + trade.leverage_amount - close_fee_amount;
 
+                let bad_debt = get_bad_debt_amount(
+                    &trade.position,
+                    trade.entry_price,
+                    base_price.atomics(),
+                    trade.collateral_amount,
+                    trade.position_size,
+                )?;
+                if !bad_debt.is_zero() {
+                    events.push(bad_debt_event(
+                        &trade.trader,
+                        &trade.collateral_denom,
+                        bad_debt,
+                    ));
+                }
+
                 match trade.position {
                     Position::Long => {
                         base_coin_to_pool.amount += send_amount_to_pool;
                         state.base_coin_total_fee += close_fee_amount;
                         *base_leveraged_amount += trade.leverage_amount;
+                        *base_bad_debt += bad_debt;
                     }
                     Position::Short => {
                         stable_coin_to_pool.amount += send_amount_to_pool;
                         state.price_coin_total_fee += close_fee_amount;
                         *stable_leveraged_amount += trade.leverage_amount;
+                        *stable_bad_debt += bad_debt;
                     }
                 }
                 trade_remove(storage, trade.trader)?;
@@ -373,9 +511,9 @@ pub fn control_desitinated_traders(
                         leverage,
                     )?,
                 };
+                trader_amount = Uint128::min(trader_amount, collateral_amount + leverage_amount);
                 let close_fee_amount =
                     calculate_close_fee_amount(trader_amount, config.open_close_fee_rate);
-
                 trader_amount -= close_fee_amount;
 
                 let bank_msg: CosmosMsg<SeiMsg> = CosmosMsg::Bank(BankMsg::Send {
@@ -404,11 +542,16 @@ pub fn control_desitinated_traders(
     Ok(())
 }
 
-pub fn fee_division(state: &mut State) -> (Uint128, Uint128, Uint128, Uint128) {
-    let send_base_fee_to_pool = state.base_coin_total_fee * Decimal::percent(90);
-    let send_price_fee_to_pool = state.price_coin_total_fee * Decimal::percent(90);
+//@@ pool 85% / vault 10% / insurance fund 5%
+pub fn fee_division(state: &mut State) -> (Uint128, Uint128, Uint128, Uint128, Uint128, Uint128) {
     let send_base_fee_to_valut = state.base_coin_total_fee * Decimal::percent(10);
     let send_price_fee_to_valut = state.price_coin_total_fee * Decimal::percent(10);
+    let send_base_fee_to_insurance = state.base_coin_total_fee * Decimal::percent(5);
+    let send_price_fee_to_insurance = state.price_coin_total_fee * Decimal::percent(5);
+    let send_base_fee_to_pool =
+        state.base_coin_total_fee - send_base_fee_to_valut - send_base_fee_to_insurance;
+    let send_price_fee_to_pool =
+        state.price_coin_total_fee - send_price_fee_to_valut - send_price_fee_to_insurance;
 
     state.base_coin_total_fee = Uint128::zero();
     state.price_coin_total_fee = Uint128::zero();
@@ -417,9 +560,212 @@ pub fn fee_division(state: &mut State) -> (Uint128, Uint128, Uint128, Uint128) {
         send_price_fee_to_pool,
         send_base_fee_to_valut,
         send_price_fee_to_valut,
+        send_base_fee_to_insurance,
+        send_price_fee_to_insurance,
     )
 }
 
+//@@ unrealized profit of a trade at the mark price, zero when the trade is losing
+pub fn get_trade_profit(trade: &Trade, base_price: Decimal) -> Result<Uint128, ContractError> {
+    let mark_price = base_price.atomics();
+    let is_profit = match trade.position {
+        Position::Long => mark_price > trade.entry_price,
+        Position::Short => mark_price < trade.entry_price,
+    };
+    match is_profit {
+        true => get_position_pnl_amount(
+            &trade.position,
+            trade.entry_price,
+            mark_price,
+            trade.position_size,
+        ),
+        false => Ok(Uint128::zero()),
+    }
+}
+
+//@@ pool side equity = reserve - unrealized trader profit, healthy while it stays above threshold% of reserve
+pub fn check_adl_health(reserve: Uint128, total_profit: Uint128, adl_threshold: u8) -> bool {
+    reserve.saturating_sub(total_profit) >= reserve * Decimal::percent(adl_threshold.into())
+}
+
+//@@ partially closes the ranked trades of one side at the mark price until the side is healthy
+//return (repay amount, borrowed amount) of the side
+pub fn control_adl_traders(
+    storage: &mut dyn Storage,
+    config: &Config,
+    base_price: Decimal,
+    position: &Position,
+    reserve: Uint128,
+    bank_msgs: &mut Vec<CosmosMsg<SeiMsg>>,
+    events: &mut Vec<Event>,
+) -> Result<(Uint128, Uint128), ContractError> {
+    let ranked_trades =
+        get_adl_ranked_trades(storage, position, config.max_leverage, ADL_TRADE_LIMIT)?;
+    let trade_profits = ranked_trades
+        .into_iter()
+        .map(|trade| {
+            let profit = get_trade_profit(&trade, base_price)?;
+            Ok((trade, profit))
+        })
+        .collect::<Result<Vec<(Trade, Uint128)>, ContractError>>()?;
+
+    let mut total_profit = trade_profits
+        .iter()
+        .fold(Uint128::zero(), |acc, (_, profit)| acc + profit);
+    let mut reserve = reserve;
+    let mut repay_amount = Uint128::zero();
+    let mut borrowed_amount = Uint128::zero();
+    let close_rate = Decimal::percent(config.adl_close_rate.into());
+
+    for (mut trade, profit) in trade_profits.into_iter() {
+        if check_adl_health(reserve, total_profit, config.adl_threshold) {
+            break;
+        }
+        if profit.is_zero() {
+            continue;
+        }
+        let closed_collateral = trade.collateral_amount * close_rate;
+        let closed_leverage = trade.leverage_amount * close_rate;
+        let closed_size = trade.position_size * close_rate;
+        let closed_profit = profit * close_rate;
+
+        //@@ payout never exceeds what the market holds for the closed part
+        let trader_amount = Uint128::min(
+            closed_collateral + closed_profit,
+            closed_collateral + closed_leverage,
+        );
+        repay_amount += closed_collateral + closed_leverage - trader_amount;
+        borrowed_amount += closed_leverage;
+        reserve = reserve.saturating_sub(trader_amount - closed_collateral);
+        total_profit -= closed_profit;
+
+        bank_msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: trade.trader.to_string(),
+            amount: vec![coin(
+                trader_amount.into(),
+                trade.collateral_denom.to_owned(),
+            )],
+        }));
+        events.push(
+            Event::new("auto_deleverage")
+                .add_attribute("trader", trade.trader.to_string())
+                .add_attribute("position", format!("{}", position.convert_boolean()))
+                .add_attribute("mark_price", base_price.to_string())
+                .add_attribute("closed_size", closed_size)
+                .add_attribute("closed_collateral", closed_collateral)
+                .add_attribute("payout", trader_amount)
+                .add_attribute("remaining_profit", total_profit),
+        );
+
+        trade.collateral_amount -= closed_collateral;
+        trade.leverage_amount -= closed_leverage;
+        trade.position_size -= closed_size;
+        match trade.collateral_amount.is_zero() {
+            true => trade_remove(storage, trade.trader)?,
+            false => trade_save(storage, trade.trader.to_owned(), trade)?,
+        }
+    }
+    Ok((repay_amount, borrowed_amount))
+}
+
+//@@ payout of a trade at the settlement price , no fee and capped at what the market holds for it
+pub fn get_settle_amount(
+    config: &Config,
+    trade: &Trade,
+    base_price: Decimal,
+    stable_price: Decimal,
+) -> Result<Uint128, ContractError> {
+    let settlement_price = base_price.atomics();
+    let winning_position = match settlement_price >= trade.entry_price {
+        true => Position::Long,
+        false => Position::Short,
+    };
+    let (collateral_decimal, collateral_price) = match trade.position {
+        Position::Long => (config.base_decimal, base_price),
+        Position::Short => (config.price_decimal, stable_price),
+    };
+    let trader_amount = get_trader_amount(
+        &trade.position,
+        &winning_position,
+        trade.entry_price,
+        settlement_price,
+        trade.collateral_amount,
+        collateral_decimal,
+        collateral_price,
+        trade.leverage,
+    )?;
+    Ok(Uint128::min(
+        trader_amount,
+        trade.collateral_amount + trade.leverage_amount,
+    ))
+}
+
+//@@ usd value of one lp token , reserve = pool amount + borrowed amount
+pub fn get_lp_nav(
+    config: &Config,
+    pool: &PoolResponse,
+    base_price: Decimal,
+    stable_price: Decimal,
+) -> Result<Decimal, ContractError> {
+    if pool.lp_total_supply.is_zero() {
+        return Ok(Decimal::zero());
+    }
+    let base_reserve = Decimal::from_atomics(
+        pool.base_amount + pool.base_borrow_amount,
+        config.base_decimal.into(),
+    )
+    .map_err(|_| ContractError::DecimalError {})?;
+    let price_reserve = Decimal::from_atomics(
+        pool.price_amount + pool.price_borrow_amount,
+        config.price_decimal.into(),
+    )
+    .map_err(|_| ContractError::DecimalError {})?;
+    let lp_total_supply = Decimal::from_atomics(pool.lp_total_supply, LP_DECIMAL)
+        .map_err(|_| ContractError::DecimalError {})?;
+
+    Ok((base_reserve * base_price + price_reserve * stable_price) / lp_total_supply)
+}
+
+//@@ snapshots the lp nav once per epoch and trips the breaker
+//return the reason when the breaker is tripped by this sweep
+pub fn check_circuit_breaker(
+    config: &Config,
+    breaker: &mut Breaker,
+    past_price: Decimal,
+    current_price: Decimal,
+    epoch: u64,
+    nav: Decimal,
+) -> Option<String> {
+    if breaker.epoch != epoch || breaker.epoch_nav.is_zero() {
+        breaker.epoch = epoch;
+        breaker.epoch_nav = nav;
+    }
+    if breaker.tripped {
+        return None;
+    }
+    let price_move = match current_price >= past_price {
+        true => current_price - past_price,
+        false => past_price - current_price,
+    };
+    let price_tripped = config.price_breaker_rate != 0
+        && !past_price.is_zero()
+        && price_move / past_price > Decimal::percent(config.price_breaker_rate.into());
+
+    let nav_tripped = config.nav_breaker_rate != 0
+        && nav < breaker.epoch_nav
+        && (breaker.epoch_nav - nav) / breaker.epoch_nav
+            > Decimal::percent(config.nav_breaker_rate.into());
+
+    let reason = match (price_tripped, nav_tripped) {
+        (true, _) => "price_move",
+        (false, true) => "nav_drawdown",
+        (false, false) => return None,
+    };
+    breaker.tripped = true;
+    breaker.reason = Some(reason.to_string());
+    Some(reason.to_string())
+}
+
 pub fn get_trade_information(
     entry_price: Decimal,
     collateral_price: Decimal,
@@ -452,6 +798,27 @@ pub mod check {
 
     use crate::{error::ContractError, position::Position, state::Config};
 
+    pub fn check_mode(allowed: bool) -> Result<(), ContractError> {
+        match allowed {
+            true => Ok(()),
+            false => Err(ContractError::NotAllowedMode {}),
+        }
+    }
+
+    pub fn check_adl_rate(adl_rate: u8) -> Result<(), ContractError> {
+        match adl_rate <= 100 {
+            true => Ok(()),
+            false => Err(ContractError::InvalidAdlRate {}),
+        }
+    }
+
+    pub fn check_breaker(tripped: bool) -> Result<(), ContractError> {
+        match tripped {
+            true => Err(ContractError::CircuitBreakerTripped {}),
+            false => Ok(()),
+        }
+    }
+
     pub fn check_leverage_amount(
         pool_balance: Uint128,
         leverage_amount: Uint128,
@@ -1,6 +1,9 @@
 use cosmwasm_std::{Addr, Decimal, QuerierWrapper, Uint128};
 
-use axis_protocol::pool::{PositionBalance, QueryMsg as PoolQueryMsg};
+use axis_protocol::core::{
+    QueryMsg as CoreQueryMsg, TraderFeeTierResponse, TraderReferralResponse,
+};
+use axis_protocol::pool::{PoolResponse, PositionBalance, QueryMsg as PoolQueryMsg};
 use sei_cosmwasm::{SeiQuerier, SeiQueryWrapper};
 
 use crate::{error::ContractError, position::Position};
@@ -44,3 +47,40 @@ pub fn query_pool_balance(
     )?;
     Ok(pool_balance.amount)
 }
+
+pub fn query_pool(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    pool_contract: &Addr,
+) -> Result<PoolResponse, ContractError> {
+    let pool: PoolResponse =
+        querier.query_wasm_smart(pool_contract.to_string(), &PoolQueryMsg::GetPool {})?;
+    Ok(pool)
+}
+
+pub fn query_trader_referral(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    trader: &Addr,
+) -> Result<TraderReferralResponse, ContractError> {
+    let referral: TraderReferralResponse = querier.query_wasm_smart(
+        core_contract.to_string(),
+        &CoreQueryMsg::GetTraderReferral {
+            trader: trader.to_string(),
+        },
+    )?;
+    Ok(referral)
+}
+
+pub fn query_trader_fee_tier(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    trader: &Addr,
+) -> Result<TraderFeeTierResponse, ContractError> {
+    let fee_tier: TraderFeeTierResponse = querier.query_wasm_smart(
+        core_contract.to_string(),
+        &CoreQueryMsg::GetTraderFeeTier {
+            trader: trader.to_string(),
+        },
+    )?;
+    Ok(fee_tier)
+}
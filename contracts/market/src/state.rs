@@ -1,3 +1,4 @@
+use axis_protocol::pool::PauseMode;
 use cosmwasm_schema::cw_serde;
 
 use cosmwasm_std::{Addr, Decimal, StdResult, Storage, Uint128};
@@ -25,6 +26,15 @@ pub struct Config {
     pub pool_contract: Addr,
     pub vault_contract: Addr,
     pub axis_contract: Addr,
+    //pool side equity % under which auto deleveraging starts
+    pub adl_threshold: u8,
+    //% of a position closed by one auto deleveraging step
+    pub adl_close_rate: u8,
+    //oracle price move % between sweeps that trips the breaker , 0 is off
+    pub price_breaker_rate: u8,
+    //lp nav drop % within an epoch that trips the breaker , 0 is off
+    pub nav_breaker_rate: u8,
+    pub core_contract: Addr,
 }
 
 pub fn save_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
@@ -40,6 +50,8 @@ pub struct State {
     pub base_coin_total_fee: Uint128,
     pub price_coin_total_fee: Uint128,
     pub past_price: Decimal,
+    //set by the pool
+    pub mode: PauseMode,
 }
 
 pub fn save_state(storage: &mut dyn Storage, state: &State) -> StdResult<()> {
@@ -50,7 +62,41 @@ pub fn load_state(storage: &dyn Storage) -> StdResult<State> {
     STATE.load(storage)
 }
 
+#[cw_serde]
+pub struct Breaker {
+    pub tripped: bool,
+    pub reason: Option<String>,
+    //lp nav at the first sweep of the epoch
+    pub epoch: u64,
+    pub epoch_nav: Decimal,
+}
+
+pub fn save_breaker(storage: &mut dyn Storage, breaker: &Breaker) -> StdResult<()> {
+    BREAKER.save(storage, breaker)
+}
+
+pub fn load_breaker(storage: &dyn Storage) -> StdResult<Breaker> {
+    BREAKER.load(storage)
+}
+
+//@@ oracle prices frozen when the pair is delisted
+#[cw_serde]
+pub struct Settlement {
+    pub base_price: Decimal,
+    pub stable_price: Decimal,
+}
+
+pub fn save_settlement(storage: &mut dyn Storage, settlement: &Settlement) -> StdResult<()> {
+    SETTLEMENT.save(storage, settlement)
+}
+
+pub fn load_settlement(storage: &dyn Storage) -> StdResult<Option<Settlement>> {
+    SETTLEMENT.may_load(storage)
+}
+
 //@@Fee_Config
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const STATE: Item<State> = Item::new("state");
+pub const BREAKER: Item<Breaker> = Item::new("breaker");
+pub const SETTLEMENT: Item<Settlement> = Item::new("settlement");
@@ -28,6 +28,9 @@ pub struct Trade {
     pub leverage: u8,
     //레버리지한 금액
     pub leverage_amount: Uint128,
+    //staker fee tier at open
+    #[serde(default)]
+    pub fee_tier: u8,
 }
 
 impl Trade {
@@ -44,6 +47,7 @@ impl Trade {
 
         leverage: u8,
         leverage_amount: Uint128,
+        fee_tier: u8,
     ) -> Self {
         //indexed map index key
         let limit_loss_price = match limit_loss_price {
@@ -67,6 +71,7 @@ impl Trade {
 
             leverage,
             leverage_amount,
+            fee_tier,
         }
         //stop loss option 처리
     }
@@ -76,6 +81,8 @@ pub struct TradeIndexes<'a> {
     pub liquidation_price: MultiIndex<'a, u128, Trade, Addr>,
     pub limit_profit_price: MultiIndex<'a, u128, Trade, Addr>,
     pub limit_loss_price: MultiIndex<'a, u128, Trade, Addr>,
+    //(position, leverage, profit rank of entry price)
+    pub adl_rank: MultiIndex<'a, (u8, u8, u128), Trade, Addr>,
 }
 
 impl<'a> IndexList<Trade> for TradeIndexes<'a> {
@@ -84,6 +91,7 @@ impl<'a> IndexList<Trade> for TradeIndexes<'a> {
             &self.liquidation_price,
             &self.limit_profit_price,
             &self.limit_loss_price,
+            &self.adl_rank,
         ];
         Box::new(v.into_iter())
     }
@@ -106,10 +114,23 @@ pub fn trades<'a>() -> IndexedMap<'a, Addr, Trade, TradeIndexes<'a>> {
             "trade_trader",
             "trade_limit_loss_price",
         ),
+        adl_rank: MultiIndex::new(
+            |_pk, d: &Trade| adl_rank_key(d),
+            "trade_trader",
+            "trade_adl_rank",
+        ),
     };
     IndexedMap::new("trade_trader", indexes)
 }
 
+//@@ descending order is most leveraged first, then the lowest entry for long / highest entry for short
+fn adl_rank_key(trade: &Trade) -> (u8, u8, u128) {
+    match trade.position {
+        Position::Long => (1, trade.leverage, u128::MAX - trade.entry_price.u128()),
+        Position::Short => (0, trade.leverage, trade.entry_price.u128()),
+    }
+}
+
 pub fn trade_update(
     storage: &mut dyn Storage,
     trader: Addr,
@@ -201,17 +222,18 @@ pub fn get_desitinated_price_traders(
         false => (before_price, now_price),
     };
 
-    let limit_loss = get_traders_in_price_range(storage, min, max, IndexType::Loss)?;
-    for trade in limit_loss.iter() {
+    //@@ a gap move can cross several prices of one trade, liquidation takes priority
+    let liquidated = get_traders_in_price_range(storage, min, max, IndexType::Liquidated)?;
+    for trade in liquidated.iter() {
         trade_remove(storage, trade.trader.clone())?;
     }
 
-    let limit_profit = get_traders_in_price_range(storage, min, max, IndexType::Profit)?;
-    for trade in limit_profit.iter() {
+    let limit_loss = get_traders_in_price_range(storage, min, max, IndexType::Loss)?;
+    for trade in limit_loss.iter() {
         trade_remove(storage, trade.trader.clone())?;
     }
 
-    let liquidated = get_traders_in_price_range(storage, min, max, IndexType::Liquidated)?;
+    let limit_profit = get_traders_in_price_range(storage, min, max, IndexType::Profit)?;
     for trade in limit_profit.iter() {
         trade_remove(storage, trade.trader.clone())?;
     }
@@ -221,3 +243,35 @@ pub fn get_desitinated_price_traders(
         liquidated: PriceDestinatedStatus::Liquidated(liquidated),
     })
 }
+
+//@@ trades one AutoDeleverage call looks at , the rest waits for the next call
+pub const ADL_TRADE_LIMIT: usize = 30;
+
+pub fn get_adl_ranked_trades(
+    storage: &dyn Storage,
+    position: &Position,
+    max_leverage: u8,
+    limit: usize,
+) -> Result<Vec<Trade>, ContractError> {
+    let side = match position {
+        Position::Long => 1u8,
+        Position::Short => 0u8,
+    };
+    let mut ranked_trades = vec![];
+    for leverage in (1..=max_leverage).rev() {
+        if ranked_trades.len() >= limit {
+            break;
+        }
+        let trades_by_leverage = trades()
+            .idx
+            .adl_rank
+            .sub_prefix((side, leverage))
+            .range_raw(storage, None, None, Order::Descending)
+            .take(limit - ranked_trades.len())
+            .map(|item| item.map(|(_, trade)| trade))
+            .collect::<StdResult<Vec<Trade>>>()
+            .map_err(|_| ContractError::ParseError {})?;
+        ranked_trades.extend(trades_by_leverage);
+    }
+    Ok(ranked_trades)
+}
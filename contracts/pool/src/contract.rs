@@ -7,7 +7,7 @@ use cosmwasm_std::{
 };
 use cw2::set_contract_version;
 
-use axis_protocol::pool::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use axis_protocol::pool::{ExecuteMsg, InstantiateMsg, PauseMode, QueryMsg};
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 use crate::error::ContractError;
@@ -58,7 +58,7 @@ pub fn instantiate(
     let lp_denom = "factory/".to_string() + env.contract.address.to_string().as_ref() + "/lp";
     let config = Config {
         core_contract: core_contract.to_owned(),
-        lock: false,
+        mode: PauseMode::Active,
         market_contract: Addr::unchecked(""),
         maximum_borrow_rate,
         lp_staking_contract: Addr::unchecked(""),
@@ -69,6 +69,7 @@ pub fn instantiate(
         lp_denom: lp_denom.clone(),
         lp_decimal: LP_DECIMAL,
         withdraw_fee_rate: Decimal::permille(1),
+        deposit_pause: false,
     };
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -103,6 +104,9 @@ pub fn instantiate(
                 base_denom: base_denom.to_owned(),
                 price_denom: price_denom.to_owned(),
                 lp_denom: lp_denom.to_owned(),
+                max_boost: None,
+                lock_tiers: None,
+                early_exit_penalty_rate: None,
             })?,
             funds: vec![],
             label: format!("{:?}:{:?} market", base_denom, price_denom),
@@ -154,10 +158,13 @@ pub fn execute(
             amount,
             borrowed_amount,
         } => execute::repay(deps, info, denom, position, amount, borrowed_amount),
+        CoverBadDebt {} => execute::cover_bad_debt(deps, info),
         Deposit {} => execute::deposit(deps, info, env),
         Withdraw {} => execute::withdraw(deps, env, info),
-        Lock {} => execute::lock(deps, env, info),
-        UnLock {} => execute::un_lock(deps, env, info),
+        SetMode { mode } => execute::set_mode(deps, info, mode),
+        TripBreaker {} => execute::trip_breaker(deps, info),
+        ResetBreaker {} => execute::reset_breaker(deps, info),
+        Delist {} => execute::delist(deps, info),
     }
 }
 
@@ -166,15 +173,18 @@ pub mod execute {
     use crate::{
         helpers::{
             check::{
-                check_core_contract, check_funds_and_get_funds, check_lock,
+                check_core_contract, check_deposit_pause, check_funds_and_get_funds,
                 check_lp_funds_and_get_lp_funds, check_market_contract,
-                check_maximum_leverage_amount, check_repay_denom,
+                check_maximum_leverage_amount, check_mode, check_repay_denom, check_settled,
+                check_vault_contract,
             },
             create_bank_msg,
         },
         state::{load_config, load_pool},
     };
 
+    use axis_protocol::market::ExecuteMsg as MarketExecuteMsg;
+    use axis_protocol::query::query_vault_contract;
     use cosmwasm_std::CosmosMsg;
     use cosmwasm_std::{coin, BankMsg, Uint128};
 
@@ -188,8 +198,8 @@ pub mod execute {
         position: bool,
         amount: Uint128,
     ) -> Result<Response<SeiMsg>, ContractError> {
-        check_lock(deps.storage)?;
         let config = load_config(deps.storage)?;
+        check_mode(config.mode.can_open())?;
         check_market_contract(&config.market_contract, &info.sender)?;
         let mut pool = load_pool(deps.storage)?;
 
@@ -238,6 +248,7 @@ pub mod execute {
     ) -> Result<Response<SeiMsg>, ContractError> {
         let mut pool = load_pool(deps.storage)?;
         let config = load_config(deps.storage)?;
+        check_mode(config.mode.can_repay())?;
         check_repay_denom(info.funds, &denom, amount)?;
         check_market_contract(&config.market_contract, &info.sender)?;
         match position {
@@ -254,6 +265,28 @@ pub mod execute {
         Ok(Response::new())
     }
 
+    //@@ insurance fund payout for liquidation shortfalls, credited without minting lp
+    pub fn cover_bad_debt(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let vault_contract = query_vault_contract(deps.querier, &config.core_contract)?;
+        check_vault_contract(&vault_contract, &info.sender)?;
+        let mut pool = load_pool(deps.storage)?;
+        for fund in info.funds.iter() {
+            if fund.denom == config.base_denom {
+                pool.base_amount += fund.amount;
+            } else if fund.denom == config.price_denom {
+                pool.price_amount += fund.amount;
+            } else {
+                return Err(ContractError::InvalidDenom {});
+            }
+        }
+        save_pool(deps.storage, &pool)?;
+        Ok(Response::new().add_attribute("method", "cover_bad_debt"))
+    }
+
     pub fn deposit(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
@@ -261,6 +294,8 @@ pub mod execute {
     ) -> Result<Response<SeiMsg>, ContractError> {
         let mut pool = load_pool(deps.storage)?;
         let config = load_config(deps.storage)?;
+        check_mode(config.mode.can_deposit())?;
+        check_deposit_pause(config.deposit_pause)?;
         let reserve_price_amount = pool.price_amount + pool.price_borrow_amount;
         let reserve_base_amount = pool.base_amount + pool.base_borrow_amount;
         let (send_base, send_price) =
@@ -333,6 +368,11 @@ pub mod execute {
     ) -> Result<Response<SeiMsg>, ContractError> {
         let mut pool = load_pool(deps.storage)?;
         let config = load_config(deps.storage)?;
+        check_mode(config.mode.can_withdraw())?;
+        //@@ lps of a delisted pair withdraw at the final nav , after every trade is settled
+        if config.mode == PauseMode::Delisted {
+            check_settled(&pool)?;
+        }
 
         let lp_token = check_lp_funds_and_get_lp_funds(info.funds, &config.lp_denom)?;
         let lp_total_supply = pool.lp_total_supply;
@@ -370,29 +410,75 @@ pub mod execute {
             .add_message(lp_burn_msg))
     }
 
-    pub fn lock(
+    pub fn set_mode(
         deps: DepsMut<SeiQueryWrapper>,
-        _env: Env,
         info: MessageInfo,
+        mode: PauseMode,
     ) -> Result<Response<SeiMsg>, ContractError> {
-        let config = load_config(deps.storage)?;
-        check_core_contract(&config.core_contract, &info.sender)?;
         let mut config = load_config(deps.storage)?;
-        config.lock = true;
+        check_core_contract(&config.core_contract, &info.sender)?;
+        check_mode(config.mode.can_set_mode())?;
+        check_mode(mode.can_set_mode())?;
+        config.mode = mode.to_owned();
         save_config(deps.storage, &config)?;
-        Ok(Response::default())
+        let market_mode_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.market_contract.to_string(),
+            msg: to_binary(&MarketExecuteMsg::SetMode {
+                mode: mode.to_owned(),
+            })?,
+            funds: vec![],
+        });
+        Ok(Response::new()
+            .add_attribute("method", "set_mode")
+            .add_attribute("mode", format!("{:?}", mode))
+            .add_message(market_mode_msg))
     }
-    pub fn un_lock(
+
+    pub fn delist(
         deps: DepsMut<SeiQueryWrapper>,
-        _env: Env,
         info: MessageInfo,
     ) -> Result<Response<SeiMsg>, ContractError> {
-        let config = load_config(deps.storage)?;
+        let mut config = load_config(deps.storage)?;
         check_core_contract(&config.core_contract, &info.sender)?;
+        config.mode = PauseMode::Delisted;
+        save_config(deps.storage, &config)?;
+        let market_delist_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.market_contract.to_string(),
+            msg: to_binary(&MarketExecuteMsg::Delist {})?,
+            funds: vec![],
+        });
+        Ok(Response::new()
+            .add_attribute("method", "delist")
+            .add_message(market_delist_msg))
+    }
+
+    pub fn trip_breaker(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+        check_market_contract(&config.market_contract, &info.sender)?;
+        config.deposit_pause = true;
+        save_config(deps.storage, &config)?;
+        Ok(Response::new().add_attribute("method", "trip_breaker"))
+    }
+
+    pub fn reset_breaker(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
         let mut config = load_config(deps.storage)?;
-        config.lock = false;
+        check_core_contract(&config.core_contract, &info.sender)?;
+        config.deposit_pause = false;
         save_config(deps.storage, &config)?;
-        Ok(Response::default())
+        let market_reset_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.market_contract.to_string(),
+            msg: to_binary(&MarketExecuteMsg::ResetBreaker {})?,
+            funds: vec![],
+        });
+        Ok(Response::new()
+            .add_attribute("method", "reset_breaker")
+            .add_message(market_reset_msg))
     }
 }
 
@@ -454,7 +540,7 @@ pub mod query {
 
         Ok(ConfigResponse {
             core_contract: config.core_contract,
-            lock: config.lock,
+            mode: config.mode,
             market_contract: config.market_contract,
             lp_decimal: config.lp_decimal,
             lp_denom: config.lp_denom,
@@ -465,6 +551,7 @@ pub mod query {
             price_decimal: config.price_decimal,
             price_denom: config.price_denom,
             withdraw_fee_rate: config.withdraw_fee_rate,
+            deposit_pause: config.deposit_pause,
         })
     }
     pub fn get_pool(deps: Deps<SeiQueryWrapper>) -> StdResult<PoolResponse> {
@@ -474,6 +561,7 @@ pub mod query {
             price_amount: pool.price_amount,
             base_borrow_amount: pool.base_borrow_amount,
             price_borrow_amount: pool.price_borrow_amount,
+            lp_total_supply: pool.lp_total_supply,
         })
     }
 }
@@ -36,8 +36,14 @@ pub enum ContractError {
     #[error("Market Contract Instantiation Failed")]
     MarketContractInstantiationFailed {},
 
-    #[error("Pool is Lock")]
-    PoolLock {},
+    #[error("Not allowed in current pool mode")]
+    NotAllowedMode {},
+
+    #[error("Deposit is paused by circuit breaker")]
+    DepositPause {},
+
+    #[error("Trades of the delisted pair are not settled yet")]
+    SettlementPending {},
 
     #[error("Lp staking Contract Missing Failed")]
     LpStakingContractInstantiationFailed {},
@@ -80,13 +80,24 @@ pub fn calculate_lp_mint_amount(
 }
 
 pub mod check {
-    use cosmwasm_std::{Addr, Coin, Decimal, Storage, Uint128};
+    use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
 
-    use crate::{error::ContractError, state::load_config};
-    pub fn check_lock(storage: &mut dyn Storage) -> Result<(), ContractError> {
-        let config = load_config(storage)?;
-        match config.lock {
-            true => Err(ContractError::PoolLock {}),
+    use crate::{error::ContractError, state::Pool};
+    pub fn check_mode(allowed: bool) -> Result<(), ContractError> {
+        match allowed {
+            true => Ok(()),
+            false => Err(ContractError::NotAllowedMode {}),
+        }
+    }
+    pub fn check_settled(pool: &Pool) -> Result<(), ContractError> {
+        match pool.base_borrow_amount.is_zero() && pool.price_borrow_amount.is_zero() {
+            true => Ok(()),
+            false => Err(ContractError::SettlementPending {}),
+        }
+    }
+    pub fn check_deposit_pause(deposit_pause: bool) -> Result<(), ContractError> {
+        match deposit_pause {
+            true => Err(ContractError::DepositPause {}),
             false => Ok(()),
         }
     }
@@ -106,6 +117,13 @@ pub mod check {
         }
     }
 
+    pub fn check_vault_contract(vault_contract: &Addr, sender: &Addr) -> Result<(), ContractError> {
+        match vault_contract == sender {
+            true => Ok(()),
+            false => Err(ContractError::Unauthorized {}),
+        }
+    }
+
     pub fn check_maximum_leverage_amount(
         leverage_amount: Uint128,
         pool_amount: Uint128,
@@ -147,6 +165,10 @@ pub mod check {
         denom: &String,
         amount: Uint128,
     ) -> Result<(), ContractError> {
+        //@@ borrow can be settled without funds when the whole position went to the trader
+        if amount.is_zero() {
+            return Ok(());
+        }
         let coin = funds
             .into_iter()
             .find(|c| c.denom == *denom)
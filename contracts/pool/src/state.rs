@@ -1,3 +1,4 @@
+use axis_protocol::pool::PauseMode;
 use cosmwasm_schema::cw_serde;
 
 use cosmwasm_std::{Addr, Decimal, Response, StdResult, Storage, Uint128};
@@ -30,13 +31,15 @@ pub struct Config {
     pub price_denom: String,
     pub price_decimal: u8,
     pub core_contract: Addr,
-    pub lock: bool,
+    pub mode: PauseMode,
     pub market_contract: Addr,
     pub lp_denom: String,
     pub lp_decimal: u8,
     pub maximum_borrow_rate: u8,
     pub lp_staking_contract: Addr,
     pub withdraw_fee_rate: Decimal,
+    //set by the market circuit breaker
+    pub deposit_pause: bool,
 }
 pub fn register_market_contract(
     storage: &mut dyn Storage,
@@ -50,7 +50,10 @@ pub fn instantiate(
         WasmMsg::Instantiate {
             admin: None,
             code_id: msg.es_axis_code,
-            msg: to_binary(&ESAxisInstantiateMsg {})?,
+            msg: to_binary(&ESAxisInstantiateMsg {
+                core_contract: msg.core_contract,
+                vesting_config: msg.es_axis_vesting_config,
+            })?,
             funds: vec![],
             label: "es-axis-contract".to_string(),
         },
@@ -72,23 +75,31 @@ pub fn execute(
     use execute::*;
     match msg {
         ExecuteMsg::Staking {} => staking(deps, info),
-        ExecuteMsg::UnStaking {} => un_staking(deps, info),
+        ExecuteMsg::UnStaking { amount } => un_staking(deps, info, amount),
         ExecuteMsg::Withdraw {} => withdraw(deps, info),
-        ExecuteMsg::ClaimReward {} => claim_reward(deps, info),
+        ExecuteMsg::ClaimReward { until_epoch } => claim_reward(deps, info, until_epoch),
+        ExecuteMsg::CancelUnbonding { unlock_epoch } => cancel_unbonding(deps, info, unlock_epoch),
         ExecuteMsg::Setting { epoch } => setting(deps, info, epoch),
+        ExecuteMsg::ReceiveFees {} => receive_fees(deps, info),
+        ExecuteMsg::ClaimFees {} => claim_fees(deps, info),
     }
 }
 
 pub mod execute {
 
+    use axis_protocol::query::query_vault_contract;
     use cosmwasm_std::{coin, BankMsg, CosmosMsg, SubMsg, WasmMsg};
     use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
     use crate::{
-        helpers::{check_core_contract, check_funds_and_get_axis, compute_mint_amount},
+        helpers::{
+            check_claim_epoch, check_core_contract, check_funds_and_get_axis, check_reserved,
+            check_unstake_amount, check_vault_contract, settle_rewards,
+        },
         state::{
-            load_config, load_stakings, load_state, load_un_stakings, save_state, StakeInfo,
-            UnStakeInfo, EPOCH_STAKING_AMOUNT, STAKING, UN_STAKING,
+            add_stake, load_accrued_fees, load_config, load_fee_denoms, load_stakings, load_state,
+            load_un_stakings, save_state, UnStakeInfo, ACCRUED_FEES, ACCRUED_REWARD,
+            ES_AXIS_REWARD, FEE_DENOMS, PENDING_FEES, REWARD_INDEX, STAKING, UN_STAKING,
         },
     };
 
@@ -102,34 +113,7 @@ pub mod execute {
         let axis_coin = check_funds_and_get_axis(info.funds, &config.axis_denom)?;
         let epoch = state.epoch;
 
-        STAKING.update(
-            deps.storage,
-            info.sender,
-            |exsits| -> StdResult<Vec<StakeInfo>> {
-                match exsits {
-                    Some(mut stakings) => {
-                        if let Some(stake) =
-                            stakings.iter_mut().find(|s| s.start_epoch == epoch + 1)
-                        {
-                            stake.staking_amount += axis_coin.amount;
-                        } else {
-                            stakings.push(StakeInfo {
-                                start_epoch: epoch + 1,
-                                staking_amount: axis_coin.amount,
-                            })
-                        }
-                        Ok(stakings)
-                    }
-                    None => {
-                        let stakings = vec![StakeInfo {
-                            start_epoch: epoch + 1,
-                            staking_amount: axis_coin.amount,
-                        }];
-                        Ok(stakings)
-                    }
-                }
-            },
-        )?;
+        add_stake(deps.storage, info.sender, epoch + 1, axis_coin.amount)?;
 
         state.pending_staking_total += axis_coin.amount;
         save_state(deps.storage, &state)?;
@@ -140,23 +124,48 @@ pub mod execute {
     pub fn un_staking(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        amount: Option<Uint128>,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         let mut state = load_state(deps.storage)?;
 
         let epoch = state.epoch;
-        let stakings = load_stakings(deps.storage, info.sender.clone())?;
+        let mut stakings = load_stakings(deps.storage, info.sender.clone())?;
+        let staked_amount: Uint128 = stakings.iter().map(|stake| stake.staking_amount).sum();
+        let withdraw_pending_amount = amount.unwrap_or(staked_amount);
+        check_unstake_amount(withdraw_pending_amount, staked_amount)?;
+        //@@ esAXIS vesting keeps the reserved stake locked
+        check_reserved(
+            deps.querier,
+            &config.es_axis_contract,
+            &info.sender,
+            staked_amount - withdraw_pending_amount,
+        )?;
 
         let unlock_epoch = epoch + 1;
 
-        let mut withdraw_pending_amount = Uint128::zero();
-
-        let mut ex_axis_amount = Uint128::zero();
-
-        for stake in stakings.into_iter() {
-            ex_axis_amount +=
-                compute_mint_amount(deps.storage, stake.staking_amount, stake.start_epoch, epoch)?;
-            withdraw_pending_amount += stake.staking_amount;
+        //@@ rewards of the whole stake are paid before it shrinks
+        let ex_axis_amount = settle_rewards(deps.storage, &info.sender, &mut stakings, epoch)?
+            + ACCRUED_REWARD
+                .may_load(deps.storage, &info.sender)?
+                .unwrap_or_default();
+        ACCRUED_REWARD.remove(deps.storage, &info.sender);
+
+        //@@ newest stakes leave first , pending ones are not in staking_total yet
+        let mut remaining_amount = withdraw_pending_amount;
+        for stake in stakings.iter_mut().rev() {
+            let amount = Uint128::min(stake.staking_amount, remaining_amount);
+            stake.staking_amount -= amount;
+            remaining_amount -= amount;
+            match stake.start_epoch > epoch {
+                true => state.pending_staking_total -= amount,
+                false => state.staking_total -= amount,
+            }
+        }
+        stakings.retain(|stake| !stake.staking_amount.is_zero());
+        match stakings.is_empty() {
+            true => STAKING.remove(deps.storage, info.sender.to_owned()),
+            false => STAKING.save(deps.storage, info.sender.to_owned(), &stakings)?,
         }
 
         let un_stake = UnStakeInfo {
@@ -164,8 +173,6 @@ pub mod execute {
             withdraw_pending_amount,
         };
 
-        STAKING.remove(deps.storage, info.sender.to_owned());
-
         UN_STAKING.update(
             deps.storage,
             info.sender.to_owned(),
@@ -183,11 +190,6 @@ pub mod execute {
             },
         )?;
 
-        state.staking_total -= state
-            .staking_total
-            .checked_sub(withdraw_pending_amount)
-            .unwrap_or_default();
-
         state.withdraw_pending_total += withdraw_pending_amount;
 
         save_state(deps.storage, &state)?;
@@ -206,6 +208,48 @@ pub mod execute {
         }
     }
 
+    pub fn cancel_unbonding(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        unlock_epoch: u64,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut state = load_state(deps.storage)?;
+        let un_stakings = UN_STAKING
+            .may_load(deps.storage, info.sender.clone())?
+            .unwrap_or_default();
+        let (cancelled, remaining_un_stakings): (Vec<UnStakeInfo>, Vec<UnStakeInfo>) = un_stakings
+            .into_iter()
+            .partition(|un_stake| un_stake.unlock_epoch == unlock_epoch);
+        let restake_amount: Uint128 = cancelled
+            .iter()
+            .map(|un_stake| un_stake.withdraw_pending_amount)
+            .sum();
+        if restake_amount.is_zero() {
+            return Err(ContractError::NoUnbonding {});
+        }
+
+        match remaining_un_stakings.len() {
+            0 => UN_STAKING.remove(deps.storage, info.sender.clone()),
+            _ => UN_STAKING.save(deps.storage, info.sender.clone(), &remaining_un_stakings)?,
+        }
+
+        //@@ restaked AXIS earns from the next epoch like a new stake
+        add_stake(
+            deps.storage,
+            info.sender.clone(),
+            state.epoch + 1,
+            restake_amount,
+        )?;
+        state.withdraw_pending_total -= restake_amount;
+        state.pending_staking_total += restake_amount;
+        save_state(deps.storage, &state)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "cancel_unbonding")
+            .add_attribute("staker", info.sender)
+            .add_attribute("amount", restake_amount))
+    }
+
     pub fn withdraw(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
@@ -252,27 +296,21 @@ pub mod execute {
     pub fn claim_reward(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        until_epoch: Option<u64>,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         let mut stakings = load_stakings(deps.storage, info.sender.clone())?;
-        let mut claim_es_axis_amount = Uint128::zero();
         let state = load_state(deps.storage)?;
+        let until_epoch = until_epoch.unwrap_or(state.epoch);
+        check_claim_epoch(until_epoch, state.epoch)?;
 
-        for stake in stakings.iter_mut() {
-            if stake.start_epoch >= state.epoch {
-                continue;
-            }
-
-            let reward = compute_mint_amount(
-                deps.storage,
-                stake.staking_amount,
-                stake.start_epoch,
-                state.epoch,
-            )?;
-
-            stake.start_epoch = state.epoch;
-            claim_es_axis_amount += reward;
-        }
+        let claim_es_axis_amount =
+            settle_rewards(deps.storage, &info.sender, &mut stakings, until_epoch)?
+                + ACCRUED_REWARD
+                    .may_load(deps.storage, &info.sender)?
+                    .unwrap_or_default();
+        ACCRUED_REWARD.remove(deps.storage, &info.sender);
+        STAKING.save(deps.storage, info.sender.clone(), &stakings)?;
 
         let claim_msg = CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: config.es_axis_contract.to_string(),
@@ -285,6 +323,69 @@ pub mod execute {
         Ok(Response::new().add_message(claim_msg))
     }
 
+    pub fn receive_fees(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let state = load_state(deps.storage)?;
+        let vault_contract = query_vault_contract(deps.querier, &config.core_contract)?;
+        check_vault_contract(&vault_contract, &info.sender)?;
+
+        let mut fee_denoms = load_fee_denoms(deps.storage)?;
+        for fund in info.funds.iter() {
+            //@@ stakes opened before the first fee of a denom earn it from here
+            if !fee_denoms.contains(&fund.denom) {
+                fee_denoms.push(fund.denom.to_owned());
+                REWARD_INDEX.checkpoint(deps.storage, &fund.denom, state.epoch)?;
+            }
+            PENDING_FEES.update(deps.storage, &fund.denom, |pending| -> StdResult<_> {
+                Ok(pending.unwrap_or_default() + fund.amount)
+            })?;
+        }
+        FEE_DENOMS.save(deps.storage, &fee_denoms)?;
+
+        Ok(Response::new().add_attribute("method", "receive_fees"))
+    }
+
+    pub fn claim_fees(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let state = load_state(deps.storage)?;
+        let mut stakings = STAKING
+            .may_load(deps.storage, info.sender.clone())?
+            .unwrap_or_default();
+
+        //@@ settling moves the stakes , their esAXIS waits for ClaimReward
+        let es_axis_amount =
+            settle_rewards(deps.storage, &info.sender, &mut stakings, state.epoch)?;
+        if !stakings.is_empty() {
+            STAKING.save(deps.storage, info.sender.clone(), &stakings)?;
+        }
+        if !es_axis_amount.is_zero() {
+            ACCRUED_REWARD.update(deps.storage, &info.sender, |accrued| -> StdResult<_> {
+                Ok(accrued.unwrap_or_default() + es_axis_amount)
+            })?;
+        }
+
+        let fees = load_accrued_fees(deps.storage, &info.sender)?;
+        for fee in fees.iter() {
+            ACCRUED_FEES.remove(deps.storage, (&info.sender, &fee.denom));
+        }
+
+        let response = Response::new()
+            .add_attribute("method", "claim_fees")
+            .add_attribute("staker", info.sender.to_string());
+        match fees.is_empty() {
+            true => Ok(response),
+            false => Ok(response.add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: fees,
+            })),
+        }
+    }
+
     pub fn setting(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
@@ -300,33 +401,41 @@ pub mod execute {
         // Get the current epoch
         // let current_epoch = query_epoch(deps.querier, &config.core_contract)? - 1;
 
-        // Save the total staking amount for the current epoch
-        EPOCH_STAKING_AMOUNT.save(deps.storage, state.epoch, &state.staking_total)?;
+        // Share the reward of the ended epoch over the stake active in it
+        REWARD_INDEX.distribute(
+            deps.storage,
+            ES_AXIS_REWARD,
+            epoch,
+            ONE_DAY_PER_MINT.into(),
+            state.staking_total,
+        )?;
+
+        //@@ fees wait in pending while nothing is staked
+        for denom in load_fee_denoms(deps.storage)?.iter() {
+            let pending_fee = PENDING_FEES
+                .may_load(deps.storage, denom)?
+                .unwrap_or_default();
+            REWARD_INDEX.distribute(
+                deps.storage,
+                denom,
+                epoch,
+                pending_fee,
+                state.staking_total,
+            )?;
+            if !state.staking_total.is_zero() {
+                PENDING_FEES.remove(deps.storage, denom);
+            }
+        }
 
         // Update the total staking amount and reset the pending staking total
-        let is_init = state.staking_total.is_zero();
         state.epoch = epoch;
         state.staking_total += state.pending_staking_total;
         state.pending_staking_total = Uint128::zero();
 
         save_state(deps.storage, &state)?;
 
-        // If the stake total is zero, return an empty response
-        if is_init {
-            return Ok(Response::new());
-        }
-
-        // Otherwise, create a mint message
-
-        let mint_msg = CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: config.es_axis_contract.to_string(),
-            msg: to_binary(&ESAxisExecuteMsg::Mint {
-                amount: ONE_DAY_PER_MINT.into(),
-            })?,
-            funds: vec![],
-        });
-
-        Ok(Response::new().add_message(mint_msg))
+        //@@ es axis mints the rewards when they are claimed
+        Ok(Response::new())
     }
 }
 
@@ -340,6 +449,7 @@ pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult
         QueryMsg::GetAvailableReward { addr } => {
             to_binary(&query::get_available_claim_reward(deps, addr)?)
         }
+        QueryMsg::GetPendingFees { address } => to_binary(&query::get_pending_fees(deps, address)?),
     }
 }
 
@@ -349,9 +459,14 @@ pub mod query {
         UnStakeResponse,
     };
 
+    use cosmwasm_std::{coin, Coin};
+
     use crate::{
-        helpers::compute_mint_amount,
-        state::{load_config, load_stakings, load_state, load_un_stakings},
+        helpers::{compute_fee_amount, compute_mint_amount},
+        state::{
+            load_accrued_fees, load_config, load_fee_denoms, load_stakings, load_state,
+            load_un_stakings, ACCRUED_REWARD, STAKING,
+        },
     };
 
     use super::*;
@@ -380,7 +495,8 @@ pub mod query {
         addr: String,
     ) -> StdResult<StakeInfoResponse> {
         let staker = deps.api.addr_validate(&addr)?;
-        let stakings = load_stakings(deps.storage, staker)?;
+        //@@ no staking is an empty list , core reads it for fee tiers
+        let stakings = STAKING.may_load(deps.storage, staker)?.unwrap_or_default();
 
         let stakings_response = stakings
             .into_iter()
@@ -421,7 +537,7 @@ pub mod query {
         let epoch = query_epoch(deps.querier, &config.core_contract)?;
 
         let staker = deps.api.addr_validate(&addr)?;
-        let stakings = load_stakings(deps.storage, staker)?;
+        let stakings = load_stakings(deps.storage, staker.clone())?;
         let ex_axis_amount: Uint128 = stakings
             .iter()
             .map(|stake| {
@@ -429,7 +545,30 @@ pub mod query {
             })
             .sum::<StdResult<Uint128>>()?;
 
-        Ok(ex_axis_amount)
+        //@@ rewards settled by ClaimFees wait in ACCRUED_REWARD
+        Ok(ex_axis_amount
+            + ACCRUED_REWARD
+                .may_load(deps.storage, &staker)?
+                .unwrap_or_default())
+    }
+
+    //@@ accrued fees plus the fees the stakes earned since their last settle
+    pub fn get_pending_fees(deps: Deps<SeiQueryWrapper>, address: String) -> StdResult<Vec<Coin>> {
+        let state = load_state(deps.storage)?;
+        let staker = deps.api.addr_validate(&address)?;
+        let stakings = STAKING
+            .may_load(deps.storage, staker.clone())?
+            .unwrap_or_default();
+        let mut fees = load_accrued_fees(deps.storage, &staker)?;
+        for denom in load_fee_denoms(deps.storage)? {
+            let fee_amount = compute_fee_amount(deps.storage, &denom, &stakings, state.epoch)?;
+            match fees.iter_mut().find(|fee| fee.denom == denom) {
+                Some(fee) => fee.amount += fee_amount,
+                None => fees.push(coin(fee_amount.u128(), denom)),
+            }
+        }
+        fees.retain(|fee| !fee.amount.is_zero());
+        Ok(fees)
     }
 }
 
@@ -20,4 +20,16 @@ pub enum ContractError {
 
     #[error("Missing es Axis Contarct")]
     MissingEsAxisContractAddr {},
+
+    #[error("Staked Axis Reserved For Vesting")]
+    ReservedForVesting {},
+
+    #[error("Invalid Amount")]
+    InvalidAmount {},
+
+    #[error("Invalid Epoch")]
+    InvalidEpoch {},
+
+    #[error("No Unbonding")]
+    NoUnbonding {},
 }
@@ -1,10 +1,13 @@
-use cosmwasm_std::{Addr, Attribute, Coin, Decimal, Order, StdResult, Storage, Uint128};
-use cw_storage_plus::Bound;
+use axis_protocol::es_axis::QueryMsg as EsAxisQueryMsg;
+use cosmwasm_std::{Addr, Attribute, Coin, QuerierWrapper, StdResult, Storage, Uint128};
+use sei_cosmwasm::SeiQueryWrapper;
 
 use crate::{
-    contract::ONE_DAY_PER_MINT,
     error::ContractError,
-    state::{load_config, save_config, EPOCH_STAKING_AMOUNT},
+    state::{
+        load_config, load_fee_denoms, save_config, StakeInfo, ACCRUED_FEES, ES_AXIS_REWARD,
+        REWARD_INDEX,
+    },
 };
 
 pub fn check_funds_and_get_axis(
@@ -25,26 +28,102 @@ pub fn compute_mint_amount(
     start: u64,
     now: u64,
 ) -> StdResult<Uint128> {
-    if start == now {
-        return Ok(Uint128::zero());
+    REWARD_INDEX.pending_reward(storage, ES_AXIS_REWARD, staking_amount, start, now)
+}
+
+//@@ fees of one denom earned by the stakes in [start_epoch, until_epoch)
+pub fn compute_fee_amount(
+    storage: &dyn Storage,
+    denom: &str,
+    stakings: &[StakeInfo],
+    until_epoch: u64,
+) -> StdResult<Uint128> {
+    stakings
+        .iter()
+        .map(|stake| {
+            REWARD_INDEX.pending_reward(
+                storage,
+                denom,
+                stake.staking_amount,
+                stake.start_epoch,
+                until_epoch,
+            )
+        })
+        .sum()
+}
+
+//@@ the stake left after unstaking must still cover the vesting reserve
+pub fn check_reserved(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    es_axis_contract: &Addr,
+    staker: &Addr,
+    remaining_amount: Uint128,
+) -> Result<(), ContractError> {
+    let reserved_amount: Uint128 = querier.query_wasm_smart(
+        es_axis_contract.to_string(),
+        &EsAxisQueryMsg::GetReservedAmount {
+            address: staker.to_string(),
+        },
+    )?;
+    match reserved_amount <= remaining_amount {
+        true => Ok(()),
+        false => Err(ContractError::ReservedForVesting {}),
+    }
+}
+
+pub fn check_unstake_amount(amount: Uint128, staked_amount: Uint128) -> Result<(), ContractError> {
+    match !amount.is_zero() && amount <= staked_amount {
+        true => Ok(()),
+        false => Err(ContractError::InvalidAmount {}),
+    }
+}
+
+pub fn check_claim_epoch(until_epoch: u64, epoch: u64) -> Result<(), ContractError> {
+    match until_epoch <= epoch {
+        true => Ok(()),
+        false => Err(ContractError::InvalidEpoch {}),
+    }
+}
+
+//@@ pays the rewards of [start_epoch, until_epoch) and moves the stakes to until_epoch
+//@@ fees of the same range are kept in the accrued ledger until ClaimFees
+pub fn settle_rewards(
+    storage: &mut dyn Storage,
+    staker: &Addr,
+    stakings: &mut [StakeInfo],
+    until_epoch: u64,
+) -> StdResult<Uint128> {
+    for denom in load_fee_denoms(storage)?.iter() {
+        let fee_amount = compute_fee_amount(storage, denom, stakings, until_epoch)?;
+        if fee_amount.is_zero() {
+            continue;
+        }
+        ACCRUED_FEES.update(storage, (staker, denom), |accrued| -> StdResult<_> {
+            Ok(accrued.unwrap_or_default() + fee_amount)
+        })?;
     }
-    let total = EPOCH_STAKING_AMOUNT
-        .range(
+
+    let mut reward = Uint128::zero();
+    for stake in stakings.iter_mut() {
+        if stake.start_epoch >= until_epoch {
+            continue;
+        }
+        reward += compute_mint_amount(
             storage,
-            Some(Bound::inclusive(start)),
-            None,
-            Order::Ascending,
-        )
-        .into_iter()
-        .map(|epoch_staking| {
-            let (_, epoch_total_amount) = epoch_staking?;
-            let ratio = Decimal::from_ratio(staking_amount, epoch_total_amount);
-            let mint_amount = Uint128::new(ONE_DAY_PER_MINT) * ratio;
-            Ok(mint_amount)
-        })
-        .sum::<StdResult<Uint128>>()?;
+            stake.staking_amount,
+            stake.start_epoch,
+            until_epoch,
+        )?;
+        stake.start_epoch = until_epoch;
+    }
+    Ok(reward)
+}
 
-    Ok(total)
+pub fn check_vault_contract(vault: &Addr, sender: &Addr) -> Result<(), ContractError> {
+    match *vault == *sender {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
 }
 
 pub fn check_core_contract(core: &Addr, sender: &Addr) -> Result<(), ContractError> {
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use axis_protocol::reward::RewardIndex;
+use cosmwasm_std::{coin, Addr, Coin, Order, StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
@@ -43,8 +44,19 @@ pub struct UnStakeInfo {
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const STATE: Item<State> = Item::new("state");
 
-//epoch,total_staking
-pub const EPOCH_STAKING_AMOUNT: Map<u64, Uint128> = Map::new("epoch_total_staking");
+//(reward key, epoch) -> cumulative reward per staked AXIS
+pub const REWARD_INDEX: RewardIndex = RewardIndex::new("reward_index");
+//reward index key of esAXIS , fee denoms are keyed by the denom
+pub const ES_AXIS_REWARD: &str = "es_axis";
+
+//every denom the vault has shared
+pub const FEE_DENOMS: Item<Vec<String>> = Item::new("fee_denoms");
+//denom -> fees received this epoch , shared at the next Setting
+pub const PENDING_FEES: Map<&str, Uint128> = Map::new("pending_fees");
+//(staker, denom) -> settled fees not claimed yet
+pub const ACCRUED_FEES: Map<(&Addr, &str), Uint128> = Map::new("accrued_fees");
+//staker -> settled esAXIS not claimed yet
+pub const ACCRUED_REWARD: Map<&Addr, Uint128> = Map::new("accrued_reward");
 
 pub const STAKING: Map<Addr, Vec<StakeInfo>> = Map::new("staking");
 pub const UN_STAKING: Map<Addr, Vec<UnStakeInfo>> = Map::new("unstaking");
@@ -54,6 +66,38 @@ pub fn load_stakings(storage: &dyn Storage, staker: Addr) -> StdResult<Vec<Stake
     Ok(stakings)
 }
 
+//@@ stakes starting at the same epoch are kept as one
+pub fn add_stake(
+    storage: &mut dyn Storage,
+    staker: Addr,
+    start_epoch: u64,
+    amount: Uint128,
+) -> StdResult<()> {
+    let mut stakings = STAKING
+        .may_load(storage, staker.clone())?
+        .unwrap_or_default();
+    match stakings.iter_mut().find(|s| s.start_epoch == start_epoch) {
+        Some(stake) => stake.staking_amount += amount,
+        None => stakings.push(StakeInfo {
+            start_epoch,
+            staking_amount: amount,
+        }),
+    }
+    STAKING.save(storage, staker, &stakings)
+}
+
+pub fn load_fee_denoms(storage: &dyn Storage) -> StdResult<Vec<String>> {
+    Ok(FEE_DENOMS.may_load(storage)?.unwrap_or_default())
+}
+
+pub fn load_accrued_fees(storage: &dyn Storage, staker: &Addr) -> StdResult<Vec<Coin>> {
+    ACCRUED_FEES
+        .prefix(staker)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect()
+}
+
 pub fn load_un_stakings(storage: &dyn Storage, staker: Addr) -> StdResult<Vec<UnStakeInfo>> {
     let stakings = UN_STAKING.load(storage, staker)?;
     Ok(stakings)
@@ -1,13 +1,16 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, SubMsgResult,
+};
 use cw2::set_contract_version;
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 // use cw2::set_contract_version;
 
 use crate::{
     error::ContractError,
-    state::{save_config, Config},
+    helpers::{check_fee_split, check_yield_denom},
+    state::{load_config, save_config, Config},
 };
 use axis_protocol::{
     query::query_epoch,
@@ -31,7 +34,14 @@ pub fn instantiate(
         es_axis_contract,
         es_axis_denom,
         denom_list,
+        staker_yield_rate,
+        yield_denom,
+        buyback_rate,
     } = msg;
+    let staker_yield_rate = staker_yield_rate.unwrap_or_default();
+    let buyback_rate = buyback_rate.unwrap_or_default();
+    check_fee_split(staker_yield_rate, buyback_rate)?;
+    check_yield_denom(&denom_list, &yield_denom)?;
     let core_contract = deps.api.addr_validate(&core_contract)?;
     let es_axis_contract = deps.api.addr_validate(&es_axis_contract)?;
     let epoch = query_epoch(deps.querier, &core_contract)?;
@@ -42,6 +52,9 @@ pub fn instantiate(
         es_axis_contract,
         es_axis_denom,
         denom_list,
+        staker_yield_rate,
+        yield_denom,
+        buyback_rate,
     };
     save_config(deps.storage, &config)?;
     Ok(Response::new())
@@ -50,7 +63,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut<SeiQueryWrapper>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<SeiMsg>, ContractError> {
@@ -68,28 +81,69 @@ pub fn execute(
             price_denom,
             price_amount,
         ),
-        ExecuteMsg::Swap {} => execute::swap(deps, info),
-        ExecuteMsg::Setting { epoch } => execute::setting(deps, info, epoch),
+        ExecuteMsg::RecievedInsuranceFee {
+            base_denom,
+            price_denom,
+        } => execute::recieved_insurance_fee(deps, info, base_denom, price_denom),
+        ExecuteMsg::CoverBadDebt {
+            base_denom,
+            base_amount,
+            price_denom,
+            price_amount,
+        } => execute::cover_bad_debt(
+            deps,
+            info,
+            base_denom,
+            base_amount,
+            price_denom,
+            price_amount,
+        ),
+        ExecuteMsg::Receive(receive_msg) => execute::receive(deps, info, receive_msg),
+        ExecuteMsg::Setting { epoch } => execute::setting(deps, env, info, epoch),
+        ExecuteMsg::UpdateYieldConfig {
+            staker_yield_rate,
+            yield_denom,
+        } => execute::update_yield_config(deps, info, staker_yield_rate, yield_denom),
+        ExecuteMsg::UpdateBuybackConfig { buyback_rate } => {
+            execute::update_buyback_config(deps, info, buyback_rate)
+        }
+        ExecuteMsg::AddDenom { denom } => execute::add_denom(deps, info, denom),
+        ExecuteMsg::RemoveDenom { denom } => execute::remove_denom(deps, info, denom),
+        ExecuteMsg::UpdateSwapAdapter { swap_adapter } => {
+            execute::update_swap_adapter(deps, info, swap_adapter)
+        }
     }
 }
 pub mod execute {
     use crate::{
         helpers::{
-            check_core_contract, check_denom_and_amount, check_funds_and_get_token, check_owner,
-            check_valid_denom,
+            check_core_contract, check_denom_and_amount, check_es_axis_contract, check_fee_split,
+            check_insurance_denom, check_market_contract, check_owner, check_owner_or_core,
+            check_swap_adapter, check_valid_denom, check_yield_denom,
+        },
+        query::{
+            query_ex_axis_total_supply, query_exchange_rate, query_oracle_return,
+            query_pair_market_contract, query_pair_pool_contract, query_staking_total,
         },
-        query::query_ex_axis_total_supply,
         state::{
-            load_balance, load_config, load_pending_balance, save_balance, save_config, BALANCE,
-            PENDING_BALANCE,
+            load_balance, load_config, load_insurance_balance, load_pending_balance, record_fee,
+            save_balance, save_config, save_insurance_balance, BuybackOffer, YieldOffer,
+            BUYBACK_QUEUE, PENDING_BALANCE, SWAP_ADAPTER, YIELD_QUEUE,
         },
         ContractError,
     };
-    use axis_protocol::es_axis::ExecuteMsg as ESAxisExecuteMsg;
+    use axis_protocol::es_axis::{EsAxisReceiveMsg, ExecuteMsg as ESAxisExecuteMsg};
+    use axis_protocol::pool::ExecuteMsg as PoolExecuteMsg;
+    use axis_protocol::query::{query_axis_contract, query_axis_denom, query_staking_contract};
+    use axis_protocol::router::ExecuteMsg as RouterExecuteMsg;
+    use axis_protocol::staking::ExecuteMsg as StakingExecuteMsg;
+    use axis_protocol::vault::{ReceiveMsg, SwapAdapter};
     use cosmwasm_std::{
-        coin, to_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, MessageInfo, Response,
-        StdResult, SubMsg, Uint128, WasmMsg,
+        coin, from_binary, to_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, Event,
+        MessageInfo, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
     };
+
+    use super::{BUYBACK_REPLY_ID, YIELD_REPLY_ID};
     use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
     pub fn recieved_fee(
@@ -102,6 +156,13 @@ pub mod execute {
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
         check_valid_denom(&config.denom_list, &base_denom, &price_denom)?;
+        let market_contract = query_pair_market_contract(
+            deps.querier,
+            &config.core_contract,
+            &base_denom,
+            &price_denom,
+        )?;
+        check_market_contract(&market_contract, &info.sender)?;
         check_denom_and_amount(
             info.funds,
             &base_denom,
@@ -127,65 +188,319 @@ pub mod execute {
                 }
             },
         )?;
+
+        record_fee(
+            deps.storage,
+            config.epoch,
+            &base_denom,
+            base_amount,
+            &price_denom,
+            price_amount,
+        )?;
         Ok(Response::new())
     }
-    pub fn swap(
+
+    pub fn recieved_insurance_fee(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
+        base_denom: String,
+        price_denom: String,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let config = load_config(deps.storage)?;
-        //@@ 그냥 토큰들 다 줄까 아니면 하나의 토큰으로 교환해서 줄까?
-        //es_axis 토큰 수량 체크
-        let es_axis_token = check_funds_and_get_token(info.funds, &config.es_axis_denom)?;
+        let market_contract = query_pair_market_contract(
+            deps.querier,
+            &config.core_contract,
+            &base_denom,
+            &price_denom,
+        )?;
+        check_market_contract(&market_contract, &info.sender)?;
+        let pair_denoms = [base_denom, price_denom];
+        for fund in info.funds.iter() {
+            check_insurance_denom(&pair_denoms, &fund.denom)?;
+            let balance = load_insurance_balance(deps.storage, &fund.denom)?;
+            save_insurance_balance(deps.storage, &fund.denom, &(balance + fund.amount))?;
+        }
+        Ok(Response::new().add_attribute("method", "recieved_insurance_fee"))
+    }
+
+    //@@ pays as much of the shortfall as the insurance fund holds, the rest stays with the lp
+    fn cover_amount(
+        storage: &mut dyn Storage,
+        denom: &String,
+        bad_debt: Uint128,
+    ) -> StdResult<Uint128> {
+        let balance = load_insurance_balance(storage, denom)?;
+        let covered = Uint128::min(balance, bad_debt);
+        save_insurance_balance(storage, denom, &(balance - covered))?;
+        Ok(covered)
+    }
+
+    pub fn cover_bad_debt(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        base_denom: String,
+        base_amount: Uint128,
+        price_denom: String,
+        price_amount: Uint128,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        let market_contract = query_pair_market_contract(
+            deps.querier,
+            &config.core_contract,
+            &base_denom,
+            &price_denom,
+        )?;
+        check_market_contract(&market_contract, &info.sender)?;
+        let pool_contract = query_pair_pool_contract(
+            deps.querier,
+            &config.core_contract,
+            &base_denom,
+            &price_denom,
+        )?;
+
+        let base_covered = cover_amount(deps.storage, &base_denom, base_amount)?;
+        let price_covered = cover_amount(deps.storage, &price_denom, price_amount)?;
+
+        let event = Event::new("insurance_payout")
+            .add_attribute("pool_contract", pool_contract.to_string())
+            .add_attribute("base_denom", base_denom.to_owned())
+            .add_attribute("base_bad_debt", base_amount)
+            .add_attribute("base_covered", base_covered)
+            .add_attribute("base_uncovered", base_amount - base_covered)
+            .add_attribute("price_denom", price_denom.to_owned())
+            .add_attribute("price_bad_debt", price_amount)
+            .add_attribute("price_covered", price_covered)
+            .add_attribute("price_uncovered", price_amount - price_covered);
+
+        let funds = vec![
+            coin(base_covered.into(), base_denom),
+            coin(price_covered.into(), price_denom),
+        ]
+        .into_iter()
+        .filter(|c| !c.amount.is_zero())
+        .collect::<Vec<Coin>>();
+
+        let response = Response::new().add_event(event);
+        match funds.is_empty() {
+            true => Ok(response),
+            false => Ok(response.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: pool_contract.to_string(),
+                msg: to_binary(&PoolExecuteMsg::CoverBadDebt {})?,
+                funds,
+            }))),
+        }
+    }
+
+    pub fn receive(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        receive_msg: EsAxisReceiveMsg,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        //@@ esAXIS only arrives through the es axis ledger
+        check_es_axis_contract(&info.sender, &config.es_axis_contract)?;
+        let sender = deps.api.addr_validate(&receive_msg.sender)?;
+        match from_binary(&receive_msg.msg)? {
+            ReceiveMsg::Swap {} => swap(deps, sender, receive_msg.amount),
+        }
+    }
+
+    pub fn swap(
+        deps: DepsMut<SeiQueryWrapper>,
+        sender: Addr,
+        es_axis_amount: Uint128,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        //@@ every denom is paid unless a swap adapter converts them into one
         let es_axis_total_supply =
             query_ex_axis_total_supply(deps.querier, &config.es_axis_contract)?;
-        let ratio = Decimal::from_ratio(es_axis_token.amount, es_axis_total_supply);
+        let ratio = Decimal::from_ratio(es_axis_amount, es_axis_total_supply);
 
         let token_vec = config
             .denom_list
             .iter()
             .map(|denom| {
-                let balance = BALANCE.load(deps.storage, denom)?;
+                let balance = load_balance(deps.storage, denom)?;
                 let sender_amount = balance * ratio;
+                //@@ the paid share leaves the balance or later swaps pay it again
+                save_balance(deps.storage, denom, &(balance - sender_amount))?;
                 Ok(coin(sender_amount.into(), denom))
             })
             .collect::<Result<Vec<Coin>, ContractError>>()?;
 
-        let send_msg = SubMsg::new(BankMsg::Send {
-            to_address: info.sender.to_string(),
-            amount: token_vec,
-        });
         let burn_msg = CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: config.es_axis_contract.to_string(),
-            msg: to_binary(&ESAxisExecuteMsg::Burn {})?,
-            funds: vec![es_axis_token],
+            msg: to_binary(&ESAxisExecuteMsg::Burn {
+                amount: es_axis_amount,
+            })?,
+            funds: vec![],
         });
-        Ok(Response::new()
-            .add_message(burn_msg)
-            .add_submessage(send_msg))
+        let response = Response::new().add_message(burn_msg);
+
+        let swap_adapter = match SWAP_ADAPTER.may_load(deps.storage)? {
+            Some(swap_adapter) => swap_adapter,
+            None => {
+                let send_msg = SubMsg::new(BankMsg::Send {
+                    to_address: sender.to_string(),
+                    amount: token_vec,
+                });
+                return Ok(response.add_submessage(send_msg));
+            }
+        };
+
+        let (payout_coins, swap_coins): (Vec<Coin>, Vec<Coin>) = token_vec
+            .into_iter()
+            .filter(|c| !c.amount.is_zero())
+            .partition(|c| c.denom == swap_adapter.payout_denom);
+
+        //@@ the router pays the holder directly , min return keeps it within the oracle price
+        let swap_msgs = swap_coins
+            .into_iter()
+            .map(|offer| {
+                let oracle_return = query_oracle_return(
+                    deps.querier,
+                    &offer.denom,
+                    offer.amount,
+                    &swap_adapter.payout_denom,
+                )?;
+                let min_return =
+                    oracle_return.multiply_ratio(100 - swap_adapter.max_slippage as u128, 100u128);
+                Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: swap_adapter.router_contract.to_owned(),
+                    msg: to_binary(&RouterExecuteMsg::Swap {
+                        ask_denom: swap_adapter.payout_denom.to_owned(),
+                        min_return,
+                        recipient: sender.to_string(),
+                    })?,
+                    funds: vec![offer],
+                }))
+            })
+            .collect::<Result<Vec<CosmosMsg<SeiMsg>>, ContractError>>()?;
+
+        let response = response.add_messages(swap_msgs);
+        match payout_coins.is_empty() {
+            true => Ok(response),
+            false => Ok(response.add_message(BankMsg::Send {
+                to_address: sender.to_string(),
+                amount: payout_coins,
+            })),
+        }
+    }
+
+    pub fn update_swap_adapter(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        swap_adapter: Option<SwapAdapter>,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let config = load_config(deps.storage)?;
+        check_owner(&info.sender, &config.owner)?;
+        match swap_adapter {
+            Some(swap_adapter) => {
+                check_swap_adapter(&config.denom_list, &swap_adapter)?;
+                let router_contract = deps.api.addr_validate(&swap_adapter.router_contract)?;
+                SWAP_ADAPTER.save(
+                    deps.storage,
+                    &SwapAdapter {
+                        router_contract: router_contract.to_string(),
+                        ..swap_adapter
+                    },
+                )?;
+            }
+            None => SWAP_ADAPTER.remove(deps.storage),
+        }
+        Ok(Response::new().add_attribute("method", "update_swap_adapter"))
     }
 
-    pub fn update_denom_list(
+    pub fn add_denom(
         deps: DepsMut<SeiQueryWrapper>,
         info: MessageInfo,
         new_denom: String,
     ) -> Result<Response<SeiMsg>, ContractError> {
         let mut config = load_config(deps.storage)?;
-        //pool_contract 에서 실행시킬수 있게 할까?
-        //이건 reply 가 되는지 확인해봐야 알 수 있다.
-        //일단 owner 만 실행할 수 있게 하자.
+        //@@ core adds the denoms of every new pair from its CreatePair reply
+        check_owner_or_core(&info.sender, &config.owner, &config.core_contract)?;
+        match (
+            config.denom_list.contains(&new_denom),
+            info.sender == config.core_contract,
+        ) {
+            //@@ the price denom is shared by many pairs
+            (true, true) => return Ok(Response::new()),
+            (true, false) => Err(ContractError::InvalidDenom {}),
+            (false, _) => Ok(()),
+        }?;
+        config.denom_list.push(new_denom.to_owned());
+        save_config(deps.storage, &config)?;
+        Ok(Response::new()
+            .add_attribute("method", "add_denom")
+            .add_attribute("denom", new_denom))
+    }
+
+    pub fn remove_denom(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        denom: String,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
         check_owner(&info.sender, &config.owner)?;
-        match config.denom_list.contains(&new_denom) {
-            true => Err(ContractError::InvalidDenom {}),
-            false => Ok(()),
+        match config.denom_list.contains(&denom) {
+            true => Ok(()),
+            false => Err(ContractError::InvalidDenom {}),
         }?;
-        config.denom_list.push(new_denom);
+        //@@ swaps and settings only walk the denom list , a balance left here would be stuck
+        let payout_denom = SWAP_ADAPTER
+            .may_load(deps.storage)?
+            .map(|swap_adapter| swap_adapter.payout_denom);
+        match load_balance(deps.storage, &denom)?.is_zero()
+            && load_pending_balance(deps.storage, &denom)?.is_zero()
+            && config.yield_denom.as_ref() != Some(&denom)
+            && payout_denom.as_ref() != Some(&denom)
+        {
+            true => Ok(()),
+            false => Err(ContractError::DenomInUse {}),
+        }?;
+        config.denom_list.retain(|d| *d != denom);
         save_config(deps.storage, &config)?;
-        Ok(Response::new())
+        Ok(Response::new()
+            .add_attribute("method", "remove_denom")
+            .add_attribute("denom", denom))
+    }
+
+    pub fn update_yield_config(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        staker_yield_rate: u8,
+        yield_denom: Option<String>,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+        check_owner(&info.sender, &config.owner)?;
+        check_fee_split(staker_yield_rate, config.buyback_rate)?;
+        check_yield_denom(&config.denom_list, &yield_denom)?;
+        config.staker_yield_rate = staker_yield_rate;
+        config.yield_denom = yield_denom;
+        save_config(deps.storage, &config)?;
+        Ok(Response::new()
+            .add_attribute("method", "update_yield_config")
+            .add_attribute("staker_yield_rate", staker_yield_rate.to_string()))
+    }
+
+    pub fn update_buyback_config(
+        deps: DepsMut<SeiQueryWrapper>,
+        info: MessageInfo,
+        buyback_rate: u8,
+    ) -> Result<Response<SeiMsg>, ContractError> {
+        let mut config = load_config(deps.storage)?;
+        check_owner(&info.sender, &config.owner)?;
+        check_fee_split(config.staker_yield_rate, buyback_rate)?;
+        config.buyback_rate = buyback_rate;
+        save_config(deps.storage, &config)?;
+        Ok(Response::new()
+            .add_attribute("method", "update_buyback_config")
+            .add_attribute("buyback_rate", buyback_rate.to_string()))
     }
 
     pub fn setting(
         deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
         info: MessageInfo,
         epoch: u64,
     ) -> Result<Response<SeiMsg>, ContractError> {
@@ -193,19 +508,275 @@ pub mod execute {
         // pending -> balance 로 이동
 
         let mut config = load_config(deps.storage)?;
+        let ended_epoch = config.epoch;
         config.epoch = epoch;
         check_core_contract(&config.core_contract, &info.sender)?;
 
+        //@@ buyback needs the router and an oracle price of AXIS for the min return
+        let swap_adapter = SWAP_ADAPTER.may_load(deps.storage)?;
+        let axis_denom = match (config.buyback_rate, &swap_adapter) {
+            (0, _) | (_, None) => None,
+            _ => {
+                let axis_contract = query_axis_contract(deps.querier, &config.core_contract)?;
+                let axis_denom = query_axis_denom(deps.querier, &axis_contract)?;
+                query_exchange_rate(deps.querier, &axis_denom)
+                    .ok()
+                    .map(|_| axis_denom)
+            }
+        };
+        let buyback_rate = match axis_denom {
+            Some(_) => config.buyback_rate,
+            None => 0,
+        };
+
+        //@@ stakers get their share only while something is staked
+        let staking_contract = query_staking_contract(deps.querier, &config.core_contract)?;
+        let staker_yield_rate = match config.staker_yield_rate {
+            0 => 0,
+            rate => match query_staking_total(deps.querier, &staking_contract)?.is_zero() {
+                true => 0,
+                false => rate,
+            },
+        };
+
+        let axis_balance = match &axis_denom {
+            Some(axis_denom) => {
+                deps.querier
+                    .query_balance(env.contract.address.to_owned(), axis_denom)?
+                    .amount
+            }
+            None => Uint128::zero(),
+        };
+        let mut yield_funds = vec![];
+        let mut buyback_offers = vec![];
         for denom in config.denom_list.iter() {
             let pending_balance = load_pending_balance(deps.storage, denom)?;
+            let staker_amount = pending_balance.multiply_ratio(staker_yield_rate, 100u128);
+            let buyback_amount = pending_balance.multiply_ratio(buyback_rate, 100u128);
             let mut balance = load_balance(deps.storage, denom)?;
-            balance += pending_balance;
+            balance += pending_balance - staker_amount - buyback_amount;
             save_balance(deps.storage, denom, &balance)?;
+            yield_funds.push(coin(staker_amount.into(), denom));
+            if !buyback_amount.is_zero() {
+                buyback_offers.push(BuybackOffer {
+                    epoch: ended_epoch,
+                    offer: coin(buyback_amount.into(), denom),
+                    axis_balance,
+                });
+            }
         }
 
         PENDING_BALANCE.clear(deps.storage);
+        //@@ fees received from here are recorded under the new epoch
+        save_config(deps.storage, &config)?;
 
-        Ok(Response::new())
+        //@@ the router sends the yield denom back here , the reply passes it to the stakers.
+        //@@ without a router the stakers are paid in the fee denoms
+        let (yield_offers, yield_funds): (Vec<Coin>, Vec<Coin>) = yield_funds
+            .into_iter()
+            .filter(|c| !c.amount.is_zero())
+            .partition(|c| match (&swap_adapter, &config.yield_denom) {
+                (Some(_), Some(yield_denom)) => c.denom != *yield_denom,
+                _ => false,
+            });
+        let yield_offers = match (&swap_adapter, &config.yield_denom) {
+            (Some(_), Some(yield_denom)) if !yield_offers.is_empty() => {
+                let yield_balance = deps
+                    .querier
+                    .query_balance(env.contract.address.to_owned(), yield_denom)?;
+                yield_offers
+                    .into_iter()
+                    .map(|offer| YieldOffer {
+                        offer,
+                        yield_balance: yield_balance.to_owned(),
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        };
+        let yield_msgs = match &swap_adapter {
+            Some(swap_adapter) => yield_offers
+                .iter()
+                .map(
+                    |YieldOffer {
+                         offer,
+                         yield_balance,
+                     }| {
+                        let oracle_return = query_oracle_return(
+                            deps.querier,
+                            &offer.denom,
+                            offer.amount,
+                            &yield_balance.denom,
+                        )?;
+                        let min_return = oracle_return
+                            .multiply_ratio(100 - swap_adapter.max_slippage as u128, 100u128);
+                        let swap_msg = WasmMsg::Execute {
+                            contract_addr: swap_adapter.router_contract.to_owned(),
+                            msg: to_binary(&RouterExecuteMsg::Swap {
+                                ask_denom: yield_balance.denom.to_owned(),
+                                min_return,
+                                recipient: env.contract.address.to_string(),
+                            })?,
+                            funds: vec![offer.to_owned()],
+                        };
+                        Ok(SubMsg::reply_always(swap_msg, YIELD_REPLY_ID))
+                    },
+                )
+                .collect::<Result<Vec<SubMsg<SeiMsg>>, ContractError>>()?,
+            None => vec![],
+        };
+        YIELD_QUEUE.save(deps.storage, &yield_offers)?;
+
+        //@@ the router sends AXIS back here , the reply burns it
+        let buyback_msgs = match (swap_adapter, axis_denom) {
+            (Some(swap_adapter), Some(axis_denom)) => buyback_offers
+                .iter()
+                .map(|BuybackOffer { offer, .. }| {
+                    let oracle_return =
+                        query_oracle_return(deps.querier, &offer.denom, offer.amount, &axis_denom)?;
+                    let min_return = oracle_return
+                        .multiply_ratio(100 - swap_adapter.max_slippage as u128, 100u128);
+                    let swap_msg = WasmMsg::Execute {
+                        contract_addr: swap_adapter.router_contract.to_owned(),
+                        msg: to_binary(&RouterExecuteMsg::Swap {
+                            ask_denom: axis_denom.to_owned(),
+                            min_return,
+                            recipient: env.contract.address.to_string(),
+                        })?,
+                        funds: vec![offer.to_owned()],
+                    };
+                    Ok(SubMsg::reply_always(swap_msg, BUYBACK_REPLY_ID))
+                })
+                .collect::<Result<Vec<SubMsg<SeiMsg>>, ContractError>>()?,
+            _ => vec![],
+        };
+        BUYBACK_QUEUE.save(deps.storage, &buyback_offers)?;
+
+        //@@ yield swaps run first , nothing else moves the yield denom until their replies
+        let response = Response::new()
+            .add_submessages(yield_msgs)
+            .add_submessages(buyback_msgs);
+        match yield_funds.is_empty() {
+            true => Ok(response),
+            false => Ok(response.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: staking_contract.to_string(),
+                msg: to_binary(&StakingExecuteMsg::ReceiveFees {})?,
+                funds: yield_funds,
+            }))),
+        }
+    }
+}
+
+pub const BUYBACK_REPLY_ID: u64 = 1;
+pub const YIELD_REPLY_ID: u64 = 2;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    deps: DepsMut<SeiQueryWrapper>,
+    env: Env,
+    msg: Reply,
+) -> Result<Response<SeiMsg>, ContractError> {
+    use crate::state::{load_balance, record_buyback, save_balance, BUYBACK_QUEUE, YIELD_QUEUE};
+    use axis_protocol::axis::ExecuteMsg as AxisExecuteMsg;
+    use axis_protocol::query::{query_axis_contract, query_axis_denom, query_staking_contract};
+    use axis_protocol::staking::ExecuteMsg as StakingExecuteMsg;
+    use cosmwasm_std::{coin, WasmMsg};
+
+    match msg.id {
+        BUYBACK_REPLY_ID => {
+            //@@ replies come in the order the swaps were sent
+            let mut buyback_offers = BUYBACK_QUEUE.load(deps.storage)?;
+            if buyback_offers.is_empty() {
+                return Err(ContractError::InvalidReplyId {});
+            }
+            let buyback_offer = buyback_offers.remove(0);
+            BUYBACK_QUEUE.save(deps.storage, &buyback_offers)?;
+            let offer = buyback_offer.offer;
+
+            match msg.result {
+                //@@ a failed swap keeps the fee in the vault , the epoch setting goes on
+                SubMsgResult::Err(err) => {
+                    let balance = load_balance(deps.storage, &offer.denom)?;
+                    save_balance(deps.storage, &offer.denom, &(balance + offer.amount))?;
+                    Ok(Response::new()
+                        .add_attribute("method", "buyback_failed")
+                        .add_attribute("denom", offer.denom)
+                        .add_attribute("error", err))
+                }
+                SubMsgResult::Ok(_) => {
+                    let config = load_config(deps.storage)?;
+                    let axis_contract = query_axis_contract(deps.querier, &config.core_contract)?;
+                    let axis_denom = query_axis_denom(deps.querier, &axis_contract)?;
+                    let balance = deps
+                        .querier
+                        .query_balance(env.contract.address, &axis_denom)?
+                        .amount;
+                    //@@ every burn lands before the next swap , so the balance is back to axis_balance
+                    let axis = coin(
+                        balance.saturating_sub(buyback_offer.axis_balance).into(),
+                        axis_denom,
+                    );
+                    record_buyback(deps.storage, buyback_offer.epoch, offer, axis.amount)?;
+
+                    let response = Response::new()
+                        .add_attribute("method", "buyback")
+                        .add_attribute("burned_amount", axis.amount);
+                    match axis.amount.is_zero() {
+                        true => Ok(response),
+                        false => Ok(response.add_message(WasmMsg::Execute {
+                            contract_addr: axis_contract.to_string(),
+                            msg: to_binary(&AxisExecuteMsg::Burn {})?,
+                            funds: vec![axis],
+                        })),
+                    }
+                }
+            }
+        }
+        YIELD_REPLY_ID => {
+            let mut yield_offers = YIELD_QUEUE.load(deps.storage)?;
+            if yield_offers.is_empty() {
+                return Err(ContractError::InvalidReplyId {});
+            }
+            let yield_offer = yield_offers.remove(0);
+            YIELD_QUEUE.save(deps.storage, &yield_offers)?;
+
+            let (fund, response) = match msg.result {
+                //@@ a failed swap pays the stakers in the fee denom
+                SubMsgResult::Err(err) => (
+                    yield_offer.offer,
+                    Response::new()
+                        .add_attribute("method", "yield_swap_failed")
+                        .add_attribute("error", err),
+                ),
+                SubMsgResult::Ok(_) => {
+                    let yield_balance = yield_offer.yield_balance;
+                    let balance = deps
+                        .querier
+                        .query_balance(env.contract.address, &yield_balance.denom)?
+                        .amount;
+                    //@@ every forward lands before the next swap , so the balance is back to yield_balance
+                    let fund = coin(
+                        balance.saturating_sub(yield_balance.amount).into(),
+                        yield_balance.denom,
+                    );
+                    (fund, Response::new().add_attribute("method", "yield_swap"))
+                }
+            };
+            match fund.amount.is_zero() {
+                true => Ok(response),
+                false => {
+                    let config = load_config(deps.storage)?;
+                    let staking_contract =
+                        query_staking_contract(deps.querier, &config.core_contract)?;
+                    Ok(response.add_message(WasmMsg::Execute {
+                        contract_addr: staking_contract.to_string(),
+                        msg: to_binary(&StakingExecuteMsg::ReceiveFees {})?,
+                        funds: vec![fund],
+                    }))
+                }
+            }
+        }
+        _ => Err(ContractError::InvalidReplyId {}),
     }
 }
 
@@ -219,15 +790,49 @@ pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult
         QueryMsg::GetAddressBalance { address } => {
             to_binary(&query::get_address_balance(deps, address)?)
         }
+        QueryMsg::GetInsuranceBalance { denom } => {
+            to_binary(&query::get_insurance_balance(deps, denom)?)
+        }
+        QueryMsg::GetYieldConfig {} => to_binary(&query::get_yield_config(deps)?),
+        QueryMsg::GetSwapAdapter {} => to_binary(&query::get_swap_adapter(deps)?),
+        QueryMsg::GetDenomList {} => to_binary(&load_config(deps.storage)?.denom_list),
+        QueryMsg::GetEpochFees {
+            start_epoch,
+            end_epoch,
+        } => to_binary(&query::get_epoch_fees(deps, start_epoch, end_epoch)?),
+        QueryMsg::GetPairFees {
+            base_denom,
+            price_denom,
+            start_epoch,
+            end_epoch,
+        } => to_binary(&query::get_pair_fees(
+            deps,
+            base_denom,
+            price_denom,
+            start_epoch,
+            end_epoch,
+        )?),
+        QueryMsg::GetBuybackHistory {
+            start_epoch,
+            end_epoch,
+        } => to_binary(&query::get_buyback_history(deps, start_epoch, end_epoch)?),
     }
 }
 
 pub mod query {
-    use crate::state::{load_balance, load_config, load_pending_balance, BALANCE};
+    use crate::query::query_es_axis_balance;
+    use crate::state::{
+        load_balance, load_config, load_insurance_balance, load_pending_balance, BUYBACK,
+        EPOCH_FEES, PAIR_FEES, SWAP_ADAPTER, TOTAL_BURNED,
+    };
+    use axis_protocol::es_axis::QueryMsg as ESAxisQueryMsg;
     use axis_protocol::vault::{
-        AddressBalanceResponse, DenomBalanceResponse, DenomPendingBalanceResponse,
+        AddressBalanceResponse, BuybackHistoryResponse, BuybackResponse, DenomBalanceResponse,
+        DenomPendingBalanceResponse, EpochFeesResponse, InsuranceBalanceResponse,
+        PairEpochFeeResponse, PairFeesResponse, SwapAdapter, YieldConfigResponse,
     };
-    use cosmwasm_std::{Decimal, Deps, StdResult};
+    use cosmwasm_std::{coin, Decimal, Deps, Order, StdResult, Uint128};
+    use cw_storage_plus::{Bound, PrefixBound};
     use sei_cosmwasm::SeiQueryWrapper;
 
     pub fn get_denom_balance(
@@ -246,22 +851,130 @@ pub mod query {
         Ok(DenomPendingBalanceResponse { denom, amount })
     }
 
+    pub fn get_insurance_balance(
+        deps: Deps<SeiQueryWrapper>,
+        denom: String,
+    ) -> StdResult<InsuranceBalanceResponse> {
+        let amount = load_insurance_balance(deps.storage, &denom)?;
+        Ok(InsuranceBalanceResponse { denom, amount })
+    }
+
+    pub fn get_yield_config(deps: Deps<SeiQueryWrapper>) -> StdResult<YieldConfigResponse> {
+        let config = load_config(deps.storage)?;
+        Ok(YieldConfigResponse {
+            staker_yield_rate: config.staker_yield_rate,
+            yield_denom: config.yield_denom,
+            buyback_rate: config.buyback_rate,
+        })
+    }
+
+    pub fn get_epoch_fees(
+        deps: Deps<SeiQueryWrapper>,
+        start_epoch: u64,
+        end_epoch: u64,
+    ) -> StdResult<Vec<EpochFeesResponse>> {
+        let mut epoch_fees: Vec<EpochFeesResponse> = vec![];
+        for item in EPOCH_FEES.prefix_range(
+            deps.storage,
+            Some(PrefixBound::inclusive(start_epoch)),
+            Some(PrefixBound::inclusive(end_epoch)),
+            Order::Ascending,
+        ) {
+            let ((epoch, denom), amount) = item?;
+            match epoch_fees.last_mut() {
+                Some(epoch_fee) if epoch_fee.epoch == epoch => {
+                    epoch_fee.fees.push(coin(amount.u128(), denom))
+                }
+                _ => epoch_fees.push(EpochFeesResponse {
+                    epoch,
+                    fees: vec![coin(amount.u128(), denom)],
+                }),
+            }
+        }
+        Ok(epoch_fees)
+    }
+
+    pub fn get_pair_fees(
+        deps: Deps<SeiQueryWrapper>,
+        base_denom: String,
+        price_denom: String,
+        start_epoch: u64,
+        end_epoch: u64,
+    ) -> StdResult<PairFeesResponse> {
+        let epoch_fees = PAIR_FEES
+            .prefix((&base_denom, &price_denom))
+            .range(
+                deps.storage,
+                Some(Bound::inclusive(start_epoch)),
+                Some(Bound::inclusive(end_epoch)),
+                Order::Ascending,
+            )
+            .map(|item| {
+                let (epoch, pair_fee) = item?;
+                Ok(PairEpochFeeResponse {
+                    epoch,
+                    base_amount: pair_fee.base_amount,
+                    price_amount: pair_fee.price_amount,
+                })
+            })
+            .collect::<StdResult<Vec<PairEpochFeeResponse>>>()?;
+        Ok(PairFeesResponse {
+            base_denom,
+            price_denom,
+            base_amount: epoch_fees.iter().map(|fee| fee.base_amount).sum(),
+            price_amount: epoch_fees.iter().map(|fee| fee.price_amount).sum(),
+            epoch_fees,
+        })
+    }
+
+    pub fn get_buyback_history(
+        deps: Deps<SeiQueryWrapper>,
+        start_epoch: u64,
+        end_epoch: u64,
+    ) -> StdResult<BuybackHistoryResponse> {
+        let buybacks = BUYBACK
+            .range(
+                deps.storage,
+                Some(Bound::inclusive(start_epoch)),
+                Some(Bound::inclusive(end_epoch)),
+                Order::Ascending,
+            )
+            .map(|item| {
+                let (epoch, buyback) = item?;
+                Ok(BuybackResponse {
+                    epoch,
+                    spent: buyback.spent,
+                    burned_amount: buyback.burned_amount,
+                })
+            })
+            .collect::<StdResult<Vec<BuybackResponse>>>()?;
+        Ok(BuybackHistoryResponse {
+            total_burned: TOTAL_BURNED.may_load(deps.storage)?.unwrap_or_default(),
+            buybacks,
+        })
+    }
+
+    pub fn get_swap_adapter(deps: Deps<SeiQueryWrapper>) -> StdResult<Option<SwapAdapter>> {
+        SWAP_ADAPTER.may_load(deps.storage)
+    }
+
     pub fn get_address_balance(
         deps: Deps<SeiQueryWrapper>,
         address: String,
     ) -> StdResult<AddressBalanceResponse> {
         let config = load_config(deps.storage)?;
-        let addr_es_axis_amount = deps
-            .querier
-            .query_balance(address, &config.es_axis_denom)?
-            .amount;
-        let es_axis_total_supply = deps.querier.query_supply(&config.es_axis_denom)?.amount;
+        let addr_es_axis_amount =
+            query_es_axis_balance(deps.querier, &config.es_axis_contract, address)?;
+        let es_axis_total_supply: Uint128 = deps.querier.query_wasm_smart(
+            config.es_axis_contract.to_string(),
+            &ESAxisQueryMsg::GetTotalSupply {},
+        )?;
         let ratio = Decimal::from_ratio(addr_es_axis_amount, es_axis_total_supply);
         let balance_vec = config
             .denom_list
             .into_iter()
             .map(|denom| {
-                let balance = BALANCE.load(deps.storage, &denom)?;
+                let balance = load_balance(deps.storage, &denom)?;
                 let amount = balance * ratio;
                 Ok(DenomBalanceResponse { denom, amount })
             })
@@ -12,4 +12,19 @@ pub enum ContractError {
     // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
     #[error("Invalid Denom")]
     InvalidDenom {},
+
+    #[error("Invalid Fee Split")]
+    InvalidFeeSplit {},
+
+    #[error("Invalid Reply ID")]
+    InvalidReplyId {},
+
+    #[error("Denom In Use")]
+    DenomInUse {},
+
+    #[error("Invalid Slippage")]
+    InvalidSlippage {},
+
+    #[error("Not Found ExchangeRate")]
+    NotFoundExchangeRate {},
 }
@@ -1,3 +1,4 @@
+use axis_protocol::vault::SwapAdapter;
 use cosmwasm_std::{Addr, Coin, Uint128};
 
 use crate::ContractError;
@@ -27,6 +28,13 @@ pub fn check_funds_and_get_token(funds: Vec<Coin>, denom: &String) -> Result<Coi
     Ok(valid_token)
 }
 
+pub fn check_es_axis_contract(sender: &Addr, es_axis_contract: &Addr) -> Result<(), ContractError> {
+    match sender == es_axis_contract {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
+}
+
 pub fn check_valid_denom(
     denom_list: &Vec<String>,
     base_denom: &String,
@@ -38,6 +46,52 @@ pub fn check_valid_denom(
     }
 }
 
+pub fn check_insurance_denom(denom_list: &[String], denom: &str) -> Result<(), ContractError> {
+    match denom_list.iter().any(|d| d == denom) {
+        true => Ok(()),
+        false => Err(ContractError::InvalidDenom {}),
+    }
+}
+
+//@@ staker yield and buyback come out of the same fees , the rest stays for esAXIS
+pub fn check_fee_split(staker_yield_rate: u8, buyback_rate: u8) -> Result<(), ContractError> {
+    match staker_yield_rate as u16 + buyback_rate as u16 <= 100 {
+        true => Ok(()),
+        false => Err(ContractError::InvalidFeeSplit {}),
+    }
+}
+
+pub fn check_yield_denom(
+    denom_list: &[String],
+    yield_denom: &Option<String>,
+) -> Result<(), ContractError> {
+    match yield_denom {
+        Some(denom) if !denom_list.contains(denom) => Err(ContractError::InvalidDenom {}),
+        _ => Ok(()),
+    }
+}
+
+pub fn check_swap_adapter(
+    denom_list: &[String],
+    swap_adapter: &SwapAdapter,
+) -> Result<(), ContractError> {
+    match swap_adapter.max_slippage < 100 {
+        true => Ok(()),
+        false => Err(ContractError::InvalidSlippage {}),
+    }?;
+    match denom_list.contains(&swap_adapter.payout_denom) {
+        true => Ok(()),
+        false => Err(ContractError::InvalidDenom {}),
+    }
+}
+
+pub fn check_market_contract(market: &Addr, sender: &Addr) -> Result<(), ContractError> {
+    match *market == *sender {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
+}
+
 pub fn check_core_contract(core: &Addr, sender: &Addr) -> Result<(), ContractError> {
     match *core == *sender {
         true => Ok(()),
@@ -45,6 +99,13 @@ pub fn check_core_contract(core: &Addr, sender: &Addr) -> Result<(), ContractErr
     }
 }
 
+pub fn check_owner_or_core(sender: &Addr, owner: &Addr, core: &Addr) -> Result<(), ContractError> {
+    match *sender == *owner || *sender == *core {
+        true => Ok(()),
+        false => Err(ContractError::Unauthorized {}),
+    }
+}
+
 pub fn check_owner(sender: &Addr, owner: &Addr) -> Result<(), ContractError> {
     match *sender == *owner {
         true => Ok(()),
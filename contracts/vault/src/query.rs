@@ -1,7 +1,11 @@
 use crate::ContractError;
+use axis_protocol::core::{
+    PairMarketContractResponse, PairPoolContractResponse, QueryMsg as CoreQueryMsg,
+};
 use axis_protocol::es_axis::QueryMsg as ESAxisQueryMsg;
-use cosmwasm_std::{Addr, QuerierWrapper, Uint128};
-use sei_cosmwasm::SeiQueryWrapper;
+use axis_protocol::staking::{QueryMsg as StakingQueryMsg, StateResponse as StakingStateResponse};
+use cosmwasm_std::{Addr, Decimal, QuerierWrapper, StdResult, Uint128};
+use sei_cosmwasm::{SeiQuerier, SeiQueryWrapper};
 
 // @@query supply 되면 삭제
 pub fn query_ex_axis_total_supply(
@@ -15,4 +19,82 @@ pub fn query_ex_axis_total_supply(
     Ok(total_supply)
 }
 
+pub fn query_es_axis_balance(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    es_axis_contract: &Addr,
+    address: String,
+) -> StdResult<Uint128> {
+    querier.query_wasm_smart(
+        es_axis_contract.to_string(),
+        &ESAxisQueryMsg::GetBalance { address },
+    )
+}
+
+pub fn query_pair_market_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    base_denom: &String,
+    price_denom: &String,
+) -> Result<Addr, ContractError> {
+    let core_res: PairMarketContractResponse = querier.query_wasm_smart(
+        core_contract.to_string(),
+        &CoreQueryMsg::GetPairMarketContract {
+            base_denom: base_denom.to_string(),
+            price_denom: price_denom.to_string(),
+        },
+    )?;
+    Ok(core_res.market_contract)
+}
+
+pub fn query_pair_pool_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+    base_denom: &String,
+    price_denom: &String,
+) -> Result<Addr, ContractError> {
+    let core_res: PairPoolContractResponse = querier.query_wasm_smart(
+        core_contract.to_string(),
+        &CoreQueryMsg::GetPairPoolContract {
+            base_denom: base_denom.to_string(),
+            price_denom: price_denom.to_string(),
+        },
+    )?;
+    Ok(core_res.pool_contract)
+}
+
+pub fn query_staking_total(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    staking_contract: &Addr,
+) -> StdResult<Uint128> {
+    let state: StakingStateResponse =
+        querier.query_wasm_smart(staking_contract.to_string(), &StakingQueryMsg::GetState {})?;
+    Ok(state.staking_total)
+}
+
+pub fn query_exchange_rate(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    denom: &String,
+) -> Result<Decimal, ContractError> {
+    let querier = SeiQuerier::new(&querier);
+    let exchange_rate_res = querier.query_exchange_rates()?;
+    exchange_rate_res
+        .denom_oracle_exchange_rate_pairs
+        .iter()
+        .find(|d| d.denom == *denom)
+        .map(|d| d.oracle_exchange_rate.exchange_rate)
+        .ok_or(ContractError::NotFoundExchangeRate {})
+}
+
+//@@ ask_denom amount worth the offer at oracle prices
+pub fn query_oracle_return(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    offer_denom: &String,
+    offer_amount: Uint128,
+    ask_denom: &String,
+) -> Result<Uint128, ContractError> {
+    let offer_price = query_exchange_rate(querier, offer_denom)?;
+    let ask_price = query_exchange_rate(querier, ask_denom)?;
+    Ok(offer_amount.multiply_ratio(offer_price.atomics(), ask_price.atomics()))
+}
+
 // pub fn query_total_supply(querier: QuerierWrapper<SeiQueryWrapper>) -> StdResult<Uint128> {}
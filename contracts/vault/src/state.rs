@@ -1,5 +1,6 @@
+use axis_protocol::vault::SwapAdapter;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cosmwasm_std::{Addr, Coin, StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
@@ -10,8 +11,107 @@ pub struct Config {
     pub es_axis_contract: Addr,
     pub es_axis_denom: String,
     pub denom_list: Vec<String>,
+    //percent of the epoch fees shared with AXIS stakers
+    pub staker_yield_rate: u8,
+    pub yield_denom: Option<String>,
+    //percent of the epoch fees sold for AXIS and burned
+    pub buyback_rate: u8,
 }
 
+#[cw_serde]
+#[derive(Default)]
+pub struct PairFee {
+    pub base_amount: Uint128,
+    pub price_amount: Uint128,
+}
+
+//(base denom, price denom, epoch) -> fees the pair market sent in the epoch
+pub const PAIR_FEES: Map<(&str, &str, u64), PairFee> = Map::new("pair_fees");
+//(epoch, denom) -> fees received in the epoch
+pub const EPOCH_FEES: Map<(u64, &str), Uint128> = Map::new("epoch_fees");
+
+pub fn record_fee(
+    storage: &mut dyn Storage,
+    epoch: u64,
+    base_denom: &str,
+    base_amount: Uint128,
+    price_denom: &str,
+    price_amount: Uint128,
+) -> StdResult<()> {
+    PAIR_FEES.update(
+        storage,
+        (base_denom, price_denom, epoch),
+        |pair_fee| -> StdResult<_> {
+            let pair_fee = pair_fee.unwrap_or_default();
+            Ok(PairFee {
+                base_amount: pair_fee.base_amount + base_amount,
+                price_amount: pair_fee.price_amount + price_amount,
+            })
+        },
+    )?;
+    for (denom, amount) in [(base_denom, base_amount), (price_denom, price_amount)] {
+        EPOCH_FEES.update(storage, (epoch, denom), |fee| -> StdResult<_> {
+            Ok(fee.unwrap_or_default() + amount)
+        })?;
+    }
+    Ok(())
+}
+
+#[cw_serde]
+pub struct BuybackOffer {
+    //epoch the fees were received in
+    pub epoch: u64,
+    pub offer: Coin,
+    //AXIS the vault held before the swap , only the swapped amount is burned
+    pub axis_balance: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct Buyback {
+    pub spent: Vec<Coin>,
+    pub burned_amount: Uint128,
+}
+
+//swaps sent by Setting , every buyback reply takes the first one
+pub const BUYBACK_QUEUE: Item<Vec<BuybackOffer>> = Item::new("buyback_queue");
+
+#[cw_serde]
+pub struct YieldOffer {
+    //staker share of a fee denom sold for the yield denom
+    pub offer: Coin,
+    //yield denom the vault held before the swap , only the swapped amount goes to the stakers
+    pub yield_balance: Coin,
+}
+
+//staker swaps sent by Setting , every yield reply takes the first one
+pub const YIELD_QUEUE: Item<Vec<YieldOffer>> = Item::new("yield_queue");
+//epoch -> fees sold and AXIS burned
+pub const BUYBACK: Map<u64, Buyback> = Map::new("buyback");
+pub const TOTAL_BURNED: Item<Uint128> = Item::new("total_burned");
+
+pub fn record_buyback(
+    storage: &mut dyn Storage,
+    epoch: u64,
+    offer: Coin,
+    burned_amount: Uint128,
+) -> StdResult<()> {
+    BUYBACK.update(storage, epoch, |buyback| -> StdResult<_> {
+        let mut buyback = buyback.unwrap_or_default();
+        match buyback.spent.iter_mut().find(|c| c.denom == offer.denom) {
+            Some(spent) => spent.amount += offer.amount,
+            None => buyback.spent.push(offer),
+        }
+        buyback.burned_amount += burned_amount;
+        Ok(buyback)
+    })?;
+    let total_burned = TOTAL_BURNED.may_load(storage)?.unwrap_or_default();
+    TOTAL_BURNED.save(storage, &(total_burned + burned_amount))
+}
+
+//router paying esAXIS swaps in one denom , none pays every denom
+pub const SWAP_ADAPTER: Item<SwapAdapter> = Item::new("swap_adapter");
+
 pub fn save_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
     CONFIG.save(storage, config)
 }
@@ -47,4 +147,20 @@ pub fn load_pending_balance(storage: &dyn Storage, denom: &String) -> StdResult<
         Err(e) => Err(e),
     }
 }
+//insurance fund, kept apart from the esAXIS fee balance
+pub const INSURANCE_BALANCE: Map<&str, Uint128> = Map::new("insurance_balance");
+pub fn save_insurance_balance(
+    storage: &mut dyn Storage,
+    denom: &String,
+    balance: &Uint128,
+) -> StdResult<()> {
+    INSURANCE_BALANCE.save(storage, denom, balance)
+}
+pub fn load_insurance_balance(storage: &dyn Storage, denom: &String) -> StdResult<Uint128> {
+    match INSURANCE_BALANCE.may_load(storage, denom) {
+        Ok(Some(balance)) => Ok(balance),
+        Ok(None) => Ok(Uint128::zero()),
+        Err(e) => Err(e),
+    }
+}
 pub const CONFIG: Item<Config> = Item::new("valut_config");
@@ -1,10 +1,58 @@
-use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Uint128};
 
 #[cw_serde]
-pub struct InstantiateMsg {}
+pub struct InstantiateMsg {
+    pub axis_contract: Addr,
+    //hex encoded sha256 root , leaf is sha256(address + amount)
+    pub merkle_root: String,
+    //block time in seconds , unclaimed amount goes back to the community pool after it
+    pub expiry: u64,
+    //percent of each claim paid as esAXIS , 0 is all AXIS
+    pub es_axis_rate: u8,
+    pub es_axis_contract: Option<Addr>,
+}
 
 #[cw_serde]
-pub enum ExecuteMsg {}
+pub enum ExecuteMsg {
+    Claim {
+        amount: Uint128,
+        //hex encoded sibling hashes from the leaf to the root
+        proof: Vec<String>,
+    },
+    //anyone can return the unclaimed amount after expiry
+    Expire {},
+}
 
 #[cw_serde]
-pub enum QueryMsg {}
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    GetConfig {},
+    #[returns(StateResponse)]
+    GetState {},
+    #[returns(ClaimedResponse)]
+    IsClaimed { address: String },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub axis_contract: String,
+    pub axis_denom: String,
+    pub merkle_root: String,
+    pub expiry: u64,
+    pub es_axis_rate: u8,
+    pub es_axis_contract: Option<String>,
+}
+
+#[cw_serde]
+pub struct StateResponse {
+    pub total_claimed: Uint128,
+    pub expired: bool,
+}
+
+#[cw_serde]
+pub struct ClaimedResponse {
+    pub claimed: bool,
+    pub amount: Uint128,
+}
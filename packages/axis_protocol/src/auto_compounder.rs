@@ -0,0 +1,90 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Uint128};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub core_contract: Addr,
+    pub base_denom: String,
+    pub price_denom: String,
+    //dex router with the router::ExecuteMsg interface
+    pub router_contract: String,
+    //None is 1 , percent under the oracle price a swap may return
+    pub max_slippage: Option<u8>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    //stake the sent lp and mint shares at the current share price
+    Deposit {},
+    //burn the sent shares , the lp is withdrawable once lp-staking unlocks it
+    Unbond {},
+    //unlocked lp of the sender
+    Withdraw {},
+    //anyone , once per epoch : claim AXIS , swap it into the pair , deposit and restake
+    Compound {},
+    //this contract only , the steps of Compound
+    SwapReward {},
+    ProvideLiquidity {},
+    StakeLiquidity {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    GetConfig {},
+    #[returns(StateResponse)]
+    GetState {},
+    #[returns(SharePriceResponse)]
+    GetSharePrice {},
+    //lp value of the shares held by the address
+    #[returns(ShareValueResponse)]
+    GetShareValue { address: String },
+    #[returns(Vec<UnbondingResponse>)]
+    GetUnbonding { address: String },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub core_contract: Addr,
+    pub lp_staking_contract: Addr,
+    pub pool_contract: Addr,
+    pub router_contract: Addr,
+    pub base_denom: String,
+    pub price_denom: String,
+    pub lp_denom: String,
+    pub axis_denom: String,
+    pub share_denom: String,
+    pub max_slippage: u8,
+}
+
+#[cw_serde]
+pub struct StateResponse {
+    //lp staked for the share holders , pending stakes included
+    pub total_lp: Uint128,
+    pub total_shares: Uint128,
+    //lp unbonded and not withdrawn yet
+    pub unbonding_total: Uint128,
+    pub last_compound_epoch: Option<u64>,
+}
+
+#[cw_serde]
+pub struct SharePriceResponse {
+    //lp per share , 1 before the first deposit
+    pub share_price: Decimal,
+    pub total_lp: Uint128,
+    pub total_shares: Uint128,
+}
+
+#[cw_serde]
+pub struct ShareValueResponse {
+    pub shares: Uint128,
+    pub lp_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct UnbondingResponse {
+    //withdrawable after this epoch
+    pub unlock_epoch: u64,
+    pub lp_amount: Uint128,
+}
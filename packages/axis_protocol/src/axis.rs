@@ -1,12 +1,82 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub core_contract: Addr,
     pub owner: Addr,
-    // pub airdrop_contract: Addr,
-    // pub airdrop_instantiate_msg: AirDropInstantiateMsg,
+    //None is halving of the default rate every 365 epochs
+    pub emission_schedule: Option<EmissionSchedule>,
+    //None is the owner vesting over 4 years of epochs after a 1 year cliff
+    pub foundation_vesting: Option<VestingParams>,
+    //None is 50 , percent of the maker emission split by gauge votes
+    pub vote_weight_rate: Option<u8>,
+}
+
+#[cw_serde]
+pub struct EmissionStep {
+    pub start_epoch: u64,
+    pub trader_amount: Uint128,
+    pub maker_amount: Uint128,
+}
+
+#[cw_serde]
+pub enum EmissionSchedule {
+    //per epoch amounts are halved every interval epochs
+    Halving {
+        trader_amount: Uint128,
+        maker_amount: Uint128,
+        interval: u64,
+    },
+    //the last step started at or before the epoch is used , sorted by start_epoch
+    Piecewise {
+        steps: Vec<EmissionStep>,
+    },
+}
+
+impl EmissionSchedule {
+    //return (trader amount, maker amount) minted for the epoch
+    pub fn rate(&self, epoch: u64) -> (Uint128, Uint128) {
+        match self {
+            EmissionSchedule::Halving {
+                trader_amount,
+                maker_amount,
+                interval,
+            } => {
+                let halvings = u32::try_from(epoch / interval).unwrap_or(u32::MAX);
+                let halve = |amount: &Uint128| {
+                    Uint128::new(amount.u128().checked_shr(halvings).unwrap_or(0))
+                };
+                (halve(trader_amount), halve(maker_amount))
+            }
+            EmissionSchedule::Piecewise { steps } => steps
+                .iter()
+                .rev()
+                .find(|step| step.start_epoch <= epoch)
+                .map(|step| (step.trader_amount, step.maker_amount))
+                .unwrap_or((Uint128::zero(), Uint128::zero())),
+        }
+    }
+}
+
+#[cw_serde]
+pub enum VestingClock {
+    //core epochs
+    Epoch,
+    //block time in seconds
+    Time,
+}
+
+#[cw_serde]
+pub struct VestingParams {
+    pub clock: VestingClock,
+    //None is now
+    pub start: Option<u64>,
+    //nothing vests before start + cliff
+    pub cliff: u64,
+    //linear from start , fully vested at start + duration
+    pub duration: u64,
+    pub revocable: bool,
 }
 
 #[cw_serde]
@@ -16,19 +86,71 @@ pub enum ExecuteMsg {
         price_denom: String,
         trader: Addr,
         fee_usd_amount: Uint128,
+        //referrer weight in trader emissions , not counted in the pool fee
+        referrer: Option<Addr>,
+        referrer_fee_usd_amount: Uint128,
     },
     Setting {
         epoch: u64,
     },
-    ClaimMintTrader {},
+    //None is from the first unclaimed epoch up to the last settled epoch
+    ClaimMintTrader {
+        start_epoch: Option<u64>,
+        end_epoch: Option<u64>,
+    },
     ClaimMintMaker {
         base_denom: String,
         price_denom: String,
         sender: Addr,
         amount: Uint128,
-    }, // RegisterAirDrop {
-       //     air_drop_contract: String,
-       // },
+    },
+    //owner only , escrow the sent AXIS for the beneficiary
+    CreateVesting {
+        beneficiary: String,
+        params: VestingParams,
+    },
+    //owner only , unvested amount goes back to the owner
+    RevokeVesting {
+        beneficiary: String,
+    },
+    ClaimVested {},
+    //owner only , fund the airdrop from the community pool
+    RegisterAirDrop {
+        air_drop_contract: String,
+        amount: Uint128,
+    },
+    //registered airdrop only , unclaimed amount back to the community pool
+    ReturnCommunity {},
+    //owner only
+    RegisterMinter {
+        minter: String,
+    },
+    RemoveMinter {
+        minter: String,
+    },
+    //registered minter only , counted in the supply cap
+    Mint {
+        recipient: String,
+        amount: Uint128,
+    },
+    //burn the sent AXIS , the supply cap is not given back
+    Burn {},
+    //AXIS stakers only , replaces the vote of the sender in the current epoch
+    Vote {
+        votes: Vec<GaugeVote>,
+    },
+    //owner only
+    UpdateVoteWeightRate {
+        vote_weight_rate: u8,
+    },
+}
+
+#[cw_serde]
+pub struct GaugeVote {
+    pub base_denom: String,
+    pub price_denom: String,
+    //percent of the voting power , the sum is at most 100
+    pub weight: u8,
 }
 // query 뭐가 필요할까?
 //1.config
@@ -50,14 +172,39 @@ pub enum QueryMsg {
     GetTotalSupply {},
     #[returns(Vec<EpochTotalFeeAmountResponse>)]
     GetEpochTotalFeeAmount { start_epoch: u64, end_epoch: u64 },
+    //None is the current epoch
+    #[returns(EmissionRateResponse)]
+    GetEmissionRate { epoch: Option<u64> },
+    #[returns(RemainingEmissionResponse)]
+    GetRemainingEmission {},
+    #[returns(TraderClaimableResponse)]
+    GetTraderClaimable { address: String },
+    #[returns(CommunityPoolResponse)]
+    GetCommunityPool {},
+    //None when the address is not a registered airdrop
+    #[returns(Option<AirDropResponse>)]
+    GetAirDrop { address: String },
+    #[returns(VestingScheduleResponse)]
+    GetVestingSchedule { address: String },
+    #[returns(Vec<MinterResponse>)]
+    GetMinters {},
+    //None is the current epoch
+    #[returns(VoteResponse)]
+    GetVote { address: String, epoch: Option<u64> },
+    //None is the current epoch projected from the votes and fees so far
+    #[returns(Vec<GaugeWeightResponse>)]
+    GetGaugeWeights { epoch: Option<u64> },
 }
 
 #[cw_serde]
 pub struct ConfigResponse {
     pub core_contract: String,
     pub axis_denom: String,
+    //rates of the current epoch
     pub mint_per_epoch_maker_amount: Uint128,
     pub mint_per_epoch_trader_amount: Uint128,
+    pub emission_schedule: EmissionSchedule,
+    pub vote_weight_rate: u8,
 }
 #[cw_serde]
 pub struct PendingFeeResponse {
@@ -80,3 +227,78 @@ pub struct EpochTotalFeeAmountResponse {
     pub epoch: u64,
     pub amount: Uint128,
 }
+
+#[cw_serde]
+pub struct EmissionRateResponse {
+    pub epoch: u64,
+    pub trader_amount: Uint128,
+    pub maker_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct RemainingEmissionResponse {
+    pub max_total_supply: Uint128,
+    pub total_minted: Uint128,
+    pub remaining_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct VestingScheduleResponse {
+    pub beneficiary: String,
+    pub total_amount: Uint128,
+    pub vested_amount: Uint128,
+    pub claimed_amount: Uint128,
+    pub claimable_amount: Uint128,
+    pub clock: VestingClock,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub revocable: bool,
+    pub revoked: bool,
+}
+
+#[cw_serde]
+pub struct TraderClaimableResponse {
+    //amount the next ClaimMintTrader without a range pays
+    pub claimable_amount: Uint128,
+    pub start_epoch: Option<u64>,
+    pub end_epoch: Option<u64>,
+    //fee of the current epoch , claimable after the next setting
+    pub pending_fee_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct AirDropResponse {
+    pub air_drop_contract: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct CommunityPoolResponse {
+    pub community_pool_amount: Uint128,
+    pub air_drops: Vec<AirDropResponse>,
+}
+
+#[cw_serde]
+pub struct MinterResponse {
+    pub minter: Addr,
+    pub minted_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct VoteResponse {
+    pub epoch: u64,
+    //staked AXIS when the vote was cast
+    pub voting_power: Uint128,
+    pub votes: Vec<GaugeVote>,
+}
+
+#[cw_serde]
+pub struct GaugeWeightResponse {
+    pub base_denom: String,
+    pub price_denom: String,
+    pub fee_amount: Uint128,
+    pub vote_amount: Uint128,
+    //share of the maker emission of the epoch
+    pub weight: Decimal,
+}
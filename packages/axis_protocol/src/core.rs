@@ -1,11 +1,39 @@
-use crate::pool::InstantiateMsg as PoolInstantiateMsg;
+use crate::axis::{EmissionSchedule, VestingParams};
+use crate::pool::{InstantiateMsg as PoolInstantiateMsg, PauseMode};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint128};
 #[cw_serde]
 pub struct InstantiateMsg {
     pub accept_price_denoms: Vec<String>,
     pub axis_code_id: u64,
     pub next_update_timestamp: u64,
+    pub emission_schedule: Option<EmissionSchedule>,
+    pub foundation_vesting: Option<VestingParams>,
+    //None is the default tiers
+    pub referral_tiers: Option<Vec<ReferralTier>>,
+    //None is the default tiers
+    pub fee_tiers: Option<Vec<FeeTier>>,
+}
+
+//rates are percent of the open / close fee
+#[cw_serde]
+pub struct ReferralTier {
+    //referred trader count a referrer needs for this tier
+    pub min_trader_count: u64,
+    //back to the trader
+    pub rebate_rate: u8,
+    //to the referrer
+    pub referrer_rate: u8,
+    //percent of the referred fee usd credited to the referrer in trader emissions
+    pub emission_rate: u8,
+}
+
+//staked AXIS plus esAXIS balance decides the tier
+#[cw_serde]
+pub struct FeeTier {
+    pub min_axis_amount: Uint128,
+    //percent off the open / close fee
+    pub discount_rate: u8,
 }
 
 #[cw_serde]
@@ -17,13 +45,19 @@ pub enum ExecuteMsg {
     RegisterPriceDenom {
         price_denom: String,
     },
-    AllPoolLock {},
-    PairLock {
+    AllPoolSetMode {
+        mode: PauseMode,
+    },
+    PairSetMode {
+        base_denom: String,
+        price_denom: String,
+        mode: PauseMode,
+    },
+    PairResetBreaker {
         base_denom: String,
         price_denom: String,
     },
-    AllPoolUnLock {},
-    PairUnLock {
+    DelistPair {
         base_denom: String,
         price_denom: String,
     },
@@ -32,6 +66,31 @@ pub enum ExecuteMsg {
         vault_contract: Option<String>,
         staking_contract: Option<String>,
     },
+    //owner only , sorted by min_axis_amount and the first tier starts at 0
+    UpdateFeeTiers {
+        tiers: Vec<FeeTier>,
+    },
+    //owner only , sorted by min_trader_count and the first tier starts at 0
+    UpdateReferralTiers {
+        tiers: Vec<ReferralTier>,
+    },
+    //sender becomes the referrer of the code
+    RegisterReferralCode {
+        code: String,
+    },
+    //sender is referred by the code owner , can be set once
+    SetReferralCode {
+        code: String,
+    },
+    //market only , rebates already paid by the market
+    AddReferralFee {
+        base_denom: String,
+        price_denom: String,
+        trader: Addr,
+        fee_usd_amount: Uint128,
+        rebate_usd_amount: Uint128,
+        reward_usd_amount: Uint128,
+    },
 }
 
 #[cw_serde]
@@ -54,6 +113,21 @@ pub enum QueryMsg {
         base_denom: String,
         price_denom: String,
     },
+    #[returns(DelistedPairResponse)]
+    GetDelistedPair {
+        base_denom: String,
+        price_denom: String,
+    },
+    #[returns(Vec<FeeTier>)]
+    GetFeeTiers {},
+    #[returns(TraderFeeTierResponse)]
+    GetTraderFeeTier { trader: String },
+    #[returns(Vec<ReferralTier>)]
+    GetReferralTiers {},
+    #[returns(TraderReferralResponse)]
+    GetTraderReferral { trader: String },
+    #[returns(ReferralStatsResponse)]
+    GetReferralStats { referrer: String },
 }
 #[cw_serde]
 pub enum SudoMsg {
@@ -88,3 +162,41 @@ pub struct PairLpStakingContractResponse {
     pub price_denom: String,
     pub lp_staking_contract: Addr,
 }
+
+#[cw_serde]
+pub struct DelistedPairResponse {
+    pub base_denom: String,
+    pub price_denom: String,
+    pub pool_contract: Addr,
+    pub market_contract: Addr,
+    pub lp_staking_contract: Addr,
+}
+
+#[cw_serde]
+pub struct TraderReferralResponse {
+    pub code: Option<String>,
+    pub referrer: Option<Addr>,
+    //rates of the referrer tier , zero without a referrer
+    pub rebate_rate: u8,
+    pub referrer_rate: u8,
+    pub emission_rate: u8,
+}
+
+#[cw_serde]
+pub struct ReferralStatsResponse {
+    pub code: Option<String>,
+    pub trader_count: u64,
+    pub tier: ReferralTier,
+    pub total_fee_usd_amount: Uint128,
+    pub total_rebate_usd_amount: Uint128,
+    pub total_reward_usd_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct TraderFeeTierResponse {
+    //index in the fee tiers
+    pub tier: u8,
+    pub discount_rate: u8,
+    pub staked_axis_amount: Uint128,
+    pub es_axis_amount: Uint128,
+}
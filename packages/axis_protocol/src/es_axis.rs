@@ -1,14 +1,80 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Binary, Uint128};
 
 #[cw_serde]
-pub struct InstantiateMsg {}
+pub struct InstantiateMsg {
+    pub core_contract: Addr,
+    //None is 365 epochs without reserve
+    pub vesting_config: Option<VestingConfig>,
+}
+
+//from the bank denom version , burns the bank esAXIS held by this contract into its ledger balance
+//and sets es_axis_total_supply to the bank supply , holders move the rest with RedeemBank
+#[cw_serde]
+pub struct MigrateMsg {
+    pub core_contract: String,
+    //None is 365 epochs without reserve
+    pub vesting_config: Option<VestingConfig>,
+}
+
+#[cw_serde]
+pub struct VestingConfig {
+    //deposited esAXIS converts linearly into AXIS over this many epochs
+    pub vesting_epochs: u64,
+    //percent of the deposit that must be covered by staked AXIS , 0 is no reserve
+    pub reserve_rate: u8,
+}
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Claim { sender: Addr, amount: Uint128 },
-    Mint { amount: Uint128 },
-    Burn {},
+    Claim {
+        sender: Addr,
+        amount: Uint128,
+    },
+    Mint {
+        amount: Uint128,
+    },
+    Burn {
+        amount: Uint128,
+    },
+    //registered airdrop only , lock the sent AXIS and mint the same amount of esAXIS to the recipient
+    Escrow {
+        recipient: String,
+    },
+    //whitelisted mover only
+    Transfer {
+        recipient: String,
+        amount: Uint128,
+    },
+    //move to a whitelisted contract and call its Receive with EsAxisReceiveMsg
+    Send {
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    },
+    //core owner only
+    AddMover {
+        mover: String,
+    },
+    RemoveMover {
+        mover: String,
+    },
+    //core owner only , applies to new deposits
+    UpdateVestingConfig {
+        vesting_config: VestingConfig,
+    },
+    //start vesting esAXIS of the sender
+    Deposit {
+        amount: Uint128,
+    },
+    //vested AXIS of every position
+    ClaimVested {},
+    //claim the vested part and take back the unvested esAXIS
+    WithdrawVesting {
+        position_id: u64,
+    },
+    //burn the bank esAXIS sent with it and credit the same amount to the sender ledger
+    RedeemBank {},
 }
 
 #[cw_serde]
@@ -18,6 +84,26 @@ pub enum QueryMsg {
     GetTotalSupply {},
     #[returns(ConfigResponse)]
     GetConfig {},
+    #[returns(Uint128)]
+    GetBalance { address: String },
+    //contracts allowed to move esAXIS
+    #[returns(Vec<Addr>)]
+    GetMovers {},
+    #[returns(VestingPositionsResponse)]
+    GetVestingPositions { address: String },
+    //staked AXIS held by the vesting positions of the address
+    #[returns(Uint128)]
+    GetReservedAmount { address: String },
+    #[returns(SupplyInvariantResponse)]
+    GetSupplyInvariant {},
+}
+
+//sent to the receiving contract as ExecuteMsg::Receive
+#[cw_serde]
+pub struct EsAxisReceiveMsg {
+    pub sender: String,
+    pub amount: Uint128,
+    pub msg: Binary,
 }
 
 #[cw_serde]
@@ -25,4 +111,38 @@ pub struct ConfigResponse {
     pub staking_contarct: String,
     pub es_axis_total_supply: Uint128,
     pub es_axis_denom: String,
+    pub core_contract: String,
+    pub vesting_config: VestingConfig,
+    //AXIS locked by escrow , paid out before anything is minted
+    pub escrowed_axis_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct SupplyInvariantResponse {
+    pub es_axis_total_supply: Uint128,
+    //sum of the ledger balances
+    pub ledger_supply: Uint128,
+    //deposited esAXIS not yet claimed from vesting positions
+    pub vesting_supply: Uint128,
+    //esAXIS still held as the bank denom
+    pub bank_supply: Uint128,
+    pub is_consistent: bool,
+}
+
+#[cw_serde]
+pub struct VestingPositionResponse {
+    pub position_id: u64,
+    pub amount: Uint128,
+    pub vested_amount: Uint128,
+    pub claimed_amount: Uint128,
+    pub claimable_amount: Uint128,
+    pub reserved_amount: Uint128,
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+}
+
+#[cw_serde]
+pub struct VestingPositionsResponse {
+    pub positions: Vec<VestingPositionResponse>,
+    pub reserved_amount: Uint128,
 }
@@ -1,11 +1,14 @@
 pub mod core;
 
 pub mod airdrop;
+pub mod auto_compounder;
 pub mod axis;
 pub mod es_axis;
 pub mod lp_staking;
 pub mod market;
 pub mod pool;
 pub mod query;
+pub mod reward;
+pub mod router;
 pub mod staking;
 pub mod vault;
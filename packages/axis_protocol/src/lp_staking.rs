@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -8,15 +8,35 @@ pub struct InstantiateMsg {
     pub axis_contract: Addr,
     pub core_contract: Addr,
     pub lp_denom: String,
+    //None is 2.5 , the most a stake can be boosted by staked AXIS and esAXIS
+    pub max_boost: Option<Decimal>,
+    //None is 7 , 30 and 90 epochs weighted 1.25 , 1.5 and 2
+    pub lock_tiers: Option<Vec<LockTier>>,
+    //None is 50 , percent of a locked stake taken on the first epoch , less as the lock runs
+    pub early_exit_penalty_rate: Option<u8>,
+}
+
+//@@ a locked stake counts weight times in the working balance until it expires
+#[cw_serde]
+pub struct LockTier {
+    pub lock_epochs: u64,
+    pub weight: Decimal,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Staking {},
+    //None stakes without a lock , otherwise lock_epochs of a lock tier
+    Staking { lock_epochs: Option<u64> },
+    //AXIS and the lp taken by early exits
     ClaimReward {},
-    UnStaking {},
+    //stakes without a lock or with an expired lock , None is all of them
+    UnStaking { amount: Option<Uint128> },
+    //every active stake , the locked ones pay the early exit penalty to the other stakers
+    EarlyUnStaking {},
     Withdraw {},
     Setting { epoch: u64 },
+    //recompute the working balance with the staked AXIS and esAXIS of the address , anyone can call
+    UpdateBoost { address: String },
 }
 
 #[cw_serde]
@@ -32,6 +52,8 @@ pub enum QueryMsg {
     GetUnstakeInfo { address: Addr },
     #[returns(EpochTotalStakingResponse)]
     GetEpochTotalStaking { start_epoch: u64, end_epoch: u64 },
+    #[returns(BoostInfoResponse)]
+    GetBoostInfo { address: Addr },
 }
 
 #[cw_serde]
@@ -41,6 +63,9 @@ pub struct ConfigResponse {
     pub lp_denom: String,
     pub base_denom: String,
     pub price_denom: String,
+    pub max_boost: Decimal,
+    pub lock_tiers: Vec<LockTier>,
+    pub early_exit_penalty_rate: u8,
 }
 
 #[cw_serde]
@@ -49,6 +74,10 @@ pub struct StateResponse {
     pub staking_total: Uint128,
     pub stake_pending_total: Uint128,
     pub withdraw_pending_total: Uint128,
+    //sum of the working balances , AXIS rewards are shared by it
+    pub working_total: Uint128,
+    //early exit penalty shared at the next setting
+    pub penalty_pending_total: Uint128,
 }
 
 #[cw_serde]
@@ -61,6 +90,15 @@ pub struct EpochTotalStakingResponse {
 pub struct StakeInfoResponse {
     pub start_epoch: u64,
     pub staking_amount: Uint128,
+    pub lock: Option<LockResponse>,
+}
+
+#[cw_serde]
+pub struct LockResponse {
+    pub lock_epochs: u64,
+    //unstakable without the penalty from this epoch
+    pub unlock_epoch: u64,
+    pub weight: Decimal,
 }
 
 #[cw_serde]
@@ -68,3 +106,16 @@ pub struct UnStakeInfoResponse {
     pub unlock_epoch: u64,
     pub unstaking_amount: Uint128,
 }
+
+#[cw_serde]
+pub struct BoostInfoResponse {
+    //active lp , pending stakes count from the next epoch
+    pub staking_amount: Uint128,
+    //staked AXIS and esAXIS of the address when the boost was last updated
+    pub boost_amount: Uint128,
+    pub boost_total: Uint128,
+    //working balance / staking amount , lock weights times 1 to max_boost
+    pub boost: Decimal,
+    pub working_balance: Uint128,
+    pub working_total: Uint128,
+}
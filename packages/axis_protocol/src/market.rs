@@ -1,3 +1,4 @@
+use crate::pool::PauseMode;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Decimal, Uint128};
 
@@ -13,6 +14,12 @@ pub struct InstantiateMsg {
     pub limit_profit_loss_open_fee_rate: u8,
     pub axis_contract: Addr,
     pub vault_contract: Addr,
+    pub adl_threshold: u8,
+    pub adl_close_rate: u8,
+    //oracle price move % between sweeps that trips the breaker , 0 is off
+    pub price_breaker_rate: u8,
+    //lp nav drop % within an epoch that trips the breaker , 0 is off
+    pub nav_breaker_rate: u8,
 }
 
 #[cw_serde]
@@ -25,6 +32,16 @@ pub enum ExecuteMsg {
     },
     Close {},
     Liquidated {},
+    AutoDeleverage {},
+    ResetBreaker {},
+    SetMode {
+        mode: PauseMode,
+    },
+    Delist {},
+    //permissionless , closes the trade at the settlement price of a delisted pair
+    SettleTrade {
+        trader: String,
+    },
 }
 
 #[cw_serde]
@@ -36,6 +53,8 @@ pub enum QueryMsg {
     GetState {},
     #[returns(TradeResponse)]
     GetTrade { trader: String },
+    #[returns(BreakerStatusResponse)]
+    GetBreakerStatus {},
 }
 
 #[cw_serde]
@@ -51,12 +70,30 @@ pub struct GetConfigResponse {
     pub pool_contract: Addr,
     pub vault_contract: Addr,
     pub axis_contract: Addr,
+    pub adl_threshold: u8,
+    pub adl_close_rate: u8,
+    pub price_breaker_rate: u8,
+    pub nav_breaker_rate: u8,
+    pub core_contract: Addr,
 }
 #[cw_serde]
 pub struct GetStateResponse {
     pub base_coin_total_fee: Uint128,
     pub price_coin_total_fee: Uint128,
     pub past_price: Decimal,
+    pub mode: PauseMode,
+    pub settlement_price: Option<Decimal>,
+}
+#[cw_serde]
+pub struct BreakerStatusResponse {
+    pub tripped: bool,
+    pub reason: Option<String>,
+    pub past_price: Decimal,
+    pub price_breaker_rate: u8,
+    pub nav_breaker_rate: u8,
+    //lp nav snapshot of the epoch
+    pub epoch: u64,
+    pub epoch_nav: Decimal,
 }
 #[cw_serde]
 pub struct TradeResponse {
@@ -82,4 +119,6 @@ pub struct TradeResponse {
     pub leverage: u8,
     //레버리지한 금액
     pub leverage_amount: Uint128,
+    //fee tier applied at open
+    pub fee_tier: u8,
 }
@@ -18,6 +18,53 @@ pub struct InstantiateMsg {
     //pub fee_Valut_contract:String
 }
 
+#[cw_serde]
+pub enum PauseMode {
+    Active,
+    //only withdrawals and closes
+    Paused,
+    //only closes and liquidations
+    ReduceOnly,
+    //emergency , everything is blocked
+    Halted,
+    //wound down , trades are settled at the final price and lps withdraw
+    Delisted,
+}
+
+impl PauseMode {
+    pub fn can_open(&self) -> bool {
+        matches!(self, PauseMode::Active)
+    }
+    pub fn can_close(&self) -> bool {
+        matches!(
+            self,
+            PauseMode::Active | PauseMode::Paused | PauseMode::ReduceOnly
+        )
+    }
+    pub fn can_liquidate(&self) -> bool {
+        matches!(self, PauseMode::Active | PauseMode::ReduceOnly)
+    }
+    pub fn can_deposit(&self) -> bool {
+        matches!(self, PauseMode::Active)
+    }
+    pub fn can_withdraw(&self) -> bool {
+        matches!(
+            self,
+            PauseMode::Active | PauseMode::Paused | PauseMode::Delisted
+        )
+    }
+    pub fn can_repay(&self) -> bool {
+        !matches!(self, PauseMode::Halted)
+    }
+    pub fn can_settle(&self) -> bool {
+        matches!(self, PauseMode::Delisted)
+    }
+    //only delist enters Delisted , nothing leaves it
+    pub fn can_set_mode(&self) -> bool {
+        !matches!(self, PauseMode::Delisted)
+    }
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     LeverageBorrow {
@@ -30,10 +77,18 @@ pub enum ExecuteMsg {
         amount: Uint128,
         borrowed_amount: Uint128,
     },
+    CoverBadDebt {},
     Deposit {},
     Withdraw {},
-    Lock {},
-    UnLock {},
+    SetMode {
+        mode: PauseMode,
+    },
+    //market trips the breaker , deposits are paused
+    TripBreaker {},
+    //core resets the breaker of the pool and its market
+    ResetBreaker {},
+    //core winds down the pair
+    Delist {},
 }
 
 #[cw_serde]
@@ -64,7 +119,7 @@ pub struct PositionBalance {
 #[cw_serde]
 pub struct ConfigResponse {
     pub core_contract: Addr,
-    pub lock: bool,
+    pub mode: PauseMode,
     pub market_contract: Addr,
     pub lp_decimal: u8,
     pub lp_denom: String,
@@ -75,6 +130,7 @@ pub struct ConfigResponse {
     pub price_denom: String,
     pub base_denom: String,
     pub withdraw_fee_rate: Decimal,
+    pub deposit_pause: bool,
 }
 
 #[cw_serde]
@@ -83,6 +139,7 @@ pub struct PoolResponse {
     pub price_amount: Uint128,
     pub base_borrow_amount: Uint128,
     pub price_borrow_amount: Uint128,
+    pub lp_total_supply: Uint128,
 }
 
 // #[cw_serde]
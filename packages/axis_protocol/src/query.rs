@@ -1,6 +1,7 @@
 use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
 use sei_cosmwasm::SeiQueryWrapper;
 
+use crate::axis::{ConfigResponse as AxisConfigResponse, QueryMsg as AxisQueryMsg};
 use crate::core::{ConfigResponse as CoreConfigResponse, QueryMsg as CoreQueryMsg};
 
 pub fn query_epoch(
@@ -12,3 +13,43 @@ pub fn query_epoch(
 
     Ok(core_config.epoch)
 }
+
+pub fn query_vault_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+) -> StdResult<Addr> {
+    let core_config: CoreConfigResponse =
+        querier.query_wasm_smart(core_contract.to_string(), &CoreQueryMsg::GetConfig {})?;
+
+    Ok(core_config.vault_contract)
+}
+
+pub fn query_staking_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+) -> StdResult<Addr> {
+    let core_config: CoreConfigResponse =
+        querier.query_wasm_smart(core_contract.to_string(), &CoreQueryMsg::GetConfig {})?;
+
+    Ok(core_config.staking_contract)
+}
+
+pub fn query_axis_denom(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    axis_contract: &Addr,
+) -> StdResult<String> {
+    let axis_config: AxisConfigResponse =
+        querier.query_wasm_smart(axis_contract.to_string(), &AxisQueryMsg::GetConfig {})?;
+
+    Ok(axis_config.axis_denom)
+}
+
+pub fn query_axis_contract(
+    querier: QuerierWrapper<SeiQueryWrapper>,
+    core_contract: &Addr,
+) -> StdResult<Addr> {
+    let core_config: CoreConfigResponse =
+        querier.query_wasm_smart(core_contract.to_string(), &CoreQueryMsg::GetConfig {})?;
+
+    Ok(core_config.axis_contract)
+}
@@ -0,0 +1,88 @@
+use cosmwasm_std::{Decimal, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+//@@ cumulative reward per staked token , saved at every Setting under the epoch it opens.
+//@@ a stake keeps its start epoch as checkpoint , its reward is amount * (index(now) - index(start))
+//@@ every reward token (esAXIS , each fee denom) keeps its own index under its key
+pub struct RewardIndex<'a>(Map<'a, (&'a str, u64), Decimal>);
+
+impl<'a> RewardIndex<'a> {
+    pub const fn new(namespace: &'a str) -> Self {
+        RewardIndex(Map::new(namespace))
+    }
+
+    pub fn latest(&self, storage: &dyn Storage, key: &str) -> StdResult<Decimal> {
+        Ok(self
+            .0
+            .prefix(key)
+            .range(storage, None, None, Order::Descending)
+            .next()
+            .transpose()?
+            .map(|(_, index)| index)
+            .unwrap_or_default())
+    }
+
+    //@@ index of the first setting at or after the epoch , None is not started yet
+    pub fn at(&self, storage: &dyn Storage, key: &str, epoch: u64) -> StdResult<Option<Decimal>> {
+        Ok(self
+            .0
+            .prefix(key)
+            .range(
+                storage,
+                Some(Bound::inclusive(epoch)),
+                None,
+                Order::Ascending,
+            )
+            .next()
+            .transpose()?
+            .map(|(_, index)| index))
+    }
+
+    //@@ a key added later starts from here , stakes opened before the epoch read it as their start
+    pub fn checkpoint(&self, storage: &mut dyn Storage, key: &str, epoch: u64) -> StdResult<()> {
+        if self.0.has(storage, (key, epoch)) {
+            return Ok(());
+        }
+        let index = self.latest(storage, key)?;
+        self.0.save(storage, (key, epoch), &index)
+    }
+
+    //@@ reward of the ended epoch is shared by the stake active in it
+    pub fn distribute(
+        &self,
+        storage: &mut dyn Storage,
+        key: &str,
+        epoch: u64,
+        reward: Uint128,
+        staking_total: Uint128,
+    ) -> StdResult<Decimal> {
+        let mut index = self.latest(storage, key)?;
+        if !staking_total.is_zero() {
+            index += Decimal::from_ratio(reward, staking_total);
+        }
+        self.0.save(storage, (key, epoch), &index)?;
+        Ok(index)
+    }
+
+    pub fn pending_reward(
+        &self,
+        storage: &dyn Storage,
+        key: &str,
+        staking_amount: Uint128,
+        start_epoch: u64,
+        until_epoch: u64,
+    ) -> StdResult<Uint128> {
+        if start_epoch >= until_epoch {
+            return Ok(Uint128::zero());
+        }
+        let (start_index, until_index) = match (
+            self.at(storage, key, start_epoch)?,
+            self.at(storage, key, until_epoch)?,
+        ) {
+            (Some(start_index), Some(until_index)) => (start_index, until_index),
+            (Some(start_index), None) => (start_index, self.latest(storage, key)?),
+            _ => return Ok(Uint128::zero()),
+        };
+        Ok(staking_amount * (until_index - start_index))
+    }
+}
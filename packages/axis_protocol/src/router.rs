@@ -0,0 +1,29 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+//interface the vault swap adapter expects from a dex router
+#[cw_serde]
+pub enum ExecuteMsg {
+    //swap the sent coin into ask_denom , fails under min_return
+    Swap {
+        ask_denom: String,
+        min_return: Uint128,
+        recipient: String,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(SimulateSwapResponse)]
+    SimulateSwap {
+        offer_denom: String,
+        offer_amount: Uint128,
+        ask_denom: String,
+    },
+}
+
+#[cw_serde]
+pub struct SimulateSwapResponse {
+    pub return_amount: Uint128,
+}
@@ -1,20 +1,32 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Uint128};
+
+use crate::es_axis::VestingConfig;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub core_contract: Addr,
     pub axis_denom: String,
     pub es_axis_code: u64,
+    //None is the es axis default
+    pub es_axis_vesting_config: Option<VestingConfig>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     Setting { epoch: u64 },
     Staking {},
-    ClaimReward {},
-    UnStaking {},
+    //None pays every reward up to the current epoch
+    ClaimReward { until_epoch: Option<u64> },
+    //None unstakes everything
+    UnStaking { amount: Option<Uint128> },
     Withdraw {},
+    //restake the unbonding AXIS unlocking at unlock_epoch
+    CancelUnbonding { unlock_epoch: u64 },
+    //vault only , staker share of the epoch fees shared at the next Setting
+    ReceiveFees {},
+    //fee denoms earned up to the current epoch
+    ClaimFees {},
 }
 
 #[cw_serde]
@@ -30,6 +42,8 @@ pub enum QueryMsg {
     GetUnStakeInfo { addr: String },
     #[returns(Uint128)]
     GetAvailableReward { addr: String },
+    #[returns(Vec<Coin>)]
+    GetPendingFees { address: String },
 }
 
 #[cw_serde]
@@ -1,5 +1,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Coin, Uint128};
+
+use crate::es_axis::EsAxisReceiveMsg;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -7,6 +9,12 @@ pub struct InstantiateMsg {
     pub es_axis_contract: String,
     pub es_axis_denom: String,
     pub denom_list: Vec<String>,
+    //percent of every epoch fee sent to AXIS stakers , None is 0
+    pub staker_yield_rate: Option<u8>,
+    //bought through the swap adapter , None or no adapter pays the stakers in the fee denoms
+    pub yield_denom: Option<String>,
+    //percent of every epoch fee used to buy back and burn AXIS , None is 0
+    pub buyback_rate: Option<u8>,
 }
 
 #[cw_serde]
@@ -17,10 +25,57 @@ pub enum ExecuteMsg {
         price_denom: String,
         price_amount: Uint128,
     },
-    Swap {},
+    RecievedInsuranceFee {
+        base_denom: String,
+        price_denom: String,
+    },
+    CoverBadDebt {
+        base_denom: String,
+        base_amount: Uint128,
+        price_denom: String,
+        price_amount: Uint128,
+    },
+    //esAXIS sent through the es axis contract
+    Receive(EsAxisReceiveMsg),
     Setting {
         epoch: u64,
     },
+    //owner only , the rest of the fees stays for esAXIS swaps
+    UpdateYieldConfig {
+        staker_yield_rate: u8,
+        yield_denom: Option<String>,
+    },
+    //owner only , bought back through the swap adapter router
+    UpdateBuybackConfig {
+        buyback_rate: u8,
+    },
+    //owner or core , core registers the denoms of every new pair
+    AddDenom {
+        denom: String,
+    },
+    //owner only , the denom must hold no fee balance
+    RemoveDenom {
+        denom: String,
+    },
+    //owner only , None pays esAXIS swaps in every denom
+    UpdateSwapAdapter {
+        swap_adapter: Option<SwapAdapter>,
+    },
+}
+
+//esAXIS swaps are paid in payout_denom , the other denoms go through the router
+#[cw_serde]
+pub struct SwapAdapter {
+    pub router_contract: String,
+    pub payout_denom: String,
+    //percent under the oracle price the router may return
+    pub max_slippage: u8,
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    //esAXIS for its share of the vault
+    Swap {},
 }
 
 #[cw_serde]
@@ -32,6 +87,28 @@ pub enum QueryMsg {
     GetDenomPendingBalance { denom: String },
     #[returns(AddressBalanceResponse)]
     GetAddressBalance { address: String },
+    #[returns(InsuranceBalanceResponse)]
+    GetInsuranceBalance { denom: String },
+    #[returns(YieldConfigResponse)]
+    GetYieldConfig {},
+    #[returns(Option<SwapAdapter>)]
+    GetSwapAdapter {},
+    #[returns(Vec<String>)]
+    GetDenomList {},
+    //fees received in each epoch of [start_epoch, end_epoch]
+    #[returns(Vec<EpochFeesResponse>)]
+    GetEpochFees { start_epoch: u64, end_epoch: u64 },
+    //fees of the pair in each epoch of [start_epoch, end_epoch]
+    #[returns(PairFeesResponse)]
+    GetPairFees {
+        base_denom: String,
+        price_denom: String,
+        start_epoch: u64,
+        end_epoch: u64,
+    },
+    //buybacks of the fees of each epoch in [start_epoch, end_epoch]
+    #[returns(BuybackHistoryResponse)]
+    GetBuybackHistory { start_epoch: u64, end_epoch: u64 },
 }
 
 #[cw_serde]
@@ -46,6 +123,57 @@ pub struct DenomPendingBalanceResponse {
     pub amount: Uint128,
 }
 
+#[cw_serde]
+pub struct InsuranceBalanceResponse {
+    pub denom: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct EpochFeesResponse {
+    pub epoch: u64,
+    pub fees: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct PairEpochFeeResponse {
+    pub epoch: u64,
+    pub base_amount: Uint128,
+    pub price_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct PairFeesResponse {
+    pub base_denom: String,
+    pub price_denom: String,
+    //sum of the epochs in the range
+    pub base_amount: Uint128,
+    pub price_amount: Uint128,
+    pub epoch_fees: Vec<PairEpochFeeResponse>,
+}
+
+#[cw_serde]
+pub struct YieldConfigResponse {
+    pub staker_yield_rate: u8,
+    pub yield_denom: Option<String>,
+    pub buyback_rate: u8,
+}
+
+#[cw_serde]
+pub struct BuybackResponse {
+    pub epoch: u64,
+    //fees sold to the router
+    pub spent: Vec<Coin>,
+    pub burned_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct BuybackHistoryResponse {
+    //every AXIS burned by the vault
+    pub total_burned: Uint128,
+    pub buybacks: Vec<BuybackResponse>,
+}
+
 #[cw_serde]
 pub struct AddressBalanceResponse {
     pub balances: Vec<DenomBalanceResponse>,
@@ -0,0 +1,210 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_multi_test::{ContractWrapper, Executor};
+use sei_integration_tests::helper::mock_app;
+use sha2::{Digest, Sha256};
+
+use crate::app::{
+    init_default_balances, init_exchange_rates, setup_init, ADMIN, BTC_DENOM, TRADER1, TRADER2,
+    USDC_DENOM,
+};
+use airdrop::contract::{
+    execute as airdrop_execute, instantiate as airdrop_instantiate, query as airdrop_query,
+};
+use axis_protocol::{
+    airdrop::{ClaimedResponse, ExecuteMsg, InstantiateMsg, QueryMsg, StateResponse},
+    axis::{
+        CommunityPoolResponse, ConfigResponse as AxisConfigResponse, ExecuteMsg as AxisExecuteMsg,
+        QueryMsg as AxisQueryMsg,
+    },
+    es_axis::QueryMsg as EsAxisQueryMsg,
+};
+
+pub fn leaf(address: &str, amount: u128) -> [u8; 32] {
+    Sha256::digest(format!("{}{}", address, amount).as_bytes()).into()
+}
+
+pub fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let mut hashes = [a, b];
+    hashes.sort_unstable();
+    Sha256::digest(hashes.concat()).into()
+}
+
+#[test]
+fn test_airdrop_claim_and_expire() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let axis_contract = contracts.axis_contract;
+    let es_axis_contract = contracts.es_axis_contract;
+    let admin = Addr::unchecked(ADMIN);
+    let trader1 = Addr::unchecked(TRADER1);
+    let trader2 = Addr::unchecked(TRADER2);
+
+    let trader1_leaf = leaf(TRADER1, 1_000_000);
+    let trader2_leaf = leaf(TRADER2, 2_000_000);
+    let merkle_root = hex::encode(hash_pair(trader1_leaf, trader2_leaf));
+
+    let airdrop_code = app.store_code(Box::new(ContractWrapper::new(
+        airdrop_execute,
+        airdrop_instantiate,
+        airdrop_query,
+    )));
+    let expiry = app.block_info().time.seconds() + 1000;
+    let airdrop_contract = app
+        .instantiate_contract(
+            airdrop_code,
+            admin.to_owned(),
+            &InstantiateMsg {
+                axis_contract: axis_contract.to_owned(),
+                merkle_root,
+                expiry,
+                es_axis_rate: 50,
+                es_axis_contract: Some(es_axis_contract.to_owned()),
+            },
+            &vec![],
+            "axis airdrop",
+            None,
+        )
+        .unwrap();
+
+    //@@ only the owner can fund an airdrop
+    let register_result = app.execute_contract(
+        trader1.to_owned(),
+        axis_contract.to_owned(),
+        &AxisExecuteMsg::RegisterAirDrop {
+            air_drop_contract: airdrop_contract.to_string(),
+            amount: Uint128::new(3_000_000),
+        },
+        &vec![],
+    );
+    assert!(register_result.is_err());
+    app.execute_contract(
+        admin.to_owned(),
+        axis_contract.to_owned(),
+        &AxisExecuteMsg::RegisterAirDrop {
+            air_drop_contract: airdrop_contract.to_string(),
+            amount: Uint128::new(3_000_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let community_res: CommunityPoolResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &AxisQueryMsg::GetCommunityPool {})
+        .unwrap();
+    assert_eq!(
+        community_res.community_pool_amount,
+        Uint128::new(200_000_000_000_000 - 3_000_000)
+    );
+
+    //@@ invalid amount does not verify
+    let claim_result = app.execute_contract(
+        trader1.to_owned(),
+        airdrop_contract.to_owned(),
+        &ExecuteMsg::Claim {
+            amount: Uint128::new(2_000_000),
+            proof: vec![hex::encode(trader2_leaf)],
+        },
+        &vec![],
+    );
+    assert!(claim_result.is_err());
+
+    app.execute_contract(
+        trader1.to_owned(),
+        airdrop_contract.to_owned(),
+        &ExecuteMsg::Claim {
+            amount: Uint128::new(1_000_000),
+            proof: vec![hex::encode(trader2_leaf)],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let axis_config_res: AxisConfigResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &AxisQueryMsg::GetConfig {})
+        .unwrap();
+    let trader1_axis = app
+        .wrap()
+        .query_balance(trader1.to_owned(), axis_config_res.axis_denom)
+        .unwrap();
+    let trader1_es_axis: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &EsAxisQueryMsg::GetBalance {
+                address: trader1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(trader1_axis.amount, Uint128::new(500_000));
+    assert_eq!(trader1_es_axis, Uint128::new(500_000));
+
+    let claimed_res: ClaimedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            airdrop_contract.to_owned(),
+            &QueryMsg::IsClaimed {
+                address: TRADER1.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(claimed_res.claimed);
+    assert_eq!(claimed_res.amount, Uint128::new(1_000_000));
+
+    let claim_again_result = app.execute_contract(
+        trader1.to_owned(),
+        airdrop_contract.to_owned(),
+        &ExecuteMsg::Claim {
+            amount: Uint128::new(1_000_000),
+            proof: vec![hex::encode(trader2_leaf)],
+        },
+        &vec![],
+    );
+    assert!(claim_again_result.is_err());
+
+    let expire_result = app.execute_contract(
+        trader2.to_owned(),
+        airdrop_contract.to_owned(),
+        &ExecuteMsg::Expire {},
+        &vec![],
+    );
+    assert!(expire_result.is_err());
+
+    //@@ after expiry unclaimed amount goes back to the community pool
+    app.update_block(|block| block.time = block.time.plus_seconds(1000));
+    let claim_result = app.execute_contract(
+        trader2.to_owned(),
+        airdrop_contract.to_owned(),
+        &ExecuteMsg::Claim {
+            amount: Uint128::new(2_000_000),
+            proof: vec![hex::encode(trader1_leaf)],
+        },
+        &vec![],
+    );
+    assert!(claim_result.is_err());
+
+    app.execute_contract(
+        trader2,
+        airdrop_contract.to_owned(),
+        &ExecuteMsg::Expire {},
+        &vec![],
+    )
+    .unwrap();
+
+    let state_res: StateResponse = app
+        .wrap()
+        .query_wasm_smart(airdrop_contract, &QueryMsg::GetState {})
+        .unwrap();
+    assert!(state_res.expired);
+    assert_eq!(state_res.total_claimed, Uint128::new(1_000_000));
+
+    let community_res: CommunityPoolResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract, &AxisQueryMsg::GetCommunityPool {})
+        .unwrap();
+    assert_eq!(
+        community_res.community_pool_amount,
+        Uint128::new(200_000_000_000_000 - 1_000_000)
+    );
+}
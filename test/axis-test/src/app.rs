@@ -13,13 +13,19 @@ use cw_multi_test::{
 };
 
 use axis_protocol::{
-    axis::{ConfigResponse as AxisConfigResponse, QueryMsg as AxisQueryMsg},
+    axis::{
+        ConfigResponse as AxisConfigResponse, ExecuteMsg as AxisExecuteMsg,
+        QueryMsg as AxisQueryMsg, VestingClock, VestingParams,
+    },
     core::{
         ConfigResponse as CoreConfigResponse, ExecuteMsg as CoreExecuteMsg,
         InstantiateMsg as CoreInstantiateMsg, PairLpStakingContractResponse,
         PairMarketContractResponse, PairPoolContractResponse, QueryMsg as CoreQueryMsg,
     },
-    es_axis::{ConfigResponse as EsAxisConfigResponse, QueryMsg as EsAxisQueryMsg},
+    es_axis::{
+        ConfigResponse as EsAxisConfigResponse, ExecuteMsg as EsAxisExecuteMsg,
+        QueryMsg as EsAxisQueryMsg,
+    },
     market::InstantiateMsg as MarketInstantiateMsg,
     pool::InstantiateMsg as PoolInstantiateMsg,
     staking::{
@@ -48,6 +54,7 @@ use pool::contract::{
 };
 use vault::contract::{
     execute as vault_execute, instantiate as vault_instantiate, query as vault_query,
+    reply as vault_reply,
 };
 
 use staking::contract::{
@@ -205,11 +212,14 @@ pub fn setup_init(
         .with_reply(pool_reply),
     ));
 
-    let vault_code = app.store_code(Box::new(ContractWrapper::new(
-        vault_execute,
-        vault_instantiate,
-        vault_query,
-    )));
+    let vault_code = app.store_code(Box::new(
+        Box::new(ContractWrapper::new(
+            vault_execute,
+            vault_instantiate,
+            vault_query,
+        ))
+        .with_reply(vault_reply),
+    ));
     let staking_code = app.store_code(Box::new(
         Box::new(ContractWrapper::new(
             staking_execute,
@@ -250,6 +260,17 @@ pub fn setup_init(
                 accept_price_denoms: vec![USDC_DENOM.to_string()],
                 axis_code_id: axis_code,
                 next_update_timestamp: 10,
+                emission_schedule: None,
+                //@@ foundation amount is fully vested at once in tests
+                foundation_vesting: Some(VestingParams {
+                    clock: VestingClock::Time,
+                    start: None,
+                    cliff: 0,
+                    duration: 0,
+                    revocable: false,
+                }),
+                referral_tiers: None,
+                fee_tiers: None,
             },
             &vec![],
             "Axis Core",
@@ -263,6 +284,14 @@ pub fn setup_init(
         .unwrap();
     let axis_contract = core_config_res.axis_contract;
 
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        axis_contract.to_owned(),
+        &AxisExecuteMsg::ClaimVested {},
+        &vec![],
+    )
+    .unwrap();
+
     let axis_config_res: AxisConfigResponse = app
         .wrap()
         .query_wasm_smart(axis_contract.to_owned(), &AxisQueryMsg::GetConfig {})
@@ -275,6 +304,7 @@ pub fn setup_init(
                 core_contract: core_contract.to_owned(),
                 axis_denom: axis_config_res.axis_denom,
                 es_axis_code,
+                es_axis_vesting_config: None,
             },
             &vec![],
             "axis contract",
@@ -300,12 +330,35 @@ pub fn setup_init(
                 es_axis_contract: es_axis_contract.to_string(),
                 es_axis_denom,
                 denom_list: vec![USDC_DENOM.to_string(), BTC_DENOM.to_string()],
+                staker_yield_rate: None,
+                yield_denom: None,
+                buyback_rate: None,
             },
             &vec![],
             "axis_vault",
             None,
         )
         .unwrap();
+    //@@ vault receives esAXIS swaps
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        es_axis_contract.to_owned(),
+        &EsAxisExecuteMsg::AddMover {
+            mover: vault_contract.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    //@@ es axis mints vested AXIS
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        axis_contract.to_owned(),
+        &AxisExecuteMsg::RegisterMinter {
+            minter: es_axis_contract.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
     //@@ core setting
     app.execute_contract(
         Addr::unchecked(ADMIN),
@@ -340,6 +393,10 @@ pub fn setup_init(
                     limit_profit_loss_open_fee_rate: 2,
                     axis_contract: axis_contract.to_owned(),
                     vault_contract: vault_contract.to_owned(),
+                    adl_threshold: 50,
+                    adl_close_rate: 50,
+                    price_breaker_rate: 30,
+                    nav_breaker_rate: 30,
                 },
                 lp_staking_code_id: lp_staking_code,
                 maker: Addr::unchecked(ADMIN),
@@ -426,6 +483,39 @@ pub fn init_exchange_rates() -> Vec<DenomOracleExchangeRatePair> {
     ]
 }
 
+//@@ replaces the oracle price of one denom, the other rates stay as init_exchange_rates.
+//@@ a denom without an init rate is added
+pub fn update_exchange_rate(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MockStorage,
+        SeiModule,
+        WasmKeeper<SeiMsg, SeiQueryWrapper>,
+        StakeKeeper,
+        DistributionKeeper,
+        FailingModule<IbcMsg, IbcQuery, Empty>,
+        FailingModule<GovMsg, Empty, Empty>,
+    >,
+    denom: &str,
+    exchange_rate: &str,
+) {
+    let mut rates: Vec<DenomOracleExchangeRatePair> = init_exchange_rates()
+        .into_iter()
+        .filter(|rate| rate.denom != denom)
+        .collect();
+    rates.push(DenomOracleExchangeRatePair {
+        denom: denom.to_string(),
+        oracle_exchange_rate: OracleExchangeRate {
+            exchange_rate: Decimal::from_str(exchange_rate).unwrap(),
+            last_update: Uint64::zero(),
+        },
+    });
+    app.init_modules(|router, _, _| {
+        router.custom = SeiModule::new_with_oracle_exchange_rates(rates);
+    });
+}
+
 pub fn create_pair(
     app: &mut App<
         BankKeeper,
@@ -444,6 +534,43 @@ pub fn create_pair(
     price_denom: &str,
     base_amount: u128,
     price_amount: u128,
+) -> Result<Addr, Error> {
+    let result = create_pair_with_adl(
+        app,
+        sender,
+        contracts,
+        base_denom,
+        price_denom,
+        base_amount,
+        price_amount,
+        50,
+        50,
+    );
+    assert!(result.is_ok());
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_pair_with_adl(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MockStorage,
+        SeiModule,
+        WasmKeeper<SeiMsg, SeiQueryWrapper>,
+        StakeKeeper,
+        DistributionKeeper,
+        FailingModule<IbcMsg, IbcQuery, Empty>,
+        FailingModule<GovMsg, Empty, Empty>,
+    >,
+    sender: &Addr,
+    contracts: &Contracts,
+    base_denom: &str,
+    price_denom: &str,
+    base_amount: u128,
+    price_amount: u128,
+    adl_threshold: u8,
+    adl_close_rate: u8,
 ) -> Result<Addr, Error> {
     let pool_code = app.store_code(Box::new(
         Box::new(ContractWrapper::new(
@@ -463,7 +590,7 @@ pub fn create_pair(
         lp_staking_instantiate,
         lp_staking_query,
     )));
-    let result = app.execute_contract(
+    app.execute_contract(
         sender.to_owned(),
         contracts.core_contract.to_owned(),
         &CoreExecuteMsg::CreatePair {
@@ -486,6 +613,10 @@ pub fn create_pair(
                     limit_profit_loss_open_fee_rate: 2,
                     axis_contract: contracts.axis_contract.to_owned(),
                     vault_contract: contracts.vault_contract.to_owned(),
+                    adl_threshold,
+                    adl_close_rate,
+                    price_breaker_rate: 30,
+                    nav_breaker_rate: 30,
                 },
                 lp_staking_code_id: lp_staking_code,
                 maker: sender.to_owned(),
@@ -497,8 +628,7 @@ pub fn create_pair(
             coin(base_amount, base_denom),
             coin(price_amount, price_denom),
         ],
-    );
-    assert!(result.is_ok());
+    )?;
     let core_res: PairPoolContractResponse = app
         .wrap()
         .query_wasm_smart(
@@ -0,0 +1,375 @@
+use cosmwasm_std::{coin, Addr, Decimal, Uint128};
+use cw_multi_test::{ContractWrapper, Executor};
+use sei_integration_tests::helper::mock_app;
+
+use crate::{
+    app::{
+        init_default_balances, init_exchange_rates, setup_init, update_exchange_rate, Contracts,
+        ADMIN, BTC_DENOM, TRADER1, USDC_DENOM,
+    },
+    mock_router::{mock_router_contract, InstantiateMsg as RouterInstantiateMsg},
+    utils::setting,
+};
+use auto_compounder::contract::{
+    execute as compounder_execute, instantiate as compounder_instantiate, query as compounder_query,
+};
+use axis_protocol::{
+    auto_compounder::{
+        ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg, SharePriceResponse,
+        ShareValueResponse, StateResponse, UnbondingResponse,
+    },
+    axis::{
+        ConfigResponse as AxisConfigResponse, ExecuteMsg as AxisExecuteMsg,
+        QueryMsg as AxisQueryMsg,
+    },
+    lp_staking::{QueryMsg as LpStakingQueryMsg, StakeInfoResponse},
+    pool::{ConfigResponse as PoolConfigResponse, QueryMsg as PoolQueryMsg},
+};
+
+#[test]
+fn compound_and_unbond() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let Contracts {
+        market_contract,
+        axis_contract,
+        lp_staking_contract,
+        pool_contract,
+        core_contract,
+        ..
+    } = contracts;
+    let lp_denom = app
+        .wrap()
+        .query_wasm_smart::<PoolConfigResponse>(pool_contract, &PoolQueryMsg::GetConfig {})
+        .unwrap()
+        .lp_denom;
+    let axis_denom = app
+        .wrap()
+        .query_wasm_smart::<AxisConfigResponse>(
+            axis_contract.to_owned(),
+            &AxisQueryMsg::GetConfig {},
+        )
+        .unwrap()
+        .axis_denom;
+    update_exchange_rate(&mut app, &axis_denom, "1");
+
+    let router_code = app.store_code(mock_router_contract());
+    let router_contract = app
+        .instantiate_contract(
+            router_code,
+            admin.to_owned(),
+            &RouterInstantiateMsg {
+                return_rate: Decimal::one(),
+            },
+            &vec![],
+            "mock_router",
+            None,
+        )
+        .unwrap();
+    app.send_tokens(
+        admin.to_owned(),
+        router_contract.to_owned(),
+        &vec![
+            coin(1_000_000_000_000_000, BTC_DENOM),
+            coin(1_000_000_000_000_000, USDC_DENOM),
+        ],
+    )
+    .unwrap();
+
+    let compounder_code = app.store_code(Box::new(ContractWrapper::new(
+        compounder_execute,
+        compounder_instantiate,
+        compounder_query,
+    )));
+    let compounder_contract = app
+        .instantiate_contract(
+            compounder_code,
+            admin.to_owned(),
+            &InstantiateMsg {
+                core_contract: core_contract.to_owned(),
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                router_contract: router_contract.to_string(),
+                max_slippage: None,
+            },
+            &vec![],
+            "auto compounder",
+            None,
+        )
+        .unwrap();
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(compounder_contract.to_owned(), &QueryMsg::GetConfig {})
+        .unwrap();
+    assert_eq!(config.lp_staking_contract, lp_staking_contract);
+    let share_denom = config.share_denom;
+
+    //@@ only lp is accepted
+    let result = app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Deposit {},
+        &vec![coin(1_000, USDC_DENOM)],
+    );
+    assert!(result.is_err());
+
+    //@@ too small for the locked shares of the first deposit
+    let result = app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Deposit {},
+        &vec![coin(1_000, &lp_denom)],
+    );
+    assert!(result.is_err());
+
+    //@@ the first deposit mints one share per lp , 1_000 of them are locked
+    let admin_lp = app.wrap().query_balance(&admin, &lp_denom).unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Deposit {},
+        &vec![admin_lp.to_owned()],
+    )
+    .unwrap();
+    let admin_shares = app.wrap().query_balance(&admin, &share_denom).unwrap();
+    assert_eq!(admin_shares.amount, admin_lp.amount - Uint128::new(1_000));
+
+    //@@ epoch 0 is minted before the stake is active , epoch 1 is all for the compounder
+    for _ in 0..2 {
+        app.execute_contract(
+            market_contract.to_owned(),
+            axis_contract.to_owned(),
+            &AxisExecuteMsg::AddFeeAmount {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                trader: trader.to_owned(),
+                fee_usd_amount: Uint128::new(100),
+                referrer: None,
+                referrer_fee_usd_amount: Uint128::zero(),
+            },
+            &vec![],
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &admin).unwrap();
+    }
+
+    //@@ the steps can't be called from outside
+    let result = app.execute_contract(
+        trader.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::SwapReward {},
+        &vec![],
+    );
+    assert!(result.is_err());
+
+    //@@ anyone compounds , once per epoch
+    app.execute_contract(
+        trader.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Compound {},
+        &vec![],
+    )
+    .unwrap();
+    let result = app.execute_contract(
+        trader.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Compound {},
+        &vec![],
+    );
+    assert!(result.is_err());
+
+    let axis_left = app
+        .wrap()
+        .query_balance(&compounder_contract, &axis_denom)
+        .unwrap();
+    assert!(axis_left.amount.is_zero());
+    let share_price: SharePriceResponse = app
+        .wrap()
+        .query_wasm_smart(compounder_contract.to_owned(), &QueryMsg::GetSharePrice {})
+        .unwrap();
+    assert!(share_price.total_lp > admin_lp.amount);
+    assert_eq!(share_price.total_shares, admin_lp.amount);
+    assert_eq!(
+        share_price.share_price,
+        Decimal::from_ratio(share_price.total_lp, share_price.total_shares)
+    );
+    let stake_infos: Vec<StakeInfoResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &LpStakingQueryMsg::GetStakeInfo {
+                address: compounder_contract.to_owned(),
+            },
+        )
+        .unwrap();
+    let staked_lp: Uint128 = stake_infos.iter().map(|s| s.staking_amount).sum();
+    assert_eq!(staked_lp, share_price.total_lp);
+
+    //@@ a quarter of the shares is unbonded at the share price
+    let unbond_shares = admin_shares.amount.multiply_ratio(1u128, 4u128);
+    let unbond_lp = unbond_shares.multiply_ratio(share_price.total_lp, share_price.total_shares);
+    let share_value: ShareValueResponse = app
+        .wrap()
+        .query_wasm_smart(
+            compounder_contract.to_owned(),
+            &QueryMsg::GetShareValue {
+                address: admin.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        share_value.lp_amount,
+        admin_shares
+            .amount
+            .multiply_ratio(share_price.total_lp, share_price.total_shares)
+    );
+    app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Unbond {},
+        &vec![coin(unbond_shares.u128(), share_denom.to_owned())],
+    )
+    .unwrap();
+    let unbondings: Vec<UnbondingResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            compounder_contract.to_owned(),
+            &QueryMsg::GetUnbonding {
+                address: admin.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        unbondings,
+        vec![UnbondingResponse {
+            unlock_epoch: 3,
+            lp_amount: unbond_lp,
+        }]
+    );
+    let stake_infos: Vec<StakeInfoResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &LpStakingQueryMsg::GetStakeInfo {
+                address: compounder_contract.to_owned(),
+            },
+        )
+        .unwrap();
+    let staked_lp: Uint128 = stake_infos.iter().map(|s| s.staking_amount).sum();
+    assert_eq!(staked_lp, share_price.total_lp - unbond_lp);
+
+    //@@ withdrawable after the unlock epoch
+    let result = app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Withdraw {},
+        &vec![],
+    );
+    assert!(result.is_err());
+    for _ in 0..2 {
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &admin).unwrap();
+    }
+    app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Withdraw {},
+        &vec![],
+    )
+    .unwrap();
+    let withdrawn_lp = app.wrap().query_balance(&admin, &lp_denom).unwrap();
+    assert_eq!(withdrawn_lp.amount, unbond_lp);
+
+    let state: StateResponse = app
+        .wrap()
+        .query_wasm_smart(compounder_contract.to_owned(), &QueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.unbonding_total, Uint128::zero());
+    assert_eq!(state.total_lp, share_price.total_lp - unbond_lp);
+    assert_eq!(state.total_shares, share_price.total_shares - unbond_shares);
+
+    //@@ lp deposited this epoch is still pending in lp-staking , its unbond is queued
+    let active_lp = state.total_lp;
+    app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Deposit {},
+        &vec![withdrawn_lp.to_owned()],
+    )
+    .unwrap();
+    let admin_shares = app.wrap().query_balance(&admin, &share_denom).unwrap();
+    let share_value: ShareValueResponse = app
+        .wrap()
+        .query_wasm_smart(
+            compounder_contract.to_owned(),
+            &QueryMsg::GetShareValue {
+                address: admin.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(share_value.lp_amount > active_lp);
+    app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Unbond {},
+        &vec![admin_shares.to_owned()],
+    )
+    .unwrap();
+    let unbondings: Vec<UnbondingResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            compounder_contract.to_owned(),
+            &QueryMsg::GetUnbonding {
+                address: admin.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        unbondings,
+        vec![
+            UnbondingResponse {
+                unlock_epoch: 5,
+                lp_amount: active_lp,
+            },
+            UnbondingResponse {
+                unlock_epoch: 6,
+                lp_amount: share_value.lp_amount - active_lp,
+            },
+        ]
+    );
+
+    //@@ the next epoch unstakes the queue , each part is withdrawn after its unlock epoch
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Withdraw {},
+        &vec![],
+    )
+    .unwrap();
+    let result = app.execute_contract(
+        admin.to_owned(),
+        compounder_contract.to_owned(),
+        &ExecuteMsg::Withdraw {},
+        &vec![],
+    );
+    assert!(result.is_err());
+    for lp_amount in [active_lp, share_value.lp_amount] {
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &admin).unwrap();
+        app.execute_contract(
+            admin.to_owned(),
+            compounder_contract.to_owned(),
+            &ExecuteMsg::Withdraw {},
+            &vec![],
+        )
+        .unwrap();
+        let withdrawn_lp = app.wrap().query_balance(&admin, &lp_denom).unwrap();
+        assert_eq!(withdrawn_lp.amount, lp_amount);
+    }
+}
@@ -1,18 +1,21 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{coin, Addr, Decimal, Uint128};
 use cw_multi_test::Executor;
 use sei_integration_tests::helper::mock_app;
 
 use crate::{
     app::{
-        init_default_balances, init_exchange_rates, setup_init, Contracts, ADMIN, BTC_DENOM,
-        TRADER1, USDC_DENOM,
+        create_pair, init_default_balances, init_exchange_rates, setup_init, Contracts, ADMIN,
+        BTC_DENOM, ETH_DENOM, TRADER1, TRADER2, USDC_DENOM,
     },
-    utils::setting,
+    utils::{setting, staking},
 };
 
 use axis_protocol::{
     axis::{
-        ConfigResponse, ExecuteMsg, PendingFeeResponse, PoolAllowedMintAmountResponse, QueryMsg,
+        ConfigResponse, EmissionRateResponse, ExecuteMsg, GaugeVote, GaugeWeightResponse,
+        PendingFeeResponse, PoolAllowedMintAmountResponse, QueryMsg, RemainingEmissionResponse,
+        TraderClaimableResponse, VestingClock, VestingParams, VestingScheduleResponse,
+        VoteResponse,
     },
     lp_staking::{ExecuteMsg as LpStakingExeucteMsg, QueryMsg as LpStakingQueryMsg},
     market::{GetConfigResponse as MarketConfigResponse, QueryMsg as MarketQueryMsg},
@@ -42,6 +45,8 @@ fn test_add_fee_amount() {
             price_denom: price_denom.to_owned(),
             trader: trader1.to_owned(),
             fee_usd_amount: Uint128::new(100),
+            referrer: None,
+            referrer_fee_usd_amount: Uint128::zero(),
         },
         &vec![],
     );
@@ -56,6 +61,8 @@ fn test_add_fee_amount() {
             price_denom: price_denom.to_owned(),
             trader: trader1,
             fee_usd_amount: Uint128::new(100),
+            referrer: None,
+            referrer_fee_usd_amount: Uint128::zero(),
         },
         &vec![],
     );
@@ -94,6 +101,8 @@ fn test_claim_minting_trader() {
             price_denom: price_denom.to_owned(),
             trader: trader1.to_owned(),
             fee_usd_amount: Uint128::new(100),
+            referrer: None,
+            referrer_fee_usd_amount: Uint128::zero(),
         },
         &vec![],
     );
@@ -106,11 +115,36 @@ fn test_claim_minting_trader() {
 
     assert!(setting_result.is_ok());
 
+    //@@ no fee record is a zero claim
+    let claim_mint_trader_result = app.execute_contract(
+        Addr::unchecked(TRADER2),
+        axis_contract.to_owned(),
+        &ExecuteMsg::ClaimMintTrader {
+            start_epoch: None,
+            end_epoch: None,
+        },
+        &vec![],
+    );
+    assert!(claim_mint_trader_result.is_ok());
+
+    let claimable_res: TraderClaimableResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetTraderClaimable {
+                address: TRADER1.to_string(),
+            },
+        )
+        .unwrap();
+
     //@@Valid
     let claim_mint_trader_result = app.execute_contract(
         trader1.to_owned(),
         axis_contract.to_owned(),
-        &ExecuteMsg::ClaimMintTrader {},
+        &ExecuteMsg::ClaimMintTrader {
+            start_epoch: None,
+            end_epoch: None,
+        },
         &vec![],
     );
 
@@ -131,7 +165,19 @@ fn test_claim_minting_trader() {
     assert_eq!(
         trader_axis.amount,
         axis_config_res.mint_per_epoch_trader_amount
-    )
+    );
+
+    assert_eq!(claimable_res.claimable_amount, trader_axis.amount);
+
+    //@@ foundation 20% + community 20% + trader claim
+    let remaining_res: RemainingEmissionResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract, &QueryMsg::GetRemainingEmission {})
+        .unwrap();
+    assert_eq!(
+        remaining_res.total_minted,
+        remaining_res.max_total_supply * Decimal::percent(40) + trader_axis.amount
+    );
 }
 
 #[test]
@@ -164,7 +210,7 @@ pub fn test_claim_minting_maker() {
     let lp_staking_result = app.execute_contract(
         admin.to_owned(),
         lp_staking_contract.to_owned(),
-        &LpStakingExeucteMsg::Staking {},
+        &LpStakingExeucteMsg::Staking { lock_epochs: None },
         &vec![admin_lp.to_owned()],
     );
     assert!(lp_staking_result.is_ok());
@@ -184,6 +230,8 @@ pub fn test_claim_minting_maker() {
             price_denom: price_denom.to_owned(),
             trader: trader1.to_owned(),
             fee_usd_amount: Uint128::new(100),
+            referrer: None,
+            referrer_fee_usd_amount: Uint128::zero(),
         },
         &vec![],
     );
@@ -258,3 +306,594 @@ pub fn test_claim_minting_maker() {
         Uint128::new(200_000_000_000_000) + mintable_amount
     )
 }
+
+#[test]
+fn test_emission_schedule() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let axis_contract = contracts.axis_contract;
+
+    let current_rate: EmissionRateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetEmissionRate { epoch: None },
+        )
+        .unwrap();
+    assert_eq!(current_rate.epoch, 0);
+    assert_eq!(
+        current_rate.trader_amount,
+        Uint128::new(300_000_000_000_000 / (365 * 5))
+    );
+
+    //@@ default schedule halves every 365 epochs
+    let halved_rate: EmissionRateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetEmissionRate { epoch: Some(365) },
+        )
+        .unwrap();
+    assert_eq!(
+        halved_rate.trader_amount,
+        current_rate.trader_amount / Uint128::new(2)
+    );
+    assert_eq!(
+        halved_rate.maker_amount,
+        current_rate.maker_amount / Uint128::new(2)
+    );
+
+    let remaining_res: RemainingEmissionResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract, &QueryMsg::GetRemainingEmission {})
+        .unwrap();
+    assert_eq!(
+        remaining_res.remaining_amount,
+        remaining_res.max_total_supply * Decimal::percent(60)
+    );
+}
+
+#[test]
+fn test_vesting_claim_and_revoke() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let axis_contract = contracts.axis_contract;
+    let admin = Addr::unchecked(ADMIN);
+    let trader1 = Addr::unchecked(TRADER1);
+
+    //@@ foundation amount was claimed in setup
+    let foundation_res: VestingScheduleResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetVestingSchedule {
+                address: ADMIN.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        foundation_res.claimed_amount,
+        Uint128::new(200_000_000_000_000)
+    );
+    assert_eq!(foundation_res.claimable_amount, Uint128::zero());
+
+    let axis_config_res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &QueryMsg::GetConfig {})
+        .unwrap();
+    let axis_denom = axis_config_res.axis_denom;
+    let team_params = VestingParams {
+        clock: VestingClock::Time,
+        start: None,
+        cliff: 100,
+        duration: 1000,
+        revocable: true,
+    };
+
+    //@@ only the owner can create a vesting
+    let unauthorized_result = app.execute_contract(
+        trader1.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::CreateVesting {
+            beneficiary: TRADER1.to_string(),
+            params: team_params.to_owned(),
+        },
+        &vec![],
+    );
+    assert!(unauthorized_result.is_err());
+
+    let create_result = app.execute_contract(
+        admin.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::CreateVesting {
+            beneficiary: TRADER1.to_string(),
+            params: team_params,
+        },
+        &vec![coin(1_000_000, axis_denom.to_owned())],
+    );
+    assert!(create_result.is_ok());
+
+    //@@ nothing before the cliff
+    app.update_block(|block| block.time = block.time.plus_seconds(50));
+    app.execute_contract(
+        trader1.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::ClaimVested {},
+        &vec![],
+    )
+    .unwrap();
+    let trader_axis = app
+        .wrap()
+        .query_balance(trader1.to_owned(), axis_denom.to_owned())
+        .unwrap();
+    assert_eq!(trader_axis.amount, Uint128::zero());
+
+    app.update_block(|block| block.time = block.time.plus_seconds(450));
+    app.execute_contract(
+        trader1.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::ClaimVested {},
+        &vec![],
+    )
+    .unwrap();
+    let trader_axis = app
+        .wrap()
+        .query_balance(trader1.to_owned(), axis_denom.to_owned())
+        .unwrap();
+    assert_eq!(trader_axis.amount, Uint128::new(500_000));
+
+    let before_admin_axis = app
+        .wrap()
+        .query_balance(admin.to_owned(), axis_denom.to_owned())
+        .unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::RevokeVesting {
+            beneficiary: TRADER1.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let after_admin_axis = app
+        .wrap()
+        .query_balance(admin.to_owned(), axis_denom.to_owned())
+        .unwrap();
+    assert_eq!(
+        after_admin_axis.amount - before_admin_axis.amount,
+        Uint128::new(500_000)
+    );
+
+    //@@ nothing vests after revoke
+    app.update_block(|block| block.time = block.time.plus_seconds(1000));
+    let vesting_res: VestingScheduleResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetVestingSchedule {
+                address: TRADER1.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(vesting_res.revoked);
+    assert_eq!(vesting_res.total_amount, Uint128::new(500_000));
+    assert_eq!(vesting_res.claimable_amount, Uint128::zero());
+
+    let revoke_again_result = app.execute_contract(
+        admin,
+        axis_contract,
+        &ExecuteMsg::RevokeVesting {
+            beneficiary: TRADER1.to_string(),
+        },
+        &vec![],
+    );
+    assert!(revoke_again_result.is_err());
+}
+
+#[test]
+fn test_claim_minting_trader_range() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let market_contract = contracts.market_contract;
+    let axis_contract = contracts.axis_contract;
+    let core_contract = contracts.core_contract;
+    let admin = Addr::unchecked(ADMIN);
+    let trader1 = Addr::unchecked(TRADER1);
+
+    //@@ trader1 pays fees in epoch 0 and epoch 1
+    for _ in 0..2 {
+        app.execute_contract(
+            market_contract.to_owned(),
+            axis_contract.to_owned(),
+            &ExecuteMsg::AddFeeAmount {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                trader: trader1.to_owned(),
+                fee_usd_amount: Uint128::new(100),
+                referrer: None,
+                referrer_fee_usd_amount: Uint128::zero(),
+            },
+            &vec![],
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &admin).unwrap();
+    }
+
+    let claimable_res: TraderClaimableResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetTraderClaimable {
+                address: TRADER1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(claimable_res.start_epoch, Some(0));
+    assert_eq!(claimable_res.end_epoch, Some(1));
+
+    let axis_config_res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &QueryMsg::GetConfig {})
+        .unwrap();
+    let epoch_amount = axis_config_res.mint_per_epoch_trader_amount;
+    assert_eq!(
+        claimable_res.claimable_amount,
+        epoch_amount * Uint128::new(2)
+    );
+
+    app.execute_contract(
+        trader1.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::ClaimMintTrader {
+            start_epoch: None,
+            end_epoch: Some(0),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let trader_axis = app
+        .wrap()
+        .query_balance(trader1.to_owned(), axis_config_res.axis_denom.to_owned())
+        .unwrap();
+    assert_eq!(trader_axis.amount, epoch_amount);
+
+    //@@ epoch 0 is gone , epoch 1 is left
+    let claimable_res: TraderClaimableResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetTraderClaimable {
+                address: TRADER1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(claimable_res.start_epoch, Some(1));
+    assert_eq!(claimable_res.claimable_amount, epoch_amount);
+
+    app.execute_contract(
+        trader1.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::ClaimMintTrader {
+            start_epoch: None,
+            end_epoch: None,
+        },
+        &vec![],
+    )
+    .unwrap();
+    let trader_axis = app
+        .wrap()
+        .query_balance(trader1, axis_config_res.axis_denom)
+        .unwrap();
+    assert_eq!(trader_axis.amount, epoch_amount * Uint128::new(2));
+}
+
+pub fn gauge_vote(base_denom: &str, price_denom: &str, weight: u8) -> GaugeVote {
+    GaugeVote {
+        base_denom: base_denom.to_string(),
+        price_denom: price_denom.to_string(),
+        weight,
+    }
+}
+
+#[test]
+fn test_gauge_vote() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let admin = Addr::unchecked(ADMIN);
+    let trader1 = Addr::unchecked(TRADER1);
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    create_pair(
+        &mut app, &admin, &contracts, ETH_DENOM, USDC_DENOM, 1_000_000, 1_000_000,
+    )
+    .unwrap();
+    let Contracts {
+        market_contract,
+        axis_contract,
+        core_contract,
+        staking_contract,
+        ..
+    } = contracts;
+    let axis_config_res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &QueryMsg::GetConfig {})
+        .unwrap();
+    assert_eq!(axis_config_res.vote_weight_rate, 50);
+
+    //@@ no staked AXIS
+    let result = app.execute_contract(
+        trader1.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::Vote {
+            votes: vec![gauge_vote(ETH_DENOM, USDC_DENOM, 100)],
+        },
+        &vec![],
+    );
+    assert!(result.is_err());
+
+    let stake_amount = Uint128::new(1_000_000);
+    staking(
+        &mut app,
+        &staking_contract,
+        &admin,
+        &vec![coin(
+            stake_amount.u128(),
+            axis_config_res.axis_denom.to_owned(),
+        )],
+    )
+    .unwrap();
+
+    //@@ over 100 , duplicated pair , unknown pair
+    for votes in [
+        vec![
+            gauge_vote(ETH_DENOM, USDC_DENOM, 60),
+            gauge_vote(BTC_DENOM, USDC_DENOM, 50),
+        ],
+        vec![
+            gauge_vote(ETH_DENOM, USDC_DENOM, 30),
+            gauge_vote(ETH_DENOM, USDC_DENOM, 30),
+        ],
+        vec![gauge_vote(BTC_DENOM, ETH_DENOM, 100)],
+    ] {
+        let result = app.execute_contract(
+            admin.to_owned(),
+            axis_contract.to_owned(),
+            &ExecuteMsg::Vote { votes },
+            &vec![],
+        );
+        assert!(result.is_err());
+    }
+
+    //@@ the second vote replaces the first
+    for votes in [
+        vec![gauge_vote(BTC_DENOM, USDC_DENOM, 100)],
+        vec![gauge_vote(ETH_DENOM, USDC_DENOM, 100)],
+    ] {
+        let result = app.execute_contract(
+            admin.to_owned(),
+            axis_contract.to_owned(),
+            &ExecuteMsg::Vote { votes },
+            &vec![],
+        );
+        assert!(result.is_ok());
+    }
+    let vote_res: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetVote {
+                address: admin.to_string(),
+                epoch: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(vote_res.epoch, 0);
+    assert_eq!(vote_res.voting_power, stake_amount);
+    assert_eq!(vote_res.votes, vec![gauge_vote(ETH_DENOM, USDC_DENOM, 100)]);
+
+    //@@ fees go to BTC only
+    app.execute_contract(
+        market_contract.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::AddFeeAmount {
+            base_denom: BTC_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+            trader: trader1.to_owned(),
+            fee_usd_amount: Uint128::new(100),
+            referrer: None,
+            referrer_fee_usd_amount: Uint128::zero(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let expected = vec![
+        GaugeWeightResponse {
+            base_denom: BTC_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+            fee_amount: Uint128::new(100),
+            vote_amount: Uint128::zero(),
+            weight: Decimal::percent(50),
+        },
+        GaugeWeightResponse {
+            base_denom: ETH_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+            fee_amount: Uint128::zero(),
+            vote_amount: stake_amount,
+            weight: Decimal::percent(50),
+        },
+    ];
+    let gauge_res: Vec<GaugeWeightResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetGaugeWeights { epoch: None },
+        )
+        .unwrap();
+    assert_eq!(gauge_res, expected);
+
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+
+    //@@ snapshot of the ended epoch
+    let gauge_res: Vec<GaugeWeightResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetGaugeWeights { epoch: Some(0) },
+        )
+        .unwrap();
+    assert_eq!(gauge_res, expected);
+    let gauge_res: Vec<GaugeWeightResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetGaugeWeights { epoch: None },
+        )
+        .unwrap();
+    assert!(gauge_res.is_empty());
+
+    let emission_res: EmissionRateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetEmissionRate { epoch: Some(0) },
+        )
+        .unwrap();
+    for base_denom in [BTC_DENOM, ETH_DENOM] {
+        let mint_res: Vec<PoolAllowedMintAmountResponse> = app
+            .wrap()
+            .query_wasm_smart(
+                axis_contract.to_owned(),
+                &QueryMsg::GetPoolAllowedMintAmount {
+                    base_denom: base_denom.to_string(),
+                    price_denom: USDC_DENOM.to_string(),
+                    start_epoch: 0,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            mint_res,
+            vec![PoolAllowedMintAmountResponse {
+                epoch: 1,
+                mint_amount: emission_res.maker_amount * Decimal::percent(50),
+            }]
+        );
+    }
+
+    //@@ owner only , at most 100
+    let result = app.execute_contract(
+        trader1.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::UpdateVoteWeightRate {
+            vote_weight_rate: 0,
+        },
+        &vec![],
+    );
+    assert!(result.is_err());
+    let result = app.execute_contract(
+        admin.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::UpdateVoteWeightRate {
+            vote_weight_rate: 101,
+        },
+        &vec![],
+    );
+    assert!(result.is_err());
+    let result = app.execute_contract(
+        admin.to_owned(),
+        axis_contract.to_owned(),
+        &ExecuteMsg::UpdateVoteWeightRate {
+            vote_weight_rate: 100,
+        },
+        &vec![],
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_maker_emission_with_referral() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let admin = Addr::unchecked(ADMIN);
+    let trader1 = Addr::unchecked(TRADER1);
+    let referrer = Addr::unchecked(TRADER2);
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let eth_pool_contract = create_pair(
+        &mut app, &admin, &contracts, ETH_DENOM, USDC_DENOM, 1_000_000, 1_000_000,
+    )
+    .unwrap();
+    let eth_market_contract = app
+        .wrap()
+        .query_wasm_smart::<PoolConfigResponse>(eth_pool_contract, &PoolQueryMsg::GetConfig {})
+        .unwrap()
+        .market_contract;
+    let Contracts {
+        market_contract,
+        axis_contract,
+        core_contract,
+        ..
+    } = contracts;
+
+    //@@ referrer fees count for the trader emissions , not for the pool share
+    for (market_contract, base_denom, fee_usd_amount, referrer_fee_usd_amount) in [
+        (market_contract, BTC_DENOM, 300, 100),
+        (eth_market_contract, ETH_DENOM, 100, 0),
+    ] {
+        app.execute_contract(
+            market_contract,
+            axis_contract.to_owned(),
+            &ExecuteMsg::AddFeeAmount {
+                base_denom: base_denom.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                trader: trader1.to_owned(),
+                fee_usd_amount: Uint128::new(fee_usd_amount),
+                referrer: Some(referrer.to_owned()),
+                referrer_fee_usd_amount: Uint128::new(referrer_fee_usd_amount),
+            },
+            &vec![],
+        )
+        .unwrap();
+    }
+    let pending_res: PendingFeeResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &QueryMsg::GetPendingTotalFee {})
+        .unwrap();
+    assert_eq!(pending_res.pending_total_fee, Uint128::new(500));
+
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+
+    let emission_res: EmissionRateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &QueryMsg::GetEmissionRate { epoch: Some(0) },
+        )
+        .unwrap();
+    let mut total_mint_amount = Uint128::zero();
+    for (base_denom, weight) in [(BTC_DENOM, 75), (ETH_DENOM, 25)] {
+        let mint_res: Vec<PoolAllowedMintAmountResponse> = app
+            .wrap()
+            .query_wasm_smart(
+                axis_contract.to_owned(),
+                &QueryMsg::GetPoolAllowedMintAmount {
+                    base_denom: base_denom.to_string(),
+                    price_denom: USDC_DENOM.to_string(),
+                    start_epoch: 0,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            mint_res,
+            vec![PoolAllowedMintAmountResponse {
+                epoch: 1,
+                mint_amount: emission_res.maker_amount * Decimal::percent(weight),
+            }]
+        );
+        total_mint_amount += mint_res[0].mint_amount;
+    }
+    //@@ only the floor of each pool share is left out
+    assert!(emission_res.maker_amount - total_mint_amount < Uint128::new(2));
+}
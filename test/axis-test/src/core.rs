@@ -27,7 +27,7 @@ use axis_protocol::{
     es_axis::{ConfigResponse as EsAxisConfigResponse, QueryMsg as EsAxisQueryMsg},
     market::InstantiateMsg as MarketInstantiateMsg,
     pool::{
-        ConfigResponse as PoolConfigResponse, InstantiateMsg as PoolInstantiateMsg,
+        ConfigResponse as PoolConfigResponse, InstantiateMsg as PoolInstantiateMsg, PauseMode,
         QueryMsg as PoolQueryMsg,
     },
     staking::{
@@ -102,7 +102,7 @@ fn register_price_denom() {
 }
 
 #[test]
-fn pool_lock_and_un_lock() {
+fn pool_set_mode() {
     let mut app = mock_app(init_default_balances, init_exchange_rates());
     let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
     let admin = Addr::unchecked(ADMIN);
@@ -114,11 +114,13 @@ fn pool_lock_and_un_lock() {
 
     assert!(result.is_ok());
 
-    //@@@Lock
+    //@@@Paused
     let result = app.execute_contract(
         admin.to_owned(),
         contracts.core_contract.to_owned(),
-        &ExecuteMsg::AllPoolLock {},
+        &ExecuteMsg::AllPoolSetMode {
+            mode: PauseMode::Paused,
+        },
         &vec![],
     );
     assert!(result.is_ok());
@@ -139,7 +141,7 @@ fn pool_lock_and_un_lock() {
         .wrap()
         .query_wasm_smart(pool_contract2.to_owned(), &PoolQueryMsg::GetConfig {})
         .unwrap();
-    assert_eq!(true, pool2_res.lock);
+    assert_eq!(PauseMode::Paused, pool2_res.mode);
     let pool_res: PoolConfigResponse = app
         .wrap()
         .query_wasm_smart(
@@ -147,13 +149,15 @@ fn pool_lock_and_un_lock() {
             &PoolQueryMsg::GetConfig {},
         )
         .unwrap();
-    assert_eq!(true, pool_res.lock);
+    assert_eq!(PauseMode::Paused, pool_res.mode);
 
-    //@@@@Unlock
+    //@@@@Active
     let result = app.execute_contract(
         admin.to_owned(),
         contracts.core_contract.to_owned(),
-        &ExecuteMsg::AllPoolUnLock {},
+        &ExecuteMsg::AllPoolSetMode {
+            mode: PauseMode::Active,
+        },
         &vec![],
     );
     assert!(result.is_ok());
@@ -162,7 +166,7 @@ fn pool_lock_and_un_lock() {
         .wrap()
         .query_wasm_smart(pool_contract2.to_owned(), &PoolQueryMsg::GetConfig {})
         .unwrap();
-    assert_eq!(false, pool2_res.lock);
+    assert_eq!(PauseMode::Active, pool2_res.mode);
     let pool_res: PoolConfigResponse = app
         .wrap()
         .query_wasm_smart(
@@ -170,7 +174,7 @@ fn pool_lock_and_un_lock() {
             &PoolQueryMsg::GetConfig {},
         )
         .unwrap();
-    assert_eq!(false, pool_res.lock);
+    assert_eq!(PauseMode::Active, pool_res.mode);
 }
 
 #[test]
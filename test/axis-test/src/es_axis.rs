@@ -1,11 +1,37 @@
-use cosmwasm_std::{Addr, Uint128};
-use cw_multi_test::Executor;
+use cosmwasm_std::{coin, to_binary, Addr, Binary, Uint128};
+use cw_multi_test::{ContractWrapper, Executor};
 use sei_integration_tests::helper::mock_app;
 
-use crate::app::{
-    init_default_balances, init_exchange_rates, setup_init, ADMIN, BTC_DENOM, USDC_DENOM,
+use crate::{
+    airdrop::leaf,
+    app::{
+        init_default_balances, init_exchange_rates, setup_init, ADMIN, BTC_DENOM, TRADER1,
+        USDC_DENOM,
+    },
+    mock_legacy_es_axis::{
+        es_axis_contract, mock_legacy_es_axis_contract, ExecuteMsg as LegacyExecuteMsg,
+        InstantiateMsg as LegacyInstantiateMsg,
+    },
+    utils::{recieved_fee, setting, staking, un_staking},
+};
+use airdrop::contract::{
+    execute as airdrop_execute, instantiate as airdrop_instantiate, query as airdrop_query,
+};
+use axis_protocol::{
+    airdrop::{ExecuteMsg as AirdropExecuteMsg, InstantiateMsg as AirdropInstantiateMsg},
+    axis::{
+        ConfigResponse as AxisConfigResponse, ExecuteMsg as AxisExecuteMsg, MinterResponse,
+        QueryMsg as AxisQueryMsg,
+    },
+    es_axis::{
+        ConfigResponse, ExecuteMsg, MigrateMsg, QueryMsg, SupplyInvariantResponse, VestingConfig,
+        VestingPositionsResponse,
+    },
+    vault::{
+        DenomBalanceResponse, ExecuteMsg as VaultExecuteMsg, QueryMsg as VaultQueryMsg,
+        ReceiveMsg as VaultReceiveMsg,
+    },
 };
-use axis_protocol::es_axis::{ConfigResponse, ExecuteMsg, QueryMsg};
 #[test]
 pub fn test_mint() {
     let mut app = mock_app(init_default_balances, init_exchange_rates());
@@ -41,14 +67,17 @@ pub fn test_mint() {
         .unwrap();
     assert_eq!(es_axis_config_res.es_axis_total_supply, Uint128::new(1000));
 
-    let es_axis_denom = es_axis_config_res.es_axis_denom;
-
-    let es_axis_balance = app
+    let es_axis_balance: Uint128 = app
         .wrap()
-        .query_balance(es_axis_contract.to_owned(), es_axis_denom.to_owned())
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: es_axis_contract.to_string(),
+            },
+        )
         .unwrap();
 
-    assert_eq!(es_axis_balance.amount, Uint128::new(1000));
+    assert_eq!(es_axis_balance, Uint128::new(1000));
 }
 
 #[test]
@@ -59,8 +88,7 @@ fn test_claim() {
     let staking_contract = contracts.staking_contract;
     let es_axis_contract = contracts.es_axis_contract;
     let addr1 = Addr::unchecked(ADMIN);
-    //claim is fail.
-    //Because mint function when core_setting.
+    //@@ nothing minted ahead , claim mints it
     let claim_result = app.execute_contract(
         staking_contract.to_owned(),
         es_axis_contract.to_owned(),
@@ -70,14 +98,13 @@ fn test_claim() {
         },
         &vec![],
     );
-    assert!(claim_result.is_err());
+    assert!(claim_result.is_ok());
     let es_axis_config_res: ConfigResponse = app
         .wrap()
         .query_wasm_smart(es_axis_contract.to_owned(), &QueryMsg::GetConfig {})
         .unwrap();
-    assert_eq!(es_axis_config_res.es_axis_total_supply, Uint128::new(0));
+    assert_eq!(es_axis_config_res.es_axis_total_supply, Uint128::new(10000));
 
-    let es_axis_denom = es_axis_config_res.es_axis_denom;
     //test mint
     let mint_result = app.execute_contract(
         staking_contract.to_owned(),
@@ -111,12 +138,38 @@ fn test_claim() {
     );
     assert!(claim_result.is_ok());
 
-    let addr1_es_axis = app
+    let addr1_es_axis: Uint128 = app
         .wrap()
-        .query_balance(addr1.to_owned(), es_axis_denom.to_owned())
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: addr1.to_string(),
+            },
+        )
         .unwrap();
 
-    assert_eq!(addr1_es_axis.amount.u128(), 1000)
+    assert_eq!(addr1_es_axis.u128(), 11000);
+
+    //@@ claim used the minted balance , supply only counts it once
+    let invariant: SupplyInvariantResponse = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetSupplyInvariant {},
+        )
+        .unwrap();
+    assert_eq!(invariant.es_axis_total_supply, Uint128::new(11000));
+    assert_eq!(invariant.ledger_supply, Uint128::new(11000));
+    assert!(invariant.is_consistent);
+
+    //@@ only bank esAXIS is redeemed into the ledger
+    let redeem_result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::RedeemBank {},
+        &vec![coin(1000, USDC_DENOM)],
+    );
+    assert!(redeem_result.is_err());
 }
 
 #[test]
@@ -134,7 +187,6 @@ fn test_burn() {
         .unwrap();
     assert_eq!(es_axis_config_res.es_axis_total_supply, Uint128::new(0));
 
-    let es_axis_denom = es_axis_config_res.es_axis_denom;
     //test mint
     let mint_result = app.execute_contract(
         staking_contract.to_owned(),
@@ -157,16 +209,34 @@ fn test_burn() {
     );
     assert!(claim_result.is_ok());
 
-    let addr1_es_axis = app
+    let addr1_es_axis: Uint128 = app
         .wrap()
-        .query_balance(addr1.to_owned(), es_axis_denom.to_owned())
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: addr1.to_string(),
+            },
+        )
         .unwrap();
 
+    //@@ Invalid burn more than balance
     let burn_result = app.execute_contract(
         addr1.to_owned(),
         es_axis_contract.to_owned(),
-        &ExecuteMsg::Burn {},
-        &vec![addr1_es_axis],
+        &ExecuteMsg::Burn {
+            amount: addr1_es_axis + Uint128::new(1),
+        },
+        &vec![],
+    );
+    assert!(burn_result.is_err());
+
+    let burn_result = app.execute_contract(
+        addr1.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Burn {
+            amount: addr1_es_axis,
+        },
+        &vec![],
     );
     assert!(burn_result.is_ok());
 
@@ -175,9 +245,524 @@ fn test_burn() {
         .query_wasm_smart(es_axis_contract.to_owned(), &QueryMsg::GetConfig {})
         .unwrap();
     assert_eq!(es_axis_config_res.es_axis_total_supply.u128(), 0);
-    let addr1_es_axis = app
+    let addr1_es_axis: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: addr1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(addr1_es_axis.u128(), 0)
+}
+
+#[test]
+fn test_vesting_deposit_claim_and_withdraw() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let staking_contract = contracts.staking_contract;
+    let es_axis_contract = contracts.es_axis_contract;
+    let core_contract = contracts.core_contract;
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    let axis_denom = app
+        .wrap()
+        .query_wasm_smart::<AxisConfigResponse>(
+            contracts.axis_contract.to_owned(),
+            &AxisQueryMsg::GetConfig {},
+        )
+        .unwrap()
+        .axis_denom;
+    //@@ 1_000_000 esAXIS from staking rewards and 100_000 backed by escrowed AXIS
+    app.execute_contract(
+        staking_contract.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Mint {
+            amount: Uint128::new(1_000_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        staking_contract.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Claim {
+            sender: trader.to_owned(),
+            amount: Uint128::new(1_000_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    //@@ Invalid escrow , only registered airdrops lock AXIS
+    let escrow_result = app.execute_contract(
+        admin.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Escrow {
+            recipient: trader.to_string(),
+        },
+        &vec![coin(100_000, axis_denom.to_owned())],
+    );
+    assert!(escrow_result.is_err());
+
+    let airdrop_code = app.store_code(Box::new(ContractWrapper::new(
+        airdrop_execute,
+        airdrop_instantiate,
+        airdrop_query,
+    )));
+    let airdrop_contract = app
+        .instantiate_contract(
+            airdrop_code,
+            admin.to_owned(),
+            &AirdropInstantiateMsg {
+                axis_contract: contracts.axis_contract.to_owned(),
+                merkle_root: hex::encode(leaf(TRADER1, 100_000)),
+                expiry: app.block_info().time.seconds() + 1000,
+                es_axis_rate: 100,
+                es_axis_contract: Some(es_axis_contract.to_owned()),
+            },
+            &vec![],
+            "axis airdrop",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        contracts.axis_contract.to_owned(),
+        &AxisExecuteMsg::RegisterAirDrop {
+            air_drop_contract: airdrop_contract.to_string(),
+            amount: Uint128::new(100_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        trader.to_owned(),
+        airdrop_contract.to_owned(),
+        &AirdropExecuteMsg::Claim {
+            amount: Uint128::new(100_000),
+            proof: vec![],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    //@@ only the core owner updates the vesting config
+    let vesting_config = VestingConfig {
+        vesting_epochs: 4,
+        reserve_rate: 50,
+    };
+    let update_result = app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::UpdateVestingConfig {
+            vesting_config: vesting_config.to_owned(),
+        },
+        &vec![],
+    );
+    assert!(update_result.is_err());
+    app.execute_contract(
+        admin.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::UpdateVestingConfig { vesting_config },
+        &vec![],
+    )
+    .unwrap();
+
+    //@@ 50% of the deposit must be covered by staked AXIS
+    let deposit_result = app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Deposit {
+            amount: Uint128::new(400_000),
+        },
+        &vec![],
+    );
+    assert!(deposit_result.is_err());
+
+    app.send_tokens(
+        admin.to_owned(),
+        trader.to_owned(),
+        &[coin(200_000, axis_denom.to_owned())],
+    )
+    .unwrap();
+    staking(
+        &mut app,
+        &staking_contract,
+        &trader,
+        &vec![coin(200_000, axis_denom.to_owned())],
+    )
+    .unwrap();
+    app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Deposit {
+            amount: Uint128::new(400_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let deposit_result = app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Deposit {
+            amount: Uint128::new(100_000),
+        },
+        &vec![],
+    );
+    assert!(deposit_result.is_err());
+    assert!(un_staking(&mut app, &staking_contract, &trader).is_err());
+
+    //@@ half of the 4 epochs
+    for _ in 0..2 {
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &admin).unwrap();
+    }
+    let before_supply = app
+        .wrap()
+        .query_wasm_smart::<ConfigResponse>(es_axis_contract.to_owned(), &QueryMsg::GetConfig {})
+        .unwrap()
+        .es_axis_total_supply;
+    let before_axis = app
+        .wrap()
+        .query_balance(&trader, axis_denom.to_owned())
+        .unwrap();
+    app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::ClaimVested {},
+        &vec![],
+    )
+    .unwrap();
+    let after_axis = app
+        .wrap()
+        .query_balance(&trader, axis_denom.to_owned())
+        .unwrap();
+    assert_eq!(
+        after_axis.amount - before_axis.amount,
+        Uint128::new(200_000)
+    );
+
+    //@@ escrowed AXIS is paid first , the rest is minted
+    let es_axis_config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(es_axis_contract.to_owned(), &QueryMsg::GetConfig {})
+        .unwrap();
+    assert_eq!(
+        before_supply - es_axis_config.es_axis_total_supply,
+        Uint128::new(200_000)
+    );
+    assert_eq!(es_axis_config.escrowed_axis_amount, Uint128::zero());
+    let minters: Vec<MinterResponse> = app
+        .wrap()
+        .query_wasm_smart(contracts.axis_contract, &AxisQueryMsg::GetMinters {})
+        .unwrap();
+    assert_eq!(minters[0].minter, es_axis_contract);
+    assert_eq!(minters[0].minted_amount, Uint128::new(100_000));
+
+    let positions: VestingPositionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetVestingPositions {
+                address: trader.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(positions.reserved_amount, Uint128::new(200_000));
+    assert_eq!(positions.positions.len(), 1);
+    let position = &positions.positions[0];
+    assert_eq!(position.claimed_amount, Uint128::new(200_000));
+    assert_eq!(position.claimable_amount, Uint128::zero());
+
+    //@@ withdraw returns the unvested esAXIS and releases the stake
+    let before_es_axis: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: trader.to_string(),
+            },
+        )
+        .unwrap();
+    app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::WithdrawVesting {
+            position_id: position.position_id,
+        },
+        &vec![],
+    )
+    .unwrap();
+    let after_es_axis: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: trader.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(after_es_axis - before_es_axis, Uint128::new(200_000));
+    assert!(un_staking(&mut app, &staking_contract, &trader).is_ok());
+}
+
+#[test]
+fn test_transfer_only_to_movers() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let staking_contract = contracts.staking_contract;
+    let es_axis_contract = contracts.es_axis_contract;
+    let vault_contract = contracts.vault_contract;
+    let core_contract = contracts.core_contract;
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    let movers: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(es_axis_contract.to_owned(), &QueryMsg::GetMovers {})
+        .unwrap();
+    assert!(movers.contains(&staking_contract));
+    assert!(movers.contains(&vault_contract));
+
+    app.execute_contract(
+        staking_contract.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Mint {
+            amount: Uint128::new(1000),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        staking_contract.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Claim {
+            sender: trader.to_owned(),
+            amount: Uint128::new(1000),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    //@@ Invalid transfer , trader is not a mover
+    let transfer_result = app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Transfer {
+            recipient: admin.to_string(),
+            amount: Uint128::new(500),
+        },
+        &vec![],
+    );
+    assert!(transfer_result.is_err());
+
+    //@@ Invalid send , core is not a mover
+    let send_result = app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Send {
+            contract: core_contract.to_string(),
+            amount: Uint128::new(500),
+            msg: Binary::default(),
+        },
+        &vec![],
+    );
+    assert!(send_result.is_err());
+
+    //@@ Invalid add mover , only the core owner
+    let add_result = app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::AddMover {
+            mover: trader.to_string(),
+        },
+        &vec![],
+    );
+    assert!(add_result.is_err());
+
+    //@@ fee for the vault
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &admin,
+        BTC_DENOM,
+        USDC_DENOM,
+        1_000_000,
+        2_000_000,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+    let vault_balance: DenomBalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &VaultQueryMsg::GetDenomBalance {
+                denom: BTC_DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!vault_balance.amount.is_zero());
+
+    //@@ swap half of the supply in the vault
+    let before_btc = app.wrap().query_balance(&trader, BTC_DENOM).unwrap();
+    app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &ExecuteMsg::Send {
+            contract: vault_contract.to_string(),
+            amount: Uint128::new(500),
+            msg: to_binary(&VaultReceiveMsg::Swap {}).unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let after_btc = app.wrap().query_balance(&trader, BTC_DENOM).unwrap();
+    assert_eq!(
+        after_btc.amount - before_btc.amount,
+        vault_balance.amount / Uint128::new(2)
+    );
+
+    let es_axis_config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(es_axis_contract.to_owned(), &QueryMsg::GetConfig {})
+        .unwrap();
+    assert_eq!(es_axis_config.es_axis_total_supply, Uint128::new(500));
+    let vault_es_axis: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: vault_contract.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vault_es_axis, Uint128::zero());
+}
+
+#[test]
+fn test_migrate_from_bank_es_axis() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    //@@ the admin plays staking of the bank denom version
+    let legacy_code = app.store_code(mock_legacy_es_axis_contract());
+    let legacy_contract = app
+        .instantiate_contract(
+            legacy_code,
+            admin.to_owned(),
+            &LegacyInstantiateMsg {},
+            &vec![],
+            "legacy es axis",
+            Some(ADMIN.to_string()),
+        )
+        .unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        legacy_contract.to_owned(),
+        &LegacyExecuteMsg::Mint {
+            amount: Uint128::new(1_000_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        legacy_contract.to_owned(),
+        &LegacyExecuteMsg::Claim {
+            sender: trader.to_owned(),
+            amount: Uint128::new(300_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let es_axis_denom = format!("factory/{}/esAxis", legacy_contract);
+
+    let es_axis_code = app.store_code(es_axis_contract());
+    app.migrate_contract(
+        admin.to_owned(),
+        legacy_contract.to_owned(),
+        &MigrateMsg {
+            core_contract: contracts.core_contract.to_string(),
+            vesting_config: None,
+        },
+        es_axis_code,
+    )
+    .unwrap();
+
+    //@@ the pre-minted esAXIS of the contract is burned into its ledger , the supply stays
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(legacy_contract.to_owned(), &QueryMsg::GetConfig {})
+        .unwrap();
+    assert_eq!(config.es_axis_total_supply, Uint128::new(1_000_000));
+    assert_eq!(config.core_contract, contracts.core_contract.to_string());
+    assert_eq!(config.escrowed_axis_amount, Uint128::zero());
+    let contract_bank = app
+        .wrap()
+        .query_balance(&legacy_contract, &es_axis_denom)
+        .unwrap();
+    assert!(contract_bank.amount.is_zero());
+    let contract_ledger: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            legacy_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: legacy_contract.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(contract_ledger, Uint128::new(700_000));
+    let movers: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(legacy_contract.to_owned(), &QueryMsg::GetMovers {})
+        .unwrap();
+    assert!(movers.contains(&contracts.vault_contract));
+    assert!(movers.contains(&admin));
+
+    //@@ claimed bank esAXIS is burned into the ledger of the holder
+    app.execute_contract(
+        trader.to_owned(),
+        legacy_contract.to_owned(),
+        &ExecuteMsg::RedeemBank {},
+        &vec![coin(300_000, es_axis_denom.to_owned())],
+    )
+    .unwrap();
+    let trader_bank = app.wrap().query_balance(&trader, &es_axis_denom).unwrap();
+    assert!(trader_bank.amount.is_zero());
+
+    //@@ claims pay from the ledger after the migration
+    app.execute_contract(
+        admin.to_owned(),
+        legacy_contract.to_owned(),
+        &ExecuteMsg::Claim {
+            sender: trader.to_owned(),
+            amount: Uint128::new(200_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let trader_ledger: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            legacy_contract.to_owned(),
+            &QueryMsg::GetBalance {
+                address: trader.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(trader_ledger, Uint128::new(500_000));
+    let config: ConfigResponse = app
         .wrap()
-        .query_balance(addr1.to_owned(), es_axis_denom.to_owned())
+        .query_wasm_smart(legacy_contract.to_owned(), &QueryMsg::GetConfig {})
         .unwrap();
-    assert_eq!(addr1_es_axis.amount.u128(), 0)
+    assert_eq!(config.es_axis_total_supply, Uint128::new(1_000_000));
 }
@@ -1,9 +1,13 @@
+pub mod airdrop;
 pub mod app;
+pub mod auto_compounder;
 pub mod axis;
 pub mod core;
 pub mod es_axis;
 pub mod lp_staking;
 pub mod market;
+pub mod mock_legacy_es_axis;
+pub mod mock_router;
 pub mod pool;
 pub mod staking;
 pub mod utils;
@@ -1,14 +1,24 @@
-use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use cosmwasm_std::{coin, Addr, Coin, Decimal, Uint128};
 use cw_multi_test::Executor;
 use sei_integration_tests::helper::mock_app;
 
-use crate::app::{
-    init_default_balances, init_exchange_rates, setup_init, Contracts, ADMIN, BTC_DENOM, TRADER1,
-    USDC_DENOM,
+use crate::{
+    app::{
+        init_default_balances, init_exchange_rates, setup_init, Contracts, ADMIN, BTC_DENOM,
+        TRADER1, USDC_DENOM,
+    },
+    utils::{setting, staking},
 };
 
 use axis_protocol::{
-    lp_staking::{ConfigResponse, ExecuteMsg, QueryMsg, StakeInfoResponse, StateResponse},
+    axis::{
+        ConfigResponse as AxisConfigResponse, ExecuteMsg as AxisExecuteMsg,
+        PoolAllowedMintAmountResponse, QueryMsg as AxisQueryMsg,
+    },
+    lp_staking::{
+        BoostInfoResponse, ConfigResponse, ExecuteMsg, LockResponse, QueryMsg, StakeInfoResponse,
+        StateResponse, UnStakeInfoResponse,
+    },
     pool::{
         ConfigResponse as PoolConfigResponse, ExecuteMsg as PoolExecuteMsg,
         QueryMsg as PoolQueryMsg,
@@ -50,7 +60,7 @@ fn valid_staking_same_epoch() {
     let _ = app.execute_contract(
         admin.to_owned(),
         lp_staking_contract.to_owned(),
-        &ExecuteMsg::Staking {},
+        &ExecuteMsg::Staking { lock_epochs: None },
         &vec![admin_lp_one.to_owned()],
     );
 
@@ -58,7 +68,7 @@ fn valid_staking_same_epoch() {
         .execute_contract(
             admin.to_owned(),
             lp_staking_contract.to_owned(),
-            &ExecuteMsg::Staking {},
+            &ExecuteMsg::Staking { lock_epochs: None },
             &vec![admin_lp_two.to_owned()],
         )
         .unwrap();
@@ -77,7 +87,8 @@ fn valid_staking_same_epoch() {
         stake_info_res,
         vec![StakeInfoResponse {
             start_epoch: 1,
-            staking_amount: admin_lp_coin.amount
+            staking_amount: admin_lp_coin.amount,
+            lock: None,
         }]
     );
 
@@ -154,7 +165,7 @@ fn valid_staking_other_epoch() {
         .execute_contract(
             trader.to_owned(),
             lp_staking_contract.to_owned(),
-            &ExecuteMsg::Staking {},
+            &ExecuteMsg::Staking { lock_epochs: None },
             &vec![trader_lp_one.to_owned()],
         )
         .unwrap();
@@ -173,7 +184,7 @@ fn valid_staking_other_epoch() {
         .execute_contract(
             trader.to_owned(),
             lp_staking_contract.to_owned(),
-            &ExecuteMsg::Staking {},
+            &ExecuteMsg::Staking { lock_epochs: None },
             &vec![trader_lp_two.to_owned()],
         )
         .unwrap();
@@ -193,11 +204,13 @@ fn valid_staking_other_epoch() {
         vec![
             StakeInfoResponse {
                 start_epoch: 1,
-                staking_amount: trader_lp_one.amount
+                staking_amount: trader_lp_one.amount,
+                lock: None,
             },
             StakeInfoResponse {
                 start_epoch: 2,
-                staking_amount: trader_lp_two.amount
+                staking_amount: trader_lp_two.amount,
+                lock: None,
             }
         ]
     );
@@ -257,7 +270,7 @@ fn fail_staking() {
     let staking_result = app.execute_contract(
         trader.to_owned(),
         lp_staking_contract.to_owned(),
-        &ExecuteMsg::Staking {},
+        &ExecuteMsg::Staking { lock_epochs: None },
         &vec![trader_btc.to_owned()],
     );
 
@@ -324,7 +337,7 @@ fn valid_unstaking_test() {
         .execute_contract(
             trader.to_owned(),
             lp_staking_contract.to_owned(),
-            &ExecuteMsg::Staking {},
+            &ExecuteMsg::Staking { lock_epochs: None },
             &vec![trader_lp_one.to_owned()],
         )
         .unwrap();
@@ -343,7 +356,7 @@ fn valid_unstaking_test() {
         .execute_contract(
             trader.to_owned(),
             lp_staking_contract.to_owned(),
-            &ExecuteMsg::Staking {},
+            &ExecuteMsg::Staking { lock_epochs: None },
             &vec![trader_lp_two.to_owned()],
         )
         .unwrap();
@@ -351,9 +364,512 @@ fn valid_unstaking_test() {
     let unstaking_result = app.execute_contract(
         trader.to_owned(),
         lp_staking_contract.to_owned(),
-        &ExecuteMsg::UnStaking {},
+        &ExecuteMsg::UnStaking { amount: None },
         &vec![],
     );
 
     assert!(unstaking_result.is_ok());
 }
+
+#[test]
+fn claim_reward_from_reward_index() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let Contracts {
+        market_contract,
+        axis_contract,
+        lp_staking_contract,
+        pool_contract,
+        core_contract,
+        ..
+    } = contracts;
+    let lp_denom = app
+        .wrap()
+        .query_wasm_smart::<PoolConfigResponse>(pool_contract, &PoolQueryMsg::GetConfig {})
+        .unwrap()
+        .lp_denom;
+    let axis_denom = app
+        .wrap()
+        .query_wasm_smart::<AxisConfigResponse>(
+            axis_contract.to_owned(),
+            &AxisQueryMsg::GetConfig {},
+        )
+        .unwrap()
+        .axis_denom;
+
+    let admin_lp = app.wrap().query_balance(&admin, lp_denom).unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        lp_staking_contract.to_owned(),
+        &ExecuteMsg::Staking { lock_epochs: None },
+        &vec![admin_lp],
+    )
+    .unwrap();
+
+    //@@ epoch 0 is minted before the stake is active , epoch 1 is all for the admin
+    for _ in 0..2 {
+        app.execute_contract(
+            market_contract.to_owned(),
+            axis_contract.to_owned(),
+            &AxisExecuteMsg::AddFeeAmount {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                trader: trader.to_owned(),
+                fee_usd_amount: Uint128::new(100),
+                referrer: None,
+                referrer_fee_usd_amount: Uint128::zero(),
+            },
+            &vec![],
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &admin).unwrap();
+    }
+    let pool_mint_amounts: Vec<PoolAllowedMintAmountResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            axis_contract.to_owned(),
+            &AxisQueryMsg::GetPoolAllowedMintAmount {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                start_epoch: 2,
+            },
+        )
+        .unwrap();
+    assert_eq!(pool_mint_amounts.len(), 1);
+
+    //@@ second claim in the same epoch pays nothing
+    let before_axis = app.wrap().query_balance(&admin, &axis_denom).unwrap();
+    for _ in 0..2 {
+        app.execute_contract(
+            admin.to_owned(),
+            lp_staking_contract.to_owned(),
+            &ExecuteMsg::ClaimReward {},
+            &vec![],
+        )
+        .unwrap();
+    }
+    let after_axis = app.wrap().query_balance(&admin, &axis_denom).unwrap();
+    assert_eq!(
+        after_axis.amount - before_axis.amount,
+        pool_mint_amounts[0].mint_amount
+    );
+}
+
+#[test]
+fn boost_by_staked_axis() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let Contracts {
+        market_contract,
+        axis_contract,
+        lp_staking_contract,
+        pool_contract,
+        core_contract,
+        staking_contract,
+        ..
+    } = contracts;
+    let lp_denom = app
+        .wrap()
+        .query_wasm_smart::<PoolConfigResponse>(pool_contract, &PoolQueryMsg::GetConfig {})
+        .unwrap()
+        .lp_denom;
+    let axis_denom = app
+        .wrap()
+        .query_wasm_smart::<AxisConfigResponse>(
+            axis_contract.to_owned(),
+            &AxisQueryMsg::GetConfig {},
+        )
+        .unwrap()
+        .axis_denom;
+
+    //@@ the trader stakes AXIS , both stake the same lp
+    let admin_lp = app.wrap().query_balance(&admin, &lp_denom).unwrap();
+    let lp_amount = admin_lp.amount.multiply_ratio(1u128, 2u128);
+    app.send_tokens(
+        admin.to_owned(),
+        trader.to_owned(),
+        &vec![
+            coin(lp_amount.u128(), &lp_denom),
+            coin(1_000_000, &axis_denom),
+        ],
+    )
+    .unwrap();
+    staking(
+        &mut app,
+        &staking_contract,
+        &trader,
+        &vec![coin(1_000_000, &axis_denom)],
+    )
+    .unwrap();
+    for staker in [&admin, &trader] {
+        app.execute_contract(
+            staker.to_owned(),
+            lp_staking_contract.to_owned(),
+            &ExecuteMsg::Staking { lock_epochs: None },
+            &vec![coin(lp_amount.u128(), &lp_denom)],
+        )
+        .unwrap();
+    }
+    let trader_boost: BoostInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &QueryMsg::GetBoostInfo {
+                address: trader.to_owned(),
+            },
+        )
+        .unwrap();
+    assert_eq!(trader_boost.staking_amount, Uint128::zero());
+    assert_eq!(trader_boost.boost_amount, Uint128::new(1_000_000));
+    assert_eq!(trader_boost.boost_total, Uint128::new(1_000_000));
+    assert_eq!(trader_boost.working_balance, Uint128::zero());
+
+    for _ in 0..2 {
+        app.execute_contract(
+            market_contract.to_owned(),
+            axis_contract.to_owned(),
+            &AxisExecuteMsg::AddFeeAmount {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                trader: trader.to_owned(),
+                fee_usd_amount: Uint128::new(100),
+                referrer: None,
+                referrer_fee_usd_amount: Uint128::zero(),
+            },
+            &vec![],
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &admin).unwrap();
+    }
+
+    //@@ all of the staked AXIS is the trader's , the boost is at the cap
+    let boosts = [&admin, &trader].map(|staker| {
+        app.wrap()
+            .query_wasm_smart::<BoostInfoResponse>(
+                lp_staking_contract.to_owned(),
+                &QueryMsg::GetBoostInfo {
+                    address: staker.to_owned(),
+                },
+            )
+            .unwrap()
+    });
+    assert_eq!(boosts[0].boost, Decimal::one());
+    assert_eq!(boosts[0].working_balance, lp_amount);
+    assert_eq!(boosts[1].boost, Decimal::from_ratio(5u128, 2u128));
+    assert_eq!(
+        boosts[1].working_balance,
+        lp_amount * Decimal::from_ratio(5u128, 2u128)
+    );
+    assert_eq!(
+        boosts[1].working_total,
+        boosts[0].working_balance + boosts[1].working_balance
+    );
+
+    let mint_amount = app
+        .wrap()
+        .query_wasm_smart::<Vec<PoolAllowedMintAmountResponse>>(
+            axis_contract.to_owned(),
+            &AxisQueryMsg::GetPoolAllowedMintAmount {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                start_epoch: 2,
+            },
+        )
+        .unwrap()[0]
+        .mint_amount;
+    let reward_index = Decimal::from_ratio(mint_amount, boosts[1].working_total);
+    for (staker, boost) in [&admin, &trader].iter().zip(boosts.iter()) {
+        let before_axis = app.wrap().query_balance(*staker, &axis_denom).unwrap();
+        app.execute_contract(
+            staker.to_owned().to_owned(),
+            lp_staking_contract.to_owned(),
+            &ExecuteMsg::ClaimReward {},
+            &vec![],
+        )
+        .unwrap();
+        let after_axis = app.wrap().query_balance(*staker, &axis_denom).unwrap();
+        assert_eq!(
+            after_axis.amount - before_axis.amount,
+            boost.working_balance * reward_index
+        );
+    }
+
+    //@@ the admin stakes AXIS too , anyone can bring the boost of the trader down
+    let admin_axis = app.wrap().query_balance(&admin, &axis_denom).unwrap();
+    staking(&mut app, &staking_contract, &admin, &vec![admin_axis]).unwrap();
+    for staker in [&admin, &trader] {
+        app.execute_contract(
+            admin.to_owned(),
+            lp_staking_contract.to_owned(),
+            &ExecuteMsg::UpdateBoost {
+                address: staker.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap();
+    }
+    let boosts = [&admin, &trader].map(|staker| {
+        app.wrap()
+            .query_wasm_smart::<BoostInfoResponse>(
+                lp_staking_contract.to_owned(),
+                &QueryMsg::GetBoostInfo {
+                    address: staker.to_owned(),
+                },
+            )
+            .unwrap()
+    });
+    assert_eq!(boosts[0].boost, Decimal::from_ratio(5u128, 2u128));
+    assert!(boosts[1].boost < Decimal::from_ratio(5u128, 2u128));
+    assert_eq!(
+        boosts[1].working_balance,
+        lp_amount
+            + (lp_amount + lp_amount).multiply_ratio(boosts[1].boost_amount, boosts[1].boost_total)
+                * Decimal::from_ratio(3u128, 2u128)
+    );
+    assert_eq!(
+        boosts[1].working_total,
+        boosts[0].working_balance + boosts[1].working_balance
+    );
+}
+
+#[test]
+fn lock_tiers_and_early_exit() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let Contracts {
+        lp_staking_contract,
+        pool_contract,
+        core_contract,
+        ..
+    } = contracts;
+    let lp_denom = app
+        .wrap()
+        .query_wasm_smart::<PoolConfigResponse>(pool_contract, &PoolQueryMsg::GetConfig {})
+        .unwrap()
+        .lp_denom;
+    let admin_lp = app.wrap().query_balance(&admin, &lp_denom).unwrap();
+    let lp_amount = admin_lp.amount.multiply_ratio(1u128, 2u128);
+    app.send_tokens(
+        admin.to_owned(),
+        trader.to_owned(),
+        &vec![coin(lp_amount.u128(), &lp_denom)],
+    )
+    .unwrap();
+
+    //@@ Invalid lock , not a lock tier
+    let staking_result = app.execute_contract(
+        admin.to_owned(),
+        lp_staking_contract.to_owned(),
+        &ExecuteMsg::Staking {
+            lock_epochs: Some(10),
+        },
+        &vec![coin(lp_amount.u128(), &lp_denom)],
+    );
+    assert!(staking_result.is_err());
+
+    //@@ the admin locks 30 epochs , weighted 1.5
+    for (staker, lock_epochs) in [(&admin, Some(30)), (&trader, None)] {
+        app.execute_contract(
+            staker.to_owned(),
+            lp_staking_contract.to_owned(),
+            &ExecuteMsg::Staking { lock_epochs },
+            &vec![coin(lp_amount.u128(), &lp_denom)],
+        )
+        .unwrap();
+    }
+    let stake_info: Vec<StakeInfoResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &QueryMsg::GetStakeInfo {
+                address: admin.to_owned(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stake_info,
+        vec![StakeInfoResponse {
+            start_epoch: 1,
+            staking_amount: lp_amount,
+            lock: Some(LockResponse {
+                lock_epochs: 30,
+                unlock_epoch: 31,
+                weight: Decimal::percent(150),
+            }),
+        }]
+    );
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+
+    let admin_boost: BoostInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &QueryMsg::GetBoostInfo {
+                address: admin.to_owned(),
+            },
+        )
+        .unwrap();
+    assert_eq!(admin_boost.boost, Decimal::percent(150));
+    assert_eq!(
+        admin_boost.working_balance,
+        lp_amount * Decimal::percent(150)
+    );
+
+    //@@ a locked stake is not unstaked without the early exit
+    app.execute_contract(
+        admin.to_owned(),
+        lp_staking_contract.to_owned(),
+        &ExecuteMsg::UnStaking { amount: None },
+        &vec![],
+    )
+    .unwrap();
+    let stake_info: Vec<StakeInfoResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &QueryMsg::GetStakeInfo {
+                address: admin.to_owned(),
+            },
+        )
+        .unwrap();
+    assert_eq!(stake_info[0].staking_amount, lp_amount);
+
+    //@@ 30 of 30 epochs left , half of the penalty rate 50 is taken
+    app.execute_contract(
+        admin.to_owned(),
+        lp_staking_contract.to_owned(),
+        &ExecuteMsg::EarlyUnStaking {},
+        &vec![],
+    )
+    .unwrap();
+    let penalty_amount = lp_amount.multiply_ratio(50u128, 100u128);
+    let un_stake_info: Vec<UnStakeInfoResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &QueryMsg::GetUnstakeInfo {
+                address: admin.to_owned(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        un_stake_info.last().unwrap().unstaking_amount,
+        lp_amount - penalty_amount
+    );
+    let state: StateResponse = app
+        .wrap()
+        .query_wasm_smart(lp_staking_contract.to_owned(), &QueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.penalty_pending_total, penalty_amount);
+    assert_eq!(state.working_total, lp_amount);
+
+    //@@ the penalty goes to the trader at the next setting
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+    let before_lp = app.wrap().query_balance(&trader, &lp_denom).unwrap();
+    app.execute_contract(
+        trader.to_owned(),
+        lp_staking_contract.to_owned(),
+        &ExecuteMsg::ClaimReward {},
+        &vec![],
+    )
+    .unwrap();
+    let after_lp = app.wrap().query_balance(&trader, &lp_denom).unwrap();
+    let received_amount = after_lp.amount - before_lp.amount;
+    assert_eq!(
+        received_amount,
+        lp_amount * Decimal::from_ratio(penalty_amount, lp_amount)
+    );
+
+    //@@ the trader locks the penalty for 7 epochs , the lock counts until it expires
+    app.execute_contract(
+        trader.to_owned(),
+        lp_staking_contract.to_owned(),
+        &ExecuteMsg::Staking {
+            lock_epochs: Some(7),
+        },
+        &vec![coin(received_amount.u128(), &lp_denom)],
+    )
+    .unwrap();
+    for epoch in 3..=10 {
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &admin).unwrap();
+        if epoch == 3 {
+            let trader_boost: BoostInfoResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    lp_staking_contract.to_owned(),
+                    &QueryMsg::GetBoostInfo {
+                        address: trader.to_owned(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                trader_boost.working_balance,
+                lp_amount + received_amount * Decimal::percent(125)
+            );
+        }
+    }
+    app.execute_contract(
+        admin.to_owned(),
+        lp_staking_contract.to_owned(),
+        &ExecuteMsg::UpdateBoost {
+            address: trader.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let trader_boost: BoostInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &QueryMsg::GetBoostInfo {
+                address: trader.to_owned(),
+            },
+        )
+        .unwrap();
+    assert_eq!(trader_boost.working_balance, lp_amount + received_amount);
+
+    //@@ an expired lock is unstaked without the penalty
+    app.execute_contract(
+        trader.to_owned(),
+        lp_staking_contract.to_owned(),
+        &ExecuteMsg::UnStaking { amount: None },
+        &vec![],
+    )
+    .unwrap();
+    let un_stake_info: Vec<UnStakeInfoResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            lp_staking_contract.to_owned(),
+            &QueryMsg::GetUnstakeInfo {
+                address: trader.to_owned(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        un_stake_info,
+        vec![UnStakeInfoResponse {
+            unlock_epoch: 11,
+            unstaking_amount: lp_amount + received_amount,
+        }]
+    );
+    let state: StateResponse = app
+        .wrap()
+        .query_wasm_smart(lp_staking_contract.to_owned(), &QueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.penalty_pending_total, Uint128::zero());
+    assert_eq!(state.working_total, Uint128::zero());
+}
@@ -1,18 +1,31 @@
-use cosmwasm_std::{Addr, BlockInfo, Decimal, QueryRequest, Uint128};
+use cosmwasm_std::{coin, Addr, BlockInfo, Decimal, QueryRequest, Uint128};
+use cw_multi_test::Executor;
 use sei_cosmwasm::{ExchangeRatesResponse, SeiQuery, SeiQueryWrapper, SeiRoute, SudoMsg};
 use sei_integration_tests::helper::mock_app;
 
 use crate::{
     app::{
-        init_default_balances, init_exchange_rates, setup_init, ADMIN, BTC_DENOM, ETH_DENOM,
-        TRADER1, USDC_DENOM,
+        create_pair, create_pair_with_adl, init_default_balances, init_exchange_rates, setup_init,
+        update_exchange_rate, ADMIN, BTC_DENOM, ETH_DENOM, TRADER1, TRADER2, USDC_DENOM,
     },
-    utils::{position_close, position_open},
+    utils::{auto_deleverage, liquidated, position_close, position_open, staking},
 };
 
 use axis_protocol::{
-    market::{GetConfigResponse, QueryMsg as MarketQueryMsg, TradeResponse},
-    pool::{PoolResponse, QueryMsg as PoolQueryMsg},
+    axis::{ConfigResponse as AxisConfigResponse, PendingFeeResponse, QueryMsg as AxisQueryMsg},
+    core::{
+        DelistedPairResponse, ExecuteMsg as CoreExecuteMsg, PairPoolContractResponse,
+        QueryMsg as CoreQueryMsg, ReferralStatsResponse, TraderFeeTierResponse,
+    },
+    market::{
+        BreakerStatusResponse, ExecuteMsg as MarketExecuteMsg, GetConfigResponse, GetStateResponse,
+        QueryMsg as MarketQueryMsg, TradeResponse,
+    },
+    pool::{
+        ConfigResponse as PoolConfigResponse, ExecuteMsg as PoolExecuteMsg, PauseMode,
+        PoolResponse, QueryMsg as PoolQueryMsg,
+    },
+    vault::{InsuranceBalanceResponse, QueryMsg as VaultQueryMsg},
 };
 
 #[test]
@@ -181,3 +194,720 @@ pub fn valid_position_close() {
     let result = position_close(&mut app, &market_contract, &trader);
     assert!(result.is_ok());
 }
+
+#[test]
+pub fn liquidation_bad_debt_covered_by_insurance() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let market_contract = contracts.market_contract;
+    let trader = Addr::unchecked(TRADER1);
+    //@@ open fee = 10_000 * 10 * 0.001 = 100 , collateral = 9_900
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &trader,
+        true,
+        10,
+        10_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_ok());
+
+    //@@ insurance fund takes 5% of the fee
+    let result = liquidated(&mut app, &market_contract);
+    assert!(result.is_ok());
+    let insurance_res: InsuranceBalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contracts.vault_contract.to_owned(),
+            &VaultQueryMsg::GetInsuranceBalance {
+                denom: BTC_DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(insurance_res.amount.u128(), 5);
+
+    //@@ gap move 10000 -> 8000 , position size = 9_900 * 10 = 99_000
+    //loss = 99_000 * (10000 - 8000) / 8000 = 24_750 , bad debt = 24_750 - 9_900 = 14_850
+    update_exchange_rate(&mut app, BTC_DENOM, "8000");
+    let result = liquidated(&mut app, &market_contract).unwrap();
+
+    let bad_debt_event = result
+        .events
+        .iter()
+        .find(|e| e.ty == "wasm-bad_debt")
+        .unwrap();
+    assert!(bad_debt_event
+        .attributes
+        .iter()
+        .any(|a| a.key == "amount" && a.value == "14850"));
+
+    let payout_event = result
+        .events
+        .iter()
+        .find(|e| e.ty == "wasm-insurance_payout")
+        .unwrap();
+    assert!(payout_event
+        .attributes
+        .iter()
+        .any(|a| a.key == "base_covered" && a.value == "5"));
+
+    let insurance_res: InsuranceBalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contracts.vault_contract,
+            &VaultQueryMsg::GetInsuranceBalance {
+                denom: BTC_DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(insurance_res.amount.is_zero());
+
+    let trade_res: Result<TradeResponse, _> = app.wrap().query_wasm_smart(
+        market_contract,
+        &MarketQueryMsg::GetTrade {
+            trader: trader.to_string(),
+        },
+    );
+    assert!(trade_res.is_err());
+}
+
+#[test]
+pub fn auto_deleverage_reduces_most_profitable_trades() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    //@@ adl rates are percents
+    let result = create_pair_with_adl(
+        &mut app,
+        &Addr::unchecked(ADMIN),
+        &contracts,
+        ETH_DENOM,
+        USDC_DENOM,
+        10_000_000,
+        10_000_000_000,
+        101,
+        50,
+    );
+    assert!(result.is_err());
+    //@@ the side is unhealthy once trader profit is over 10% of the reserve
+    let pool_contract = create_pair_with_adl(
+        &mut app,
+        &Addr::unchecked(ADMIN),
+        &contracts,
+        ETH_DENOM,
+        USDC_DENOM,
+        10_000_000,
+        10_000_000_000,
+        90,
+        50,
+    )
+    .unwrap();
+    let pool_config: PoolConfigResponse = app
+        .wrap()
+        .query_wasm_smart(pool_contract, &PoolQueryMsg::GetConfig {})
+        .unwrap();
+    let market_contract = pool_config.market_contract;
+
+    //@@ trader1 collateral = 99_000 , leverage_amount = 990_000
+    let trader1 = Addr::unchecked(TRADER1);
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &trader1,
+        true,
+        10,
+        100_000,
+        ETH_DENOM,
+    );
+    assert!(result.is_ok());
+    //@@ trader2 collateral = 99_500 , leverage_amount = 497_500
+    let trader2 = Addr::unchecked(TRADER2);
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &trader2,
+        true,
+        5,
+        100_000,
+        ETH_DENOM,
+    );
+    assert!(result.is_ok());
+
+    //@@ pool side is healthy at the entry price, nothing is closed
+    let result = auto_deleverage(&mut app, &market_contract).unwrap();
+    assert!(!result.events.iter().any(|e| e.ty == "wasm-auto_deleverage"));
+
+    //@@ 1000 -> 4000 , profit = size * 3000 / 4000 , 742_500 + 373_125 is over 10% of the reserve
+    update_exchange_rate(&mut app, ETH_DENOM, "4000");
+    let result = auto_deleverage(&mut app, &market_contract).unwrap();
+    let adl_events: Vec<_> = result
+        .events
+        .iter()
+        .filter(|e| e.ty == "wasm-auto_deleverage")
+        .collect();
+    //@@ closing half of trader1 makes the side healthy again , trader2 is kept
+    assert_eq!(adl_events.len(), 1);
+    //@@ the highest leverage is reduced first
+    assert!(adl_events[0]
+        .attributes
+        .iter()
+        .any(|a| a.key == "trader" && a.value == TRADER1));
+    assert!(adl_events[0]
+        .attributes
+        .iter()
+        .any(|a| a.key == "payout" && a.value == "420750"));
+
+    let trade1: TradeResponse = app
+        .wrap()
+        .query_wasm_smart(
+            market_contract.to_owned(),
+            &MarketQueryMsg::GetTrade {
+                trader: trader1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(trade1.collateral_amount.u128(), 49_500);
+    assert_eq!(trade1.leverage_amount.u128(), 495_000);
+    let trade2: TradeResponse = app
+        .wrap()
+        .query_wasm_smart(
+            market_contract,
+            &MarketQueryMsg::GetTrade {
+                trader: trader2.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(trade2.collateral_amount.u128(), 99_500);
+}
+
+#[test]
+pub fn circuit_breaker_blocks_open_and_deposit() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let market_contract = contracts.market_contract;
+    let pool_contract = contracts.pool_contract;
+    let trader = Addr::unchecked(TRADER1);
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &trader,
+        true,
+        2,
+        10_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_ok());
+
+    //@@ first sweep snapshots the epoch nav
+    let result = liquidated(&mut app, &market_contract).unwrap();
+    assert!(!result.events.iter().any(|e| e.ty == "wasm-circuit_breaker"));
+
+    //@@ 10000 -> 14000 , 40% move is over price_breaker_rate(30%)
+    update_exchange_rate(&mut app, BTC_DENOM, "14000");
+    let result = liquidated(&mut app, &market_contract).unwrap();
+    let breaker_event = result
+        .events
+        .iter()
+        .find(|e| e.ty == "wasm-circuit_breaker")
+        .unwrap();
+    assert!(breaker_event
+        .attributes
+        .iter()
+        .any(|a| a.key == "reason" && a.value == "price_move"));
+
+    let status: BreakerStatusResponse = app
+        .wrap()
+        .query_wasm_smart(
+            market_contract.to_owned(),
+            &MarketQueryMsg::GetBreakerStatus {},
+        )
+        .unwrap();
+    assert!(status.tripped);
+    assert_eq!(status.reason, Some("price_move".to_string()));
+    assert_eq!(
+        status.past_price,
+        Decimal::from_atomics(14000u128, 0).unwrap()
+    );
+
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &Addr::unchecked(TRADER2),
+        true,
+        2,
+        10_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_err());
+
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        pool_contract.to_owned(),
+        &PoolExecuteMsg::Deposit {},
+        &[coin(1_000_000, BTC_DENOM), coin(1_000_000, USDC_DENOM)],
+    );
+    assert!(result.is_err());
+
+    //@@ closes keep working while tripped
+    let result = position_close(&mut app, &market_contract, &trader);
+    assert!(result.is_ok());
+
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contracts.core_contract,
+        &CoreExecuteMsg::PairResetBreaker {
+            base_denom: BTC_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+        },
+        &[],
+    );
+    assert!(result.is_ok());
+
+    let status: BreakerStatusResponse = app
+        .wrap()
+        .query_wasm_smart(
+            market_contract.to_owned(),
+            &MarketQueryMsg::GetBreakerStatus {},
+        )
+        .unwrap();
+    assert!(!status.tripped);
+
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &Addr::unchecked(TRADER2),
+        true,
+        2,
+        10_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_ok());
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        pool_contract,
+        &PoolExecuteMsg::Deposit {},
+        &[coin(1_000_000, BTC_DENOM), coin(1_000_000, USDC_DENOM)],
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+pub fn reduce_only_mode_allows_close_only() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let market_contract = contracts.market_contract;
+    let trader = Addr::unchecked(TRADER1);
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &trader,
+        true,
+        2,
+        10_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_ok());
+
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contracts.core_contract.to_owned(),
+        &CoreExecuteMsg::PairSetMode {
+            base_denom: BTC_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+            mode: PauseMode::ReduceOnly,
+        },
+        &[],
+    );
+    assert!(result.is_ok());
+
+    let state: GetStateResponse = app
+        .wrap()
+        .query_wasm_smart(market_contract.to_owned(), &MarketQueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.mode, PauseMode::ReduceOnly);
+
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &Addr::unchecked(TRADER2),
+        true,
+        2,
+        10_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_err());
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contracts.pool_contract.to_owned(),
+        &PoolExecuteMsg::Deposit {},
+        &[coin(1_000_000, BTC_DENOM), coin(1_000_000, USDC_DENOM)],
+    );
+    assert!(result.is_err());
+
+    let result = liquidated(&mut app, &market_contract);
+    assert!(result.is_ok());
+    let result = position_close(&mut app, &market_contract, &trader);
+    assert!(result.is_ok());
+
+    //@@ halted blocks closes too
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contracts.core_contract,
+        &CoreExecuteMsg::PairSetMode {
+            base_denom: BTC_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+            mode: PauseMode::Halted,
+        },
+        &[],
+    );
+    assert!(result.is_ok());
+    let result = liquidated(&mut app, &market_contract);
+    assert!(result.is_err());
+}
+
+#[test]
+pub fn delist_pair_settles_trades_at_final_price() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let market_contract = contracts.market_contract;
+    let pool_contract = contracts.pool_contract;
+    let trader = Addr::unchecked(TRADER1);
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &trader,
+        true,
+        2,
+        10_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_ok());
+
+    //@@ Delisted is entered by delist only
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contracts.core_contract.to_owned(),
+        &CoreExecuteMsg::AllPoolSetMode {
+            mode: PauseMode::Delisted,
+        },
+        &[],
+    );
+    assert!(result.is_err());
+
+    update_exchange_rate(&mut app, BTC_DENOM, "11000");
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contracts.core_contract.to_owned(),
+        &CoreExecuteMsg::DelistPair {
+            base_denom: BTC_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+        },
+        &[],
+    );
+    assert!(result.is_ok());
+
+    let pair_res: Result<PairPoolContractResponse, _> = app.wrap().query_wasm_smart(
+        contracts.core_contract.to_owned(),
+        &CoreQueryMsg::GetPairPoolContract {
+            base_denom: BTC_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+        },
+    );
+    assert!(pair_res.is_err());
+    let delisted_res: DelistedPairResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contracts.core_contract.to_owned(),
+            &CoreQueryMsg::GetDelistedPair {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(delisted_res.pool_contract, pool_contract);
+
+    //@@ and never left
+    let result = app.execute_contract(
+        contracts.core_contract,
+        pool_contract.to_owned(),
+        &PoolExecuteMsg::SetMode {
+            mode: PauseMode::Active,
+        },
+        &[],
+    );
+    assert!(result.is_err());
+
+    let state: GetStateResponse = app
+        .wrap()
+        .query_wasm_smart(market_contract.to_owned(), &MarketQueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.mode, PauseMode::Delisted);
+    assert_eq!(
+        state.settlement_price,
+        Some(Decimal::from_atomics(11000u128, 0).unwrap())
+    );
+
+    //@@ lps wait until every trade is settled
+    let pool_config: PoolConfigResponse = app
+        .wrap()
+        .query_wasm_smart(pool_contract.to_owned(), &PoolQueryMsg::GetConfig {})
+        .unwrap();
+    let lp_balance = app
+        .wrap()
+        .query_balance(ADMIN, pool_config.lp_denom.to_owned())
+        .unwrap();
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        pool_contract.to_owned(),
+        &PoolExecuteMsg::Withdraw {},
+        &[lp_balance.to_owned()],
+    );
+    assert!(result.is_err());
+
+    let result = position_close(&mut app, &market_contract, &trader);
+    assert!(result.is_err());
+
+    //@@ settlement price is frozen , later oracle moves are ignored
+    update_exchange_rate(&mut app, BTC_DENOM, "5000");
+    let result = app
+        .execute_contract(
+            Addr::unchecked(TRADER2),
+            market_contract.to_owned(),
+            &MarketExecuteMsg::SettleTrade {
+                trader: TRADER1.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let settle_event = result
+        .events
+        .iter()
+        .find(|e| e.ty == "wasm-settle_trade")
+        .unwrap();
+    assert!(settle_event
+        .attributes
+        .iter()
+        .any(|a| a.key == "settlement_price" && a.value == "11000"));
+
+    let pool: PoolResponse = app
+        .wrap()
+        .query_wasm_smart(pool_contract.to_owned(), &PoolQueryMsg::GetPool {})
+        .unwrap();
+    assert!(pool.base_borrow_amount.is_zero());
+
+    let result = app.execute_contract(
+        Addr::unchecked(ADMIN),
+        pool_contract,
+        &PoolExecuteMsg::Withdraw {},
+        &[lp_balance],
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+pub fn referral_rebate_and_referrer_reward_on_open() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let core_contract = contracts.core_contract;
+    let market_contract = contracts.market_contract;
+    let trader = Addr::unchecked(TRADER1);
+    let referrer = Addr::unchecked(TRADER2);
+
+    app.execute_contract(
+        referrer.to_owned(),
+        core_contract.to_owned(),
+        &CoreExecuteMsg::RegisterReferralCode {
+            code: "alpha".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    //@@ own code is not allowed
+    let result = app.execute_contract(
+        referrer.to_owned(),
+        core_contract.to_owned(),
+        &CoreExecuteMsg::SetReferralCode {
+            code: "alpha".to_string(),
+        },
+        &[],
+    );
+    assert!(result.is_err());
+    app.execute_contract(
+        trader.to_owned(),
+        core_contract.to_owned(),
+        &CoreExecuteMsg::SetReferralCode {
+            code: "alpha".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let result = app.execute_contract(
+        trader.to_owned(),
+        core_contract.to_owned(),
+        &CoreExecuteMsg::SetReferralCode {
+            code: "alpha".to_string(),
+        },
+        &[],
+    );
+    assert!(result.is_err());
+
+    let before_trader = app.wrap().query_balance(&trader, BTC_DENOM).unwrap();
+    let before_referrer = app.wrap().query_balance(&referrer, BTC_DENOM).unwrap();
+
+    //@@ open fee 1_000_000 * 10 * 0.1% = 10_000 , first tier is 5% rebate and 5% reward
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &trader,
+        true,
+        10,
+        1_000_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_ok());
+
+    let after_trader = app.wrap().query_balance(&trader, BTC_DENOM).unwrap();
+    let after_referrer = app.wrap().query_balance(&referrer, BTC_DENOM).unwrap();
+    assert_eq!(
+        before_trader.amount - after_trader.amount,
+        Uint128::new(1_000_000 - 500)
+    );
+    assert_eq!(
+        after_referrer.amount - before_referrer.amount,
+        Uint128::new(500)
+    );
+
+    let state: GetStateResponse = app
+        .wrap()
+        .query_wasm_smart(market_contract.to_owned(), &MarketQueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.base_coin_total_fee, Uint128::new(9_000));
+
+    let stats: ReferralStatsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_contract.to_owned(),
+            &CoreQueryMsg::GetReferralStats {
+                referrer: referrer.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(stats.code, Some("alpha".to_string()));
+    assert_eq!(stats.trader_count, 1);
+    assert_eq!(
+        stats.total_rebate_usd_amount,
+        stats.total_fee_usd_amount * Decimal::percent(5)
+    );
+
+    //@@ referrer is weighted 10% of the fee in trader emissions
+    let pending_fee: PendingFeeResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contracts.axis_contract,
+            &AxisQueryMsg::GetPendingTotalFee {},
+        )
+        .unwrap();
+    assert_eq!(
+        pending_fee.pending_total_fee,
+        stats.total_fee_usd_amount + stats.total_fee_usd_amount * Decimal::percent(10)
+    );
+}
+
+#[test]
+pub fn staker_fee_tier_discount_on_open() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let core_contract = contracts.core_contract;
+    let market_contract = contracts.market_contract;
+    let trader = Addr::unchecked(TRADER1);
+
+    let axis_config: AxisConfigResponse = app
+        .wrap()
+        .query_wasm_smart(contracts.axis_contract, &AxisQueryMsg::GetConfig {})
+        .unwrap();
+    //@@ 10_000 AXIS staked is the 10% tier
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        trader.to_owned(),
+        &[coin(10_000_000_000, axis_config.axis_denom.to_owned())],
+    )
+    .unwrap();
+    staking(
+        &mut app,
+        &contracts.staking_contract,
+        &trader,
+        &vec![coin(10_000_000_000, axis_config.axis_denom)],
+    )
+    .unwrap();
+
+    let fee_tier: TraderFeeTierResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_contract.to_owned(),
+            &CoreQueryMsg::GetTraderFeeTier {
+                trader: trader.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(fee_tier.tier, 2);
+    assert_eq!(fee_tier.discount_rate, 10);
+    assert_eq!(fee_tier.staked_axis_amount, Uint128::new(10_000_000_000));
+
+    //@@ open fee 10_000 with 10% off
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &trader,
+        true,
+        10,
+        1_000_000,
+        BTC_DENOM,
+    )
+    .unwrap();
+    assert!(result.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "fee_tier" && attr.value == "2")));
+
+    let state: GetStateResponse = app
+        .wrap()
+        .query_wasm_smart(market_contract.to_owned(), &MarketQueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.base_coin_total_fee, Uint128::new(9_000));
+
+    let trade: TradeResponse = app
+        .wrap()
+        .query_wasm_smart(
+            market_contract.to_owned(),
+            &MarketQueryMsg::GetTrade {
+                trader: trader.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(trade.fee_tier, 2);
+    assert_eq!(trade.collateral_amount, Uint128::new(1_000_000 - 9_000));
+
+    //@@ trader without stake pays the full fee
+    let result = position_open(
+        &mut app,
+        &market_contract,
+        &Addr::unchecked(TRADER2),
+        true,
+        10,
+        1_000_000,
+        BTC_DENOM,
+    );
+    assert!(result.is_ok());
+    let state: GetStateResponse = app
+        .wrap()
+        .query_wasm_smart(market_contract, &MarketQueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.base_coin_total_fee, Uint128::new(19_000));
+}
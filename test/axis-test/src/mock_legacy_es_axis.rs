@@ -0,0 +1,96 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coin, Addr, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    Uint128,
+};
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::Item;
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use es_axis::contract::{
+    execute as es_axis_execute, instantiate as es_axis_instantiate, migrate as es_axis_migrate,
+    query as es_axis_query,
+};
+
+//@@ es axis before the ledger , esAXIS is a bank denom minted here and sent out on claim
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Claim { sender: Addr, amount: Uint128 },
+    Mint { amount: Uint128 },
+}
+
+#[cw_serde]
+pub enum QueryMsg {}
+
+#[cw_serde]
+struct Config {
+    staking_contarct: Addr,
+    es_axis_total_supply: Uint128,
+    es_axis_denom: String,
+}
+
+const CONFIG: Item<Config> = Item::new("config");
+const ES_AXIS_DENOM: &str = "esAxis";
+
+pub fn instantiate(
+    deps: DepsMut<SeiQueryWrapper>,
+    env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response<SeiMsg>> {
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            staking_contarct: info.sender,
+            es_axis_total_supply: Uint128::zero(),
+            es_axis_denom: format!("factory/{}/{}", env.contract.address, ES_AXIS_DENOM),
+        },
+    )?;
+    Ok(Response::new().add_message(SeiMsg::CreateDenom {
+        subdenom: ES_AXIS_DENOM.to_owned(),
+    }))
+}
+
+pub fn execute(
+    deps: DepsMut<SeiQueryWrapper>,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response<SeiMsg>> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.staking_contarct {
+        return Err(StdError::generic_err("unauthorized"));
+    }
+    match msg {
+        ExecuteMsg::Claim { sender, amount } => Ok(Response::new().add_message(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: vec![coin(amount.u128(), config.es_axis_denom)],
+        })),
+        ExecuteMsg::Mint { amount } => {
+            config.es_axis_total_supply += amount;
+            CONFIG.save(deps.storage, &config)?;
+            Ok(Response::new().add_message(SeiMsg::MintTokens {
+                amount: coin(amount.u128(), config.es_axis_denom),
+            }))
+        }
+    }
+}
+
+pub fn query(_deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {}
+}
+
+pub fn mock_legacy_es_axis_contract() -> Box<dyn Contract<SeiMsg, SeiQueryWrapper>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
+//@@ the current es axis with its migrate entry point
+pub fn es_axis_contract() -> Box<dyn Contract<SeiMsg, SeiQueryWrapper>> {
+    Box::new(
+        ContractWrapper::new(es_axis_execute, es_axis_instantiate, es_axis_query)
+            .with_migrate(es_axis_migrate),
+    )
+}
@@ -0,0 +1,91 @@
+use axis_protocol::router::{ExecuteMsg, QueryMsg, SimulateSwapResponse};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coin, to_binary, BankMsg, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    StdResult, Uint128,
+};
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::Item;
+use sei_cosmwasm::{SeiMsg, SeiQuerier, SeiQueryWrapper};
+
+//@@ dex router for tests , pays the oracle price times return_rate out of its own balance
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub return_rate: Decimal,
+}
+
+const RETURN_RATE: Item<Decimal> = Item::new("return_rate");
+
+fn simulate_swap(
+    deps: Deps<SeiQueryWrapper>,
+    offer_denom: &String,
+    offer_amount: Uint128,
+    ask_denom: &String,
+) -> StdResult<Uint128> {
+    let exchange_rates = SeiQuerier::new(&deps.querier).query_exchange_rates()?;
+    let price = |denom: &String| {
+        exchange_rates
+            .denom_oracle_exchange_rate_pairs
+            .iter()
+            .find(|d| d.denom == *denom)
+            .map(|d| d.oracle_exchange_rate.exchange_rate)
+            .ok_or_else(|| StdError::not_found("exchange rate"))
+    };
+    let return_amount =
+        offer_amount.multiply_ratio(price(offer_denom)?.atomics(), price(ask_denom)?.atomics());
+    Ok(return_amount * RETURN_RATE.load(deps.storage)?)
+}
+
+pub fn instantiate(
+    deps: DepsMut<SeiQueryWrapper>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response<SeiMsg>> {
+    RETURN_RATE.save(deps.storage, &msg.return_rate)?;
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut<SeiQueryWrapper>,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response<SeiMsg>> {
+    match msg {
+        ExecuteMsg::Swap {
+            ask_denom,
+            min_return,
+            recipient,
+        } => {
+            let mut return_amount = Uint128::zero();
+            for offer in info.funds.iter() {
+                return_amount +=
+                    simulate_swap(deps.as_ref(), &offer.denom, offer.amount, &ask_denom)?;
+            }
+            if return_amount < min_return {
+                return Err(StdError::generic_err("return amount under min return"));
+            }
+            Ok(Response::new().add_message(BankMsg::Send {
+                to_address: recipient,
+                amount: vec![coin(return_amount.u128(), ask_denom)],
+            }))
+        }
+    }
+}
+
+pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::SimulateSwap {
+            offer_denom,
+            offer_amount,
+            ask_denom,
+        } => to_binary(&SimulateSwapResponse {
+            return_amount: simulate_swap(deps, &offer_denom, offer_amount, &ask_denom)?,
+        }),
+    }
+}
+
+pub fn mock_router_contract() -> Box<dyn Contract<SeiMsg, SeiQueryWrapper>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
@@ -1,7 +1,8 @@
 use axis::state::TRADER;
-use cosmwasm_std::{coin, Addr, Uint128};
+use cosmwasm_std::{coin, Addr, Coin, Decimal, Uint128};
 use cw_multi_test::{custom_app, Executor, SudoMsg};
 
+use ::staking::contract::ONE_DAY_PER_MINT;
 use ::staking::state::StakeInfo;
 use sei_cosmwasm::{SeiMsg, SeiRoute, SudoMsg as SeiSudoMsg};
 use sei_integration_tests::{helper::mock_app, module::SeiModule};
@@ -11,8 +12,9 @@ use crate::{
         self, init_default_balances, init_exchange_rates, setup_init, Contracts, ADMIN, BTC_DENOM,
         TRADER1, USDC_DENOM,
     },
+    mock_router::{mock_router_contract, InstantiateMsg as RouterInstantiateMsg},
     staking,
-    utils::{setting, staking, un_staking},
+    utils::{recieved_fee, setting, staking, un_staking},
 };
 
 use axis_protocol::{
@@ -20,11 +22,14 @@ use axis_protocol::{
     core::{
         ConfigResponse as CoreConfigResponse, QueryMsg as CoreQueryMsg, SudoMsg as CoreSudoMsg,
     },
-    es_axis::{ConfigResponse as EsAxisConfigResponse, QueryMsg as EsAxisQueryMsg},
+    es_axis::QueryMsg as EsAxisQueryMsg,
     staking::{
         ConfigResponse as StakingConfigResponse, ExecuteMsg, QueryMsg, StakeInfoResponse,
         StakeResponse, StateResponse, UnStakeInfoResponse, UnStakeResponse,
     },
+    vault::{
+        DenomBalanceResponse, ExecuteMsg as VaultExecuteMsg, QueryMsg as VaultQueryMsg, SwapAdapter,
+    },
 };
 
 #[test]
@@ -158,11 +163,6 @@ fn test_withdraw() {
 
     let axis_denom = axis_res.axis_denom;
 
-    let ex_axis_config_res: EsAxisConfigResponse = app
-        .wrap()
-        .query_wasm_smart(es_axis_contract.to_owned(), &EsAxisQueryMsg::GetConfig {})
-        .unwrap();
-    let es_axis_denom = ex_axis_config_res.es_axis_denom;
     let staking_admin_axis = app
         .wrap()
         .query_balance(ADMIN, axis_denom.to_owned())
@@ -184,14 +184,15 @@ fn test_withdraw() {
         //epoch is 2
         let setting_result = setting(&mut app, &core_contract, &staker);
         assert!(setting_result.is_ok());
-        let es_axis_contract_balance = app
+        //@@ rewards are minted on claim
+        let es_axis_total_supply: Uint128 = app
             .wrap()
-            .query_balance(es_axis_contract, es_axis_denom.to_owned())
+            .query_wasm_smart(
+                es_axis_contract.to_owned(),
+                &EsAxisQueryMsg::GetTotalSupply {},
+            )
             .unwrap();
-        assert_eq!(
-            es_axis_contract_balance.amount.u128(),
-            1_000_000_000_000_000
-        );
+        assert!(es_axis_total_supply.is_zero());
     }
 
     let result = un_staking(&mut app, &staking_contract, &staker);
@@ -253,12 +254,17 @@ fn test_withdraw() {
         .unwrap();
     assert_eq!(staking_admin_axis.amount, return_staking_admin_axis.amount);
 
-    let admin_es_axis = app
+    let admin_es_axis: Uint128 = app
         .wrap()
-        .query_balance(ADMIN, es_axis_denom.to_owned())
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &EsAxisQueryMsg::GetBalance {
+                address: ADMIN.to_string(),
+            },
+        )
         .unwrap();
 
-    assert_eq!(admin_es_axis.amount, Uint128::new(1_000_000_000_000_000));
+    assert_eq!(admin_es_axis, Uint128::new(1_000_000_000_000_000));
 }
 
 #[test]
@@ -278,13 +284,6 @@ fn test_cliam_reward() {
     let es_axis_mint_per_day = 1_000_000_000_000_000u128;
     let axis_denom = axis_res.axis_denom;
 
-    let ex_axis_config_res: EsAxisConfigResponse = app
-        .wrap()
-        .query_wasm_smart(es_axis_contract.to_owned(), &EsAxisQueryMsg::GetConfig {})
-        .unwrap();
-
-    let es_axis_denom = ex_axis_config_res.es_axis_denom;
-
     let staker1_axis = app
         .wrap()
         .query_balance(ADMIN, axis_denom.to_owned())
@@ -342,20 +341,20 @@ fn test_cliam_reward() {
         //epoch is 2
         let setting_result = setting(&mut app, &core_contract, &staker);
         assert!(setting_result.is_ok());
-        let es_axis_contract_balance = app
+        let es_axis_total_supply: Uint128 = app
             .wrap()
-            .query_balance(es_axis_contract, es_axis_denom.to_owned())
+            .query_wasm_smart(
+                es_axis_contract.to_owned(),
+                &EsAxisQueryMsg::GetTotalSupply {},
+            )
             .unwrap();
-        assert_eq!(
-            es_axis_contract_balance.amount.u128(),
-            (es_axis_mint_per_day)
-        );
+        assert!(es_axis_total_supply.is_zero());
     }
 
     app.execute_contract(
         staker.to_owned(),
         staking_contract.to_owned(),
-        &ExecuteMsg::ClaimReward {},
+        &ExecuteMsg::ClaimReward { until_epoch: None },
         &vec![],
     )
     .unwrap();
@@ -363,28 +362,47 @@ fn test_cliam_reward() {
     app.execute_contract(
         staker2.to_owned(),
         staking_contract.to_owned(),
-        &ExecuteMsg::ClaimReward {},
+        &ExecuteMsg::ClaimReward { until_epoch: None },
         &vec![],
     )
     .unwrap();
 
     //staker es-axis balance check
-    let staker_ex_axis = app
+    let staker_ex_axis: Uint128 = app
         .wrap()
-        .query_balance(staker.to_owned(), es_axis_denom.to_owned())
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &EsAxisQueryMsg::GetBalance {
+                address: staker.to_string(),
+            },
+        )
         .unwrap();
 
-    assert_eq!(staker_ex_axis.amount.u128(), es_axis_mint_per_day / 2);
+    assert_eq!(staker_ex_axis.u128(), es_axis_mint_per_day / 2);
 
     //staker2 es-axis balance check
-    let staker2_ex_axis = app
+    let staker2_ex_axis: Uint128 = app
         .wrap()
-        .query_balance(staker2.to_owned(), es_axis_denom)
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &EsAxisQueryMsg::GetBalance {
+                address: staker2.to_string(),
+            },
+        )
         .unwrap();
 
-    assert_eq!(staker2_ex_axis.amount.u128(), es_axis_mint_per_day / 2);
+    assert_eq!(staker2_ex_axis.u128(), es_axis_mint_per_day / 2);
 
-    //staking_amount_check
+    let es_axis_total_supply: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &EsAxisQueryMsg::GetTotalSupply {},
+        )
+        .unwrap();
+    assert_eq!(es_axis_total_supply.u128(), es_axis_mint_per_day);
+
+    //staking_amount_check , claimed stakes start from the claimed epoch
     let staker_staking_info: StakeInfoResponse = app
         .wrap()
         .query_wasm_smart(
@@ -398,7 +416,7 @@ fn test_cliam_reward() {
         staker_staking_info,
         StakeInfoResponse {
             stake_infos: vec![StakeResponse {
-                start_epoch: 1,
+                start_epoch: 2,
                 staking_amount: staker1_axis.amount
             }]
         }
@@ -417,7 +435,7 @@ fn test_cliam_reward() {
         staker2_staking_info,
         StakeInfoResponse {
             stake_infos: vec![StakeResponse {
-                start_epoch: 1,
+                start_epoch: 2,
                 staking_amount: staker2_axis.amount
             }]
         }
@@ -469,3 +487,455 @@ fn test_setting() {
     assert_eq!(staking_state_res.epoch, 1);
     assert_eq!(staking_state_res.staking_total, staking_admin_axis.amount);
 }
+
+#[test]
+fn test_partial_unstake_cancel_unbonding_and_claim_until() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let staker = Addr::unchecked(ADMIN);
+    let Contracts {
+        core_contract,
+        staking_contract,
+        axis_contract,
+        es_axis_contract,
+        ..
+    } = contracts;
+    let axis_denom = app
+        .wrap()
+        .query_wasm_smart::<AxisConfigResponse>(axis_contract, &AxisQueryMsg::GetConfig {})
+        .unwrap()
+        .axis_denom;
+    let staked_axis = app
+        .wrap()
+        .query_balance(&staker, axis_denom.to_owned())
+        .unwrap();
+    staking(
+        &mut app,
+        &staking_contract,
+        &staker,
+        &vec![staked_axis.to_owned()],
+    )
+    .unwrap();
+    for _ in 0..3 {
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        setting(&mut app, &core_contract, &staker).unwrap();
+    }
+    let state: StateResponse = app
+        .wrap()
+        .query_wasm_smart(staking_contract.to_owned(), &QueryMsg::GetState {})
+        .unwrap();
+    let epoch = state.epoch;
+
+    //@@ Invalid claim , epoch not reached yet
+    let claim_result = app.execute_contract(
+        staker.to_owned(),
+        staking_contract.to_owned(),
+        &ExecuteMsg::ClaimReward {
+            until_epoch: Some(epoch + 1),
+        },
+        &vec![],
+    );
+    assert!(claim_result.is_err());
+
+    //@@ claim only the first rewarded epoch , twice pays once
+    for _ in 0..2 {
+        app.execute_contract(
+            staker.to_owned(),
+            staking_contract.to_owned(),
+            &ExecuteMsg::ClaimReward {
+                until_epoch: Some(epoch - 1),
+            },
+            &vec![],
+        )
+        .unwrap();
+    }
+    let staker_es_axis: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &EsAxisQueryMsg::GetBalance {
+                address: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(staker_es_axis.u128(), ONE_DAY_PER_MINT);
+
+    //@@ Invalid unstake more than staked
+    let un_staking_result = app.execute_contract(
+        staker.to_owned(),
+        staking_contract.to_owned(),
+        &ExecuteMsg::UnStaking {
+            amount: Some(staked_axis.amount + Uint128::new(1)),
+        },
+        &vec![],
+    );
+    assert!(un_staking_result.is_err());
+
+    //@@ partial unstake pays the rest of the rewards
+    let un_stake_amount = staked_axis.amount / Uint128::new(4);
+    app.execute_contract(
+        staker.to_owned(),
+        staking_contract.to_owned(),
+        &ExecuteMsg::UnStaking {
+            amount: Some(un_stake_amount),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let staker_es_axis: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &EsAxisQueryMsg::GetBalance {
+                address: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(staker_es_axis.u128(), ONE_DAY_PER_MINT * 2);
+    let state: StateResponse = app
+        .wrap()
+        .query_wasm_smart(staking_contract.to_owned(), &QueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.staking_total, staked_axis.amount - un_stake_amount);
+    assert_eq!(state.withdraw_pending_total, un_stake_amount);
+
+    //@@ Invalid cancel , nothing unlocks at that epoch
+    let cancel_result = app.execute_contract(
+        staker.to_owned(),
+        staking_contract.to_owned(),
+        &ExecuteMsg::CancelUnbonding {
+            unlock_epoch: epoch + 2,
+        },
+        &vec![],
+    );
+    assert!(cancel_result.is_err());
+
+    app.execute_contract(
+        staker.to_owned(),
+        staking_contract.to_owned(),
+        &ExecuteMsg::CancelUnbonding {
+            unlock_epoch: epoch + 1,
+        },
+        &vec![],
+    )
+    .unwrap();
+    let state: StateResponse = app
+        .wrap()
+        .query_wasm_smart(staking_contract.to_owned(), &QueryMsg::GetState {})
+        .unwrap();
+    assert_eq!(state.withdraw_pending_total, Uint128::zero());
+    assert_eq!(state.pending_staking_total, un_stake_amount);
+    let stake_info: StakeInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            staking_contract.to_owned(),
+            &QueryMsg::GetStakeInfo {
+                addr: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stake_info,
+        StakeInfoResponse {
+            stake_infos: vec![
+                StakeResponse {
+                    start_epoch: epoch,
+                    staking_amount: staked_axis.amount - un_stake_amount,
+                },
+                StakeResponse {
+                    start_epoch: epoch + 1,
+                    staking_amount: un_stake_amount,
+                },
+            ]
+        }
+    );
+}
+
+#[test]
+fn test_claim_fees_from_vault_yield() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let staker = Addr::unchecked(ADMIN);
+    let Contracts {
+        core_contract,
+        staking_contract,
+        axis_contract,
+        vault_contract,
+        ..
+    } = contracts;
+    let axis_denom = app
+        .wrap()
+        .query_wasm_smart::<AxisConfigResponse>(axis_contract, &AxisQueryMsg::GetConfig {})
+        .unwrap()
+        .axis_denom;
+    let staked_axis = app.wrap().query_balance(&staker, axis_denom).unwrap();
+    staking(
+        &mut app,
+        &staking_contract,
+        &staker,
+        &vec![staked_axis.to_owned()],
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &staker).unwrap();
+
+    //@@ Invalid yield config , over 100 percent and not the owner
+    let update_result = app.execute_contract(
+        staker.to_owned(),
+        vault_contract.to_owned(),
+        &VaultExecuteMsg::UpdateYieldConfig {
+            staker_yield_rate: 101,
+            yield_denom: None,
+        },
+        &vec![],
+    );
+    assert!(update_result.is_err());
+    let update_result = app.execute_contract(
+        Addr::unchecked(TRADER1),
+        vault_contract.to_owned(),
+        &VaultExecuteMsg::UpdateYieldConfig {
+            staker_yield_rate: 50,
+            yield_denom: None,
+        },
+        &vec![],
+    );
+    assert!(update_result.is_err());
+    app.execute_contract(
+        staker.to_owned(),
+        vault_contract.to_owned(),
+        &VaultExecuteMsg::UpdateYieldConfig {
+            staker_yield_rate: 50,
+            yield_denom: None,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    //@@ Invalid receive fees , only the vault shares fees
+    let receive_result = app.execute_contract(
+        staker.to_owned(),
+        staking_contract.to_owned(),
+        &ExecuteMsg::ReceiveFees {},
+        &vec![coin(1_000, USDC_DENOM)],
+    );
+    assert!(receive_result.is_err());
+
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &staker,
+        BTC_DENOM,
+        USDC_DENOM,
+        1_000_000,
+        2_000_000,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &staker).unwrap();
+
+    //@@ half of the fees stays for esAXIS , the other half is shared by the stake
+    let vault_btc: DenomBalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &VaultQueryMsg::GetDenomBalance {
+                denom: BTC_DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vault_btc.amount, Uint128::new(500_000));
+    let staker_fee =
+        |amount: u128| staked_axis.amount * Decimal::from_ratio(amount, staked_axis.amount);
+    let pending_fees: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            staking_contract.to_owned(),
+            &QueryMsg::GetPendingFees {
+                address: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pending_fees.len(), 2);
+    for (denom, amount) in [(BTC_DENOM, 500_000), (USDC_DENOM, 1_000_000)] {
+        let fee = pending_fees.iter().find(|fee| fee.denom == denom).unwrap();
+        assert_eq!(fee.amount, staker_fee(amount));
+    }
+
+    let reward_before: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            staking_contract.to_owned(),
+            &QueryMsg::GetAvailableReward {
+                addr: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!reward_before.is_zero());
+    let btc_before = app.wrap().query_balance(&staker, BTC_DENOM).unwrap();
+    app.execute_contract(
+        staker.to_owned(),
+        staking_contract.to_owned(),
+        &ExecuteMsg::ClaimFees {},
+        &vec![],
+    )
+    .unwrap();
+    let btc_after = app.wrap().query_balance(&staker, BTC_DENOM).unwrap();
+    assert_eq!(btc_after.amount - btc_before.amount, staker_fee(500_000));
+
+    //@@ the rewards settled by ClaimFees are still claimable
+    let reward_after: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            staking_contract.to_owned(),
+            &QueryMsg::GetAvailableReward {
+                addr: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(reward_after, reward_before);
+    let pending_fees: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            staking_contract.to_owned(),
+            &QueryMsg::GetPendingFees {
+                address: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(pending_fees.is_empty());
+
+    //@@ without a swap adapter the BTC share is paid as it is , the vault balance is untouched
+    app.execute_contract(
+        staker.to_owned(),
+        vault_contract.to_owned(),
+        &VaultExecuteMsg::UpdateYieldConfig {
+            staker_yield_rate: 50,
+            yield_denom: Some(USDC_DENOM.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &staker,
+        BTC_DENOM,
+        USDC_DENOM,
+        100,
+        1_000,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &staker).unwrap();
+
+    for (denom, amount) in [(BTC_DENOM, 500_050), (USDC_DENOM, 1_000_500)] {
+        let vault_balance: DenomBalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                vault_contract.to_owned(),
+                &VaultQueryMsg::GetDenomBalance {
+                    denom: denom.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(vault_balance.amount, Uint128::new(amount));
+    }
+    let pending_fees: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            staking_contract.to_owned(),
+            &QueryMsg::GetPendingFees {
+                address: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pending_fees.len(), 2);
+    for (denom, amount) in [(BTC_DENOM, 50), (USDC_DENOM, 500)] {
+        let fee = pending_fees.iter().find(|fee| fee.denom == denom).unwrap();
+        assert_eq!(fee.amount, staker_fee(amount));
+    }
+    app.execute_contract(
+        staker.to_owned(),
+        staking_contract.to_owned(),
+        &ExecuteMsg::ClaimFees {},
+        &vec![],
+    )
+    .unwrap();
+
+    //@@ with a swap adapter the BTC share is sold for USDC through the router
+    let router_code = app.store_code(mock_router_contract());
+    let router_contract = app
+        .instantiate_contract(
+            router_code,
+            staker.to_owned(),
+            &RouterInstantiateMsg {
+                return_rate: Decimal::one(),
+            },
+            &vec![],
+            "mock_router",
+            None,
+        )
+        .unwrap();
+    app.send_tokens(
+        staker.to_owned(),
+        router_contract.to_owned(),
+        &vec![coin(100_000_000, USDC_DENOM)],
+    )
+    .unwrap();
+    app.execute_contract(
+        staker.to_owned(),
+        vault_contract.to_owned(),
+        &VaultExecuteMsg::UpdateSwapAdapter {
+            swap_adapter: Some(SwapAdapter {
+                router_contract: router_contract.to_string(),
+                payout_denom: USDC_DENOM.to_string(),
+                max_slippage: 1,
+            }),
+        },
+        &vec![],
+    )
+    .unwrap();
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &staker,
+        BTC_DENOM,
+        USDC_DENOM,
+        100,
+        1_000,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &staker).unwrap();
+
+    for (denom, amount) in [(BTC_DENOM, 500_100), (USDC_DENOM, 1_001_000)] {
+        let vault_balance: DenomBalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                vault_contract.to_owned(),
+                &VaultQueryMsg::GetDenomBalance {
+                    denom: denom.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(vault_balance.amount, Uint128::new(amount));
+    }
+    let pending_fees: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            staking_contract.to_owned(),
+            &QueryMsg::GetPendingFees {
+                address: staker.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        pending_fees,
+        vec![coin(staker_fee(500_500).u128(), USDC_DENOM)]
+    );
+}
@@ -1,12 +1,17 @@
 use anyhow::Error;
-use cosmwasm_std::{coin, Addr, Coin};
+
+use crate::app::ADMIN;
+use cosmwasm_std::{coin, Addr, Coin, Uint128};
 
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 use sei_integration_tests::module::SeiModule;
 
 use axis_protocol::{
-    core::ExecuteMsg as CoreExecuteMsg, market::ExecuteMsg as MarketExecuteMsg,
-    pool::ExecuteMsg as PoolExecuteMsg, staking::ExecuteMsg as StakingExecuteMsg,
+    core::{ExecuteMsg as CoreExecuteMsg, PairMarketContractResponse, QueryMsg as CoreQueryMsg},
+    market::ExecuteMsg as MarketExecuteMsg,
+    pool::ExecuteMsg as PoolExecuteMsg,
+    staking::ExecuteMsg as StakingExecuteMsg,
+    vault::ExecuteMsg as VaultExecuteMsg,
 };
 use cosmwasm_std::{
     testing::{MockApi, MockStorage},
@@ -136,6 +141,50 @@ pub fn position_close(
     result
 }
 
+pub fn liquidated(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MockStorage,
+        SeiModule,
+        WasmKeeper<SeiMsg, SeiQueryWrapper>,
+        StakeKeeper,
+        DistributionKeeper,
+        FailingModule<IbcMsg, IbcQuery, Empty>,
+        FailingModule<GovMsg, Empty, Empty>,
+    >,
+    market_contract: &Addr,
+) -> Result<AppResponse, Error> {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        market_contract.to_owned(),
+        &MarketExecuteMsg::Liquidated {},
+        &[],
+    )
+}
+
+pub fn auto_deleverage(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MockStorage,
+        SeiModule,
+        WasmKeeper<SeiMsg, SeiQueryWrapper>,
+        StakeKeeper,
+        DistributionKeeper,
+        FailingModule<IbcMsg, IbcQuery, Empty>,
+        FailingModule<GovMsg, Empty, Empty>,
+    >,
+    market_contract: &Addr,
+) -> Result<AppResponse, Error> {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        market_contract.to_owned(),
+        &MarketExecuteMsg::AutoDeleverage {},
+        &[],
+    )
+}
+
 pub fn staking(
     app: &mut App<
         BankKeeper,
@@ -177,7 +226,7 @@ pub fn un_staking(
     staking_contract: &Addr,
     staker: &Addr,
 ) -> Result<AppResponse, Error> {
-    let un_staking_msg = &StakingExecuteMsg::UnStaking {};
+    let un_staking_msg = &StakingExecuteMsg::UnStaking { amount: None };
     let result = app.execute_contract(
         staker.to_owned(),
         staking_contract.to_owned(),
@@ -211,3 +260,53 @@ pub fn setting(
     );
     result
 }
+
+//@@ fee sent through the pair market , the vault takes fees only from it
+#[allow(clippy::too_many_arguments)]
+pub fn recieved_fee(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MockStorage,
+        SeiModule,
+        WasmKeeper<SeiMsg, SeiQueryWrapper>,
+        StakeKeeper,
+        DistributionKeeper,
+        FailingModule<IbcMsg, IbcQuery, Empty>,
+        FailingModule<GovMsg, Empty, Empty>,
+    >,
+    core_contract: &Addr,
+    vault_contract: &Addr,
+    sender: &Addr,
+    base_denom: &str,
+    price_denom: &str,
+    base_amount: u128,
+    price_amount: u128,
+) -> Result<AppResponse, Error> {
+    let market_contract = app
+        .wrap()
+        .query_wasm_smart::<PairMarketContractResponse>(
+            core_contract.to_owned(),
+            &CoreQueryMsg::GetPairMarketContract {
+                base_denom: base_denom.to_string(),
+                price_denom: price_denom.to_string(),
+            },
+        )?
+        .market_contract;
+    let funds = vec![
+        coin(base_amount, base_denom),
+        coin(price_amount, price_denom),
+    ];
+    app.send_tokens(sender.to_owned(), market_contract.to_owned(), &funds)?;
+    app.execute_contract(
+        market_contract,
+        vault_contract.to_owned(),
+        &VaultExecuteMsg::RecievedFee {
+            base_denom: base_denom.to_string(),
+            base_amount: Uint128::new(base_amount),
+            price_denom: price_denom.to_string(),
+            price_amount: Uint128::new(price_amount),
+        },
+        &funds,
+    )
+}
@@ -1,9 +1,809 @@
+use cosmwasm_std::{coin, to_binary, Addr, Decimal, Uint128};
+use cw_multi_test::Executor;
+use sei_integration_tests::helper::mock_app;
+
+use crate::{
+    app::{
+        create_pair, init_default_balances, init_exchange_rates, setup_init, update_exchange_rate,
+        Contracts, ADMIN, BTC_DENOM, ETH_DENOM, TRADER1, TRADER2, USDC_DENOM, USDT_DENOM,
+    },
+    mock_router::{mock_router_contract, InstantiateMsg as RouterInstantiateMsg},
+    utils::{recieved_fee, setting},
+};
+use axis_protocol::{
+    axis::{ConfigResponse as AxisConfigResponse, QueryMsg as AxisQueryMsg, TotalSupplyResponse},
+    core::{ConfigResponse as CoreConfigResponse, QueryMsg as CoreQueryMsg},
+    es_axis::{ExecuteMsg as EsAxisExecuteMsg, QueryMsg as EsAxisQueryMsg},
+    vault::{
+        BuybackHistoryResponse, BuybackResponse, DenomBalanceResponse, EpochFeesResponse,
+        ExecuteMsg, PairEpochFeeResponse, PairFeesResponse, QueryMsg, ReceiveMsg, SwapAdapter,
+        YieldConfigResponse,
+    },
+};
+
 // fee 를 받았을 때
 #[test]
 fn test_recieved_fee() {}
 
 #[test]
-fn test_swap() {}
+fn test_swap() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let Contracts {
+        core_contract,
+        staking_contract,
+        es_axis_contract,
+        vault_contract,
+        ..
+    } = contracts;
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    //@@ Invalid fee , only the pair market records fees
+    let fee_result = app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::RecievedFee {
+            base_denom: BTC_DENOM.to_string(),
+            base_amount: Uint128::new(1_000_000),
+            price_denom: USDC_DENOM.to_string(),
+            price_amount: Uint128::new(2_000_000),
+        },
+        &vec![coin(1_000_000, BTC_DENOM), coin(2_000_000, USDC_DENOM)],
+    );
+    assert!(fee_result.is_err());
+
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &admin,
+        BTC_DENOM,
+        USDC_DENOM,
+        1_000_000,
+        2_000_000,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+
+    app.execute_contract(
+        staking_contract.to_owned(),
+        es_axis_contract.to_owned(),
+        &EsAxisExecuteMsg::Claim {
+            sender: trader.to_owned(),
+            amount: Uint128::new(1000),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let router_code = app.store_code(mock_router_contract());
+    let router_contract = app
+        .instantiate_contract(
+            router_code,
+            admin.to_owned(),
+            &RouterInstantiateMsg {
+                return_rate: Decimal::one(),
+            },
+            &vec![],
+            "mock_router",
+            None,
+        )
+        .unwrap();
+    app.send_tokens(
+        admin.to_owned(),
+        router_contract.to_owned(),
+        &vec![coin(100_000_000_000, USDC_DENOM)],
+    )
+    .unwrap();
+
+    //@@ Invalid adapter , not the owner , slippage over 99 and payout denom not in the vault
+    let swap_adapter = SwapAdapter {
+        router_contract: router_contract.to_string(),
+        payout_denom: USDC_DENOM.to_string(),
+        max_slippage: 1,
+    };
+    for (sender, swap_adapter) in [
+        (trader.to_owned(), swap_adapter.to_owned()),
+        (
+            admin.to_owned(),
+            SwapAdapter {
+                max_slippage: 100,
+                ..swap_adapter.to_owned()
+            },
+        ),
+        (
+            admin.to_owned(),
+            SwapAdapter {
+                payout_denom: ETH_DENOM.to_string(),
+                ..swap_adapter.to_owned()
+            },
+        ),
+    ] {
+        let update_result = app.execute_contract(
+            sender,
+            vault_contract.to_owned(),
+            &ExecuteMsg::UpdateSwapAdapter {
+                swap_adapter: Some(swap_adapter),
+            },
+            &vec![],
+        );
+        assert!(update_result.is_err());
+    }
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::UpdateSwapAdapter {
+            swap_adapter: Some(swap_adapter.to_owned()),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let saved_adapter: Option<SwapAdapter> = app
+        .wrap()
+        .query_wasm_smart(vault_contract.to_owned(), &QueryMsg::GetSwapAdapter {})
+        .unwrap();
+    assert_eq!(saved_adapter, Some(swap_adapter));
+
+    //@@ half of the supply is paid all in USDC , BTC at the oracle price
+    let es_axis_total_supply: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            es_axis_contract.to_owned(),
+            &EsAxisQueryMsg::GetTotalSupply {},
+        )
+        .unwrap();
+    assert_eq!(es_axis_total_supply, Uint128::new(1000));
+    let before_usdc = app.wrap().query_balance(&trader, USDC_DENOM).unwrap();
+    let before_btc = app.wrap().query_balance(&trader, BTC_DENOM).unwrap();
+    app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &EsAxisExecuteMsg::Send {
+            contract: vault_contract.to_string(),
+            amount: Uint128::new(500),
+            msg: to_binary(&ReceiveMsg::Swap {}).unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let after_usdc = app.wrap().query_balance(&trader, USDC_DENOM).unwrap();
+    let after_btc = app.wrap().query_balance(&trader, BTC_DENOM).unwrap();
+    assert_eq!(
+        after_usdc.amount - before_usdc.amount,
+        Uint128::new(1_000_000 + 500_000 * 10000)
+    );
+    assert_eq!(after_btc.amount, before_btc.amount);
+    //@@ the paid share leaves the vault balance
+    let vault_btc: DenomBalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &QueryMsg::GetDenomBalance {
+                denom: BTC_DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vault_btc.amount, Uint128::new(500_000));
+
+    //@@ Invalid swap , the router returns under the min return
+    let bad_router_contract = app
+        .instantiate_contract(
+            router_code,
+            admin.to_owned(),
+            &RouterInstantiateMsg {
+                return_rate: Decimal::percent(90),
+            },
+            &vec![],
+            "bad_mock_router",
+            None,
+        )
+        .unwrap();
+    app.send_tokens(
+        admin.to_owned(),
+        bad_router_contract.to_owned(),
+        &vec![coin(100_000_000_000, USDC_DENOM)],
+    )
+    .unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::UpdateSwapAdapter {
+            swap_adapter: Some(SwapAdapter {
+                router_contract: bad_router_contract.to_string(),
+                payout_denom: USDC_DENOM.to_string(),
+                max_slippage: 1,
+            }),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let swap_result = app.execute_contract(
+        trader.to_owned(),
+        es_axis_contract.to_owned(),
+        &EsAxisExecuteMsg::Send {
+            contract: vault_contract.to_string(),
+            amount: Uint128::new(500),
+            msg: to_binary(&ReceiveMsg::Swap {}).unwrap(),
+        },
+        &vec![],
+    );
+    assert!(swap_result.is_err());
+}
+
+#[test]
+fn test_swap_pays_each_share_once() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let Contracts {
+        core_contract,
+        staking_contract,
+        es_axis_contract,
+        vault_contract,
+        ..
+    } = contracts;
+    let admin = Addr::unchecked(ADMIN);
+    let traders = [Addr::unchecked(TRADER1), Addr::unchecked(TRADER2)];
+
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &admin,
+        BTC_DENOM,
+        USDC_DENOM,
+        1_000_000,
+        2_000_000,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+    for trader in traders.iter() {
+        app.execute_contract(
+            staking_contract.to_owned(),
+            es_axis_contract.to_owned(),
+            &EsAxisExecuteMsg::Claim {
+                sender: trader.to_owned(),
+                amount: Uint128::new(500),
+            },
+            &vec![],
+        )
+        .unwrap();
+    }
+
+    //@@ each half of the supply takes half of the fees , the second swap is not paid twice
+    for trader in traders.iter() {
+        let before_btc = app.wrap().query_balance(trader, BTC_DENOM).unwrap();
+        let before_usdc = app.wrap().query_balance(trader, USDC_DENOM).unwrap();
+        app.execute_contract(
+            trader.to_owned(),
+            es_axis_contract.to_owned(),
+            &EsAxisExecuteMsg::Send {
+                contract: vault_contract.to_string(),
+                amount: Uint128::new(500),
+                msg: to_binary(&ReceiveMsg::Swap {}).unwrap(),
+            },
+            &vec![],
+        )
+        .unwrap();
+        let after_btc = app.wrap().query_balance(trader, BTC_DENOM).unwrap();
+        let after_usdc = app.wrap().query_balance(trader, USDC_DENOM).unwrap();
+        assert_eq!(after_btc.amount - before_btc.amount, Uint128::new(500_000));
+        assert_eq!(
+            after_usdc.amount - before_usdc.amount,
+            Uint128::new(1_000_000)
+        );
+    }
+    for denom in [BTC_DENOM, USDC_DENOM] {
+        let vault_balance: DenomBalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                vault_contract.to_owned(),
+                &QueryMsg::GetDenomBalance {
+                    denom: denom.to_string(),
+                },
+            )
+            .unwrap();
+        assert!(vault_balance.amount.is_zero());
+    }
+}
 
 #[test]
 fn test_setting() {}
+
+#[test]
+fn test_add_and_remove_denom() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let vault_contract = contracts.vault_contract.to_owned();
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+
+    //@@ core registers the denoms of a new pair , the shared price denom only once
+    create_pair(
+        &mut app, &admin, &contracts, ETH_DENOM, USDC_DENOM, 1_000_000, 1_000_000,
+    )
+    .unwrap();
+    let denom_list: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(vault_contract.to_owned(), &QueryMsg::GetDenomList {})
+        .unwrap();
+    assert_eq!(
+        denom_list,
+        vec![
+            USDC_DENOM.to_string(),
+            BTC_DENOM.to_string(),
+            ETH_DENOM.to_string()
+        ]
+    );
+
+    //@@ Invalid add , not the owner and already listed
+    let add_result = app.execute_contract(
+        trader.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::AddDenom {
+            denom: USDT_DENOM.to_string(),
+        },
+        &vec![],
+    );
+    assert!(add_result.is_err());
+    let add_result = app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::AddDenom {
+            denom: ETH_DENOM.to_string(),
+        },
+        &vec![],
+    );
+    assert!(add_result.is_err());
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::AddDenom {
+            denom: USDT_DENOM.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    //@@ Invalid remove , ETH fees are pending and not the owner
+    recieved_fee(
+        &mut app,
+        &contracts.core_contract,
+        &vault_contract,
+        &admin,
+        ETH_DENOM,
+        USDC_DENOM,
+        1_000,
+        1_000,
+    )
+    .unwrap();
+    let remove_result = app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::RemoveDenom {
+            denom: ETH_DENOM.to_string(),
+        },
+        &vec![],
+    );
+    assert!(remove_result.is_err());
+    let remove_result = app.execute_contract(
+        trader.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::RemoveDenom {
+            denom: USDT_DENOM.to_string(),
+        },
+        &vec![],
+    );
+    assert!(remove_result.is_err());
+
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::RemoveDenom {
+            denom: USDT_DENOM.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let denom_list: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(vault_contract.to_owned(), &QueryMsg::GetDenomList {})
+        .unwrap();
+    assert!(!denom_list.contains(&USDT_DENOM.to_string()));
+}
+
+#[test]
+fn test_fee_records() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let vault_contract = contracts.vault_contract.to_owned();
+    let core_contract = contracts.core_contract.to_owned();
+    let admin = Addr::unchecked(ADMIN);
+    create_pair(
+        &mut app, &admin, &contracts, ETH_DENOM, USDC_DENOM, 1_000_000, 1_000_000,
+    )
+    .unwrap();
+    let epoch = app
+        .wrap()
+        .query_wasm_smart::<CoreConfigResponse>(
+            core_contract.to_owned(),
+            &CoreQueryMsg::GetConfig {},
+        )
+        .unwrap()
+        .epoch;
+
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &admin,
+        BTC_DENOM,
+        USDC_DENOM,
+        1_000,
+        2_000,
+    )
+    .unwrap();
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &admin,
+        ETH_DENOM,
+        USDC_DENOM,
+        300,
+        400,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &admin,
+        BTC_DENOM,
+        USDC_DENOM,
+        10,
+        20,
+    )
+    .unwrap();
+
+    let epoch_fees: Vec<EpochFeesResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &QueryMsg::GetEpochFees {
+                start_epoch: epoch,
+                end_epoch: epoch + 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        epoch_fees,
+        vec![
+            EpochFeesResponse {
+                epoch,
+                fees: vec![
+                    coin(1_000, BTC_DENOM),
+                    coin(300, ETH_DENOM),
+                    coin(2_400, USDC_DENOM)
+                ],
+            },
+            EpochFeesResponse {
+                epoch: epoch + 1,
+                fees: vec![coin(10, BTC_DENOM), coin(20, USDC_DENOM)],
+            },
+        ]
+    );
+    let epoch_fees: Vec<EpochFeesResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &QueryMsg::GetEpochFees {
+                start_epoch: epoch + 1,
+                end_epoch: epoch + 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(epoch_fees.len(), 1);
+
+    let pair_fees: PairFeesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &QueryMsg::GetPairFees {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                start_epoch: epoch,
+                end_epoch: epoch + 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        pair_fees,
+        PairFeesResponse {
+            base_denom: BTC_DENOM.to_string(),
+            price_denom: USDC_DENOM.to_string(),
+            base_amount: Uint128::new(1_010),
+            price_amount: Uint128::new(2_020),
+            epoch_fees: vec![
+                PairEpochFeeResponse {
+                    epoch,
+                    base_amount: Uint128::new(1_000),
+                    price_amount: Uint128::new(2_000),
+                },
+                PairEpochFeeResponse {
+                    epoch: epoch + 1,
+                    base_amount: Uint128::new(10),
+                    price_amount: Uint128::new(20),
+                },
+            ],
+        }
+    );
+    let pair_fees: PairFeesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &QueryMsg::GetPairFees {
+                base_denom: BTC_DENOM.to_string(),
+                price_denom: USDC_DENOM.to_string(),
+                start_epoch: epoch + 1,
+                end_epoch: epoch + 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(pair_fees.base_amount, Uint128::new(10));
+    assert_eq!(pair_fees.epoch_fees.len(), 1);
+}
+
+#[test]
+fn test_buyback_and_burn() {
+    let mut app = mock_app(init_default_balances, init_exchange_rates());
+
+    let contracts = setup_init(&mut app, BTC_DENOM, USDC_DENOM);
+    let Contracts {
+        core_contract,
+        axis_contract,
+        vault_contract,
+        ..
+    } = contracts;
+    let admin = Addr::unchecked(ADMIN);
+    let trader = Addr::unchecked(TRADER1);
+    let axis_denom = app
+        .wrap()
+        .query_wasm_smart::<AxisConfigResponse>(
+            axis_contract.to_owned(),
+            &AxisQueryMsg::GetConfig {},
+        )
+        .unwrap()
+        .axis_denom;
+    update_exchange_rate(&mut app, &axis_denom, "2");
+
+    let router_code = app.store_code(mock_router_contract());
+    let mut routers = vec![];
+    for return_rate in ["1", "0.5"] {
+        let router_contract = app
+            .instantiate_contract(
+                router_code,
+                admin.to_owned(),
+                &RouterInstantiateMsg {
+                    return_rate: return_rate.parse().unwrap(),
+                },
+                &vec![],
+                "mock_router",
+                None,
+            )
+            .unwrap();
+        app.send_tokens(
+            admin.to_owned(),
+            router_contract.to_owned(),
+            &vec![coin(100_000_000, &axis_denom)],
+        )
+        .unwrap();
+        routers.push(router_contract);
+    }
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::UpdateSwapAdapter {
+            swap_adapter: Some(SwapAdapter {
+                router_contract: routers[0].to_string(),
+                payout_denom: USDC_DENOM.to_string(),
+                max_slippage: 1,
+            }),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    //@@ Invalid buyback config , not the owner and over 100 with the staker share
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::UpdateYieldConfig {
+            staker_yield_rate: 90,
+            yield_denom: None,
+        },
+        &vec![],
+    )
+    .unwrap();
+    for (sender, buyback_rate) in [(trader.to_owned(), 20), (admin.to_owned(), 20)] {
+        let update_result = app.execute_contract(
+            sender,
+            vault_contract.to_owned(),
+            &ExecuteMsg::UpdateBuybackConfig { buyback_rate },
+            &vec![],
+        );
+        assert!(update_result.is_err());
+    }
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::UpdateYieldConfig {
+            staker_yield_rate: 0,
+            yield_denom: None,
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::UpdateBuybackConfig { buyback_rate: 20 },
+        &vec![],
+    )
+    .unwrap();
+    let yield_config: YieldConfigResponse = app
+        .wrap()
+        .query_wasm_smart(vault_contract.to_owned(), &QueryMsg::GetYieldConfig {})
+        .unwrap();
+    assert_eq!(yield_config.buyback_rate, 20);
+
+    let epoch = app
+        .wrap()
+        .query_wasm_smart::<CoreConfigResponse>(
+            core_contract.to_owned(),
+            &CoreQueryMsg::GetConfig {},
+        )
+        .unwrap()
+        .epoch;
+    let before_supply: TotalSupplyResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &AxisQueryMsg::GetTotalSupply {})
+        .unwrap();
+
+    //@@ AXIS already held by the vault is not part of the buyback
+    app.send_tokens(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &vec![coin(5_000, &axis_denom)],
+    )
+    .unwrap();
+
+    //@@ 20% of the fees buys AXIS at the oracle price and burns it
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &admin,
+        BTC_DENOM,
+        USDC_DENOM,
+        1_000,
+        2_000,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+
+    let burned_amount = Uint128::new(200 * 10000 / 2 + 400 / 2);
+    let after_supply: TotalSupplyResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &AxisQueryMsg::GetTotalSupply {})
+        .unwrap();
+    assert_eq!(
+        before_supply.total_supply - after_supply.total_supply,
+        burned_amount
+    );
+    let vault_axis = app
+        .wrap()
+        .query_balance(&vault_contract, &axis_denom)
+        .unwrap();
+    assert_eq!(vault_axis.amount, Uint128::new(5_000));
+    for (denom, amount) in [(BTC_DENOM, 800), (USDC_DENOM, 1_600)] {
+        let vault_balance: DenomBalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                vault_contract.to_owned(),
+                &QueryMsg::GetDenomBalance {
+                    denom: denom.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(vault_balance.amount, Uint128::new(amount));
+    }
+    let buyback_history: BuybackHistoryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &QueryMsg::GetBuybackHistory {
+                start_epoch: epoch,
+                end_epoch: epoch + 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        buyback_history,
+        BuybackHistoryResponse {
+            total_burned: burned_amount,
+            buybacks: vec![BuybackResponse {
+                epoch,
+                spent: vec![coin(400, USDC_DENOM), coin(200, BTC_DENOM)],
+                burned_amount,
+            }],
+        }
+    );
+
+    //@@ a failed swap keeps the fees in the vault and the setting goes on
+    app.execute_contract(
+        admin.to_owned(),
+        vault_contract.to_owned(),
+        &ExecuteMsg::UpdateSwapAdapter {
+            swap_adapter: Some(SwapAdapter {
+                router_contract: routers[1].to_string(),
+                payout_denom: USDC_DENOM.to_string(),
+                max_slippage: 1,
+            }),
+        },
+        &vec![],
+    )
+    .unwrap();
+    recieved_fee(
+        &mut app,
+        &core_contract,
+        &vault_contract,
+        &admin,
+        BTC_DENOM,
+        USDC_DENOM,
+        1_000,
+        2_000,
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_days(1));
+    setting(&mut app, &core_contract, &admin).unwrap();
+
+    let failed_supply: TotalSupplyResponse = app
+        .wrap()
+        .query_wasm_smart(axis_contract.to_owned(), &AxisQueryMsg::GetTotalSupply {})
+        .unwrap();
+    assert_eq!(failed_supply, after_supply);
+    for (denom, amount) in [(BTC_DENOM, 1_800), (USDC_DENOM, 3_600)] {
+        let vault_balance: DenomBalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                vault_contract.to_owned(),
+                &QueryMsg::GetDenomBalance {
+                    denom: denom.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(vault_balance.amount, Uint128::new(amount));
+    }
+    let buyback_history: BuybackHistoryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            vault_contract.to_owned(),
+            &QueryMsg::GetBuybackHistory {
+                start_epoch: epoch,
+                end_epoch: epoch + 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(buyback_history.total_burned, burned_amount);
+    assert_eq!(buyback_history.buybacks.len(), 1);
+}